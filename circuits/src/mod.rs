@@ -1,4 +1,5 @@
 pub mod utils;
 pub mod bid_commitment;
+pub mod bid_range;
 pub mod winner_selection;
 pub mod delivery_confirmation;
\ No newline at end of file
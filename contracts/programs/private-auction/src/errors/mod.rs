@@ -78,6 +78,114 @@ pub enum AuctionError {
     /// Invalid auction state for this operation
     #[msg("Invalid auction state")]
     InvalidAuctionState,
+ 
+    /// Auction bidding period has not ended yet
+    #[msg("Auction has not ended yet")]
+    AuctionNotEnded,
+ 
+    /// Auction has received bids
+    #[msg("Auction has bids")]
+    AuctionHasBids,
+ 
+    /// Reserve reveal does not match the committed hash
+    #[msg("Reserve reveal does not match commitment")]
+    ReserveMismatch,
+ 
+    /// Reserve has already been revealed
+    #[msg("Reserve already revealed")]
+    ReserveAlreadyRevealed,
+ 
+    /// Counter-offer window is not open
+    #[msg("Counter-offer window is not open")]
+    CounterofferWindowClosed,
+ 
+    /// Chunked settlement has not processed every bid
+    #[msg("Settlement has unprocessed bids")]
+    SettlementIncomplete,
+ 
+    /// Bid account out of order or from another auction
+    #[msg("Invalid bid for settlement batch")]
+    InvalidSettlementBid,
+ 
+    /// No runner-up to promote
+    #[msg("No runner-up bid to promote")]
+    NoRunnerUp,
+ 
+    /// Winner's claim window has not lapsed
+    #[msg("Winner claim window still open")]
+    ClaimWindowOpen,
+ 
+    /// Auction account already uses the current layout
+    #[msg("Auction account is already on the current schema version")]
+    SchemaUpToDate,
+ 
+    /// Product title exceeds the maximum length
+    #[msg("Product title too long")]
+    TitleTooLong,
+ 
+    /// Product description exceeds the maximum length
+    #[msg("Product description too long")]
+    DescriptionTooLong,
+ 
+    /// Too many product images
+    #[msg("Too many product images")]
+    TooManyImages,
+ 
+    /// An image URL exceeds the per-image budget
+    #[msg("Image URL too long")]
+    ImageUrlTooLong,
+ 
+    /// IPFS hash exceeds the maximum length
+    #[msg("IPFS hash too long")]
+    IpfsHashTooLong,
+ 
+    /// Token quantity for a token auction must be positive
+    #[msg("Token amount must be greater than zero")]
+    InvalidTokenAmount,
+ 
+    /// Listing cannot be edited after bids are placed
+    #[msg("Cannot edit listing after bids have been placed")]
+    CannotEditWithBids,
+ 
+    /// Batch create exceeds the per-transaction listing limit
+    #[msg("Too many auctions in one batch")]
+    BatchTooLarge,
+ 
+    /// Remaining accounts don't match the batch layout
+    #[msg("Invalid accounts for batch auction creation")]
+    InvalidBatchAccounts,
+ 
+    /// Fewer bids were revealed than settlement requires
+    #[msg("Not enough revealed bids to settle")]
+    InsufficientReveals,
+ 
+    /// Category belongs to a different product type
+    #[msg("Category does not match the product type")]
+    CategoryProductMismatch,
+ 
+    /// Seller cancelled a listing of this NFT too recently
+    #[msg("NFT was cancelled too recently to relist")]
+    RelistCooldownActive,
+ 
+    /// Seller already has the maximum number of open auctions
+    #[msg("Seller has too many active auctions")]
+    TooManyActiveAuctions,
+ 
+    /// Seller storefront must be provided to track open auctions
+    #[msg("Seller storefront required")]
+    StorefrontRequired,
+ 
+    /// Extension would push the reveal window past the configured maximum
+    #[msg("Reveal window would exceed the maximum duration")]
+    RevealWindowTooLong,
+ 
+    /// A private settlement needs the winner's commitment to the settled amounts
+    #[msg("Settlement commitment required")]
+    SettlementCommitmentRequired,
+ 
+    /// Revealed blinding factor doesn't open the published settlement commitment
+    #[msg("Settlement commitment mismatch")]
+    SettlementCommitmentMismatch,
 }
  
 /// Bid-related errors
@@ -126,6 +234,34 @@ pub enum BidError {
     /// Bid reveal deadline passed
     #[msg("Bid reveal deadline passed")]
     RevealDeadlinePassed,
+ 
+    /// Revealed bid amount is zero
+    #[msg("Bid amount must be greater than zero")]
+    ZeroBidAmount,
+ 
+    /// Revealed bid amount is below the auction's minimum bid
+    #[msg("Bid amount is below the minimum bid")]
+    BelowMinimumBid,
+ 
+    /// Revealed bid amount exceeds the auction's bid cap
+    #[msg("Bid amount exceeds the auction's bid cap")]
+    AboveBidCap,
+ 
+    /// No bidder signature authorizes this delegated reveal
+    #[msg("Reveal is not authorized by the bidder")]
+    InvalidRevealAuthorization,
+ 
+    /// Encrypted contact is too long
+    #[msg("Invalid encrypted contact")]
+    InvalidContact,
+ 
+    /// Winning bidder left no contact to share
+    #[msg("No contact provided with the winning bid")]
+    NoContactProvided,
+ 
+    /// Bidder is the seller or a wallet linked to the seller
+    #[msg("Seller cannot bid on their own auction")]
+    SellerCannotBid,
 }
  
 /// Escrow-related errors
@@ -174,6 +310,34 @@ pub enum EscrowError {
     /// Amount mismatch
     #[msg("Amount mismatch")]
     AmountMismatch,
+ 
+    /// Winner missed the escrow funding window
+    #[msg("Funding window expired")]
+    FundingWindowExpired,
+ 
+    /// Winner can still fund the escrow
+    #[msg("Funding window still open")]
+    FundingWindowOpen,
+ 
+    /// Vault is not held by the given escrow or collateral pool
+    #[msg("Invalid vault for token rescue")]
+    InvalidRescueVault,
+ 
+    /// Rescue would dip into tracked funds
+    #[msg("Rescue amount exceeds the untracked surplus")]
+    RescueExceedsSurplus,
+ 
+    /// Collateral vault holds less than the pool owes
+    #[msg("Collateral pool vault cannot cover this withdrawal")]
+    CollateralPoolInsolvent,
+ 
+    /// Top-up requested for a pool whose vault already covers its tracked collateral
+    #[msg("Collateral pool has no shortfall")]
+    NoCollateralShortfall,
+ 
+    /// A token account the payment moves through is frozen by its mint
+    #[msg("Token account is frozen")]
+    TokenAccountFrozen,
 }
  
 /// Dispute-related errors
@@ -226,6 +390,30 @@ pub enum DisputeError {
     /// Resolution deadline passed
     #[msg("Resolution deadline passed")]
     ResolutionDeadlinePassed,
+ 
+    /// Stake has already been slashed for this dispute
+    #[msg("Stake already slashed")]
+    AlreadySlashed,
+ 
+    /// Escrow already released after delivery confirmation
+    #[msg("Delivery already confirmed; released funds can no longer be disputed")]
+    DeliveryAlreadyConfirmed,
+ 
+    /// User raised another dispute too soon
+    #[msg("Dispute cooldown active; wait before filing another dispute")]
+    DisputeCooldownActive,
+ 
+    /// No evidence item at the given index
+    #[msg("Evidence not found")]
+    EvidenceNotFound,
+ 
+    /// Arbitrator has no pooled fees left to withdraw
+    #[msg("No arbitrator fees to withdraw")]
+    NoFeesToWithdraw,
+ 
+    /// Only the seller can confirm a return before the return window lapses
+    #[msg("Return not confirmed and return window still open")]
+    ReturnPending,
 }
  
 /// Profile-related errors
@@ -258,6 +446,10 @@ pub enum ProfileError {
     /// Invalid stake amount
     #[msg("Invalid stake amount")]
     InvalidStakeAmount,
+ 
+    /// Wallet cannot be linked (self-link, or already part of another link)
+    #[msg("Invalid wallet link")]
+    InvalidWalletLink,
 }
  
 /// Program configuration errors
@@ -286,6 +478,46 @@ pub enum ConfigError {
     /// Not initialized
     #[msg("Not initialized")]
     NotInitialized,
+ 
+    /// Program is running or hasn't been paused past the emergency timelock
+    #[msg("Emergency withdrawal is locked")]
+    EmergencyWithdrawLocked,
+ 
+    /// Amount or payout arithmetic overflowed
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
+ 
+    /// Account already uses the current layout
+    #[msg("Account is already on the current layout")]
+    LayoutUpToDate,
+ 
+    /// Account type has never changed layout and cannot be migrated
+    #[msg("Account type has no legacy layout")]
+    NotMigratable,
+}
+ 
+/// Oracle-related errors
+#[error_code]
+pub enum OracleError {
+    /// Price feed account is required for this auction
+    #[msg("Price feed required")]
+    PriceFeedRequired,
+ 
+    /// Price feed does not match the expected mint
+    #[msg("Invalid price feed")]
+    InvalidPriceFeed,
+ 
+    /// Price conversion overflowed
+    #[msg("Price conversion failed")]
+    PriceConversionFailed,
+ 
+    /// Price feed hasn't been updated within max_oracle_age
+    #[msg("Price feed is stale")]
+    StalePrice,
+ 
+    /// Price moved more than max_price_deviation_bps since the previous update
+    #[msg("Price deviates too far from the previous price")]
+    PriceDeviationTooLarge,
 }
  
 /// Compression-related errors
@@ -342,5 +574,25 @@ pub enum FulfillmentError {
     /// Service already redeemed
     #[msg("Service already redeemed")]
     ServiceAlreadyRedeemed,
+ 
+    /// Only buyer can perform this action
+    #[msg("Only buyer can perform this action")]
+    OnlyBuyer,
+ 
+    /// Shipping info is empty or too large
+    #[msg("Invalid shipping info")]
+    InvalidShippingInfo,
+ 
+    /// Carrier not offered in the listing's shipping options
+    #[msg("Unsupported shipping carrier")]
+    UnsupportedCarrier,
+ 
+    /// Shipment has already been dispatched
+    #[msg("Shipment already dispatched")]
+    AlreadyShipped,
+ 
+    /// All allowed downloads have been used
+    #[msg("Download limit reached")]
+    DownloadsExhausted,
 }
  
@@ -0,0 +1,685 @@
+use anchor_lang::prelude::*;
+ 
+/// Reason codes for auction cancellation
+pub mod cancellation_reasons {
+    /// Seller requested cancellation
+    pub const SELLER_REQUEST: u8 = 0;
+    /// Seller cancelled after bids were placed and refunds all bidders
+    pub const SELLER_FAULT: u8 = 1;
+    /// Auction ended without any bids
+    pub const NO_BIDS: u8 = 2;
+    /// Highest bid did not meet the seller's reserve
+    pub const RESERVE_NOT_MET: u8 = 3;
+    /// Seller declined the highest bid below their reserve
+    pub const COUNTEROFFER_DECLINED: u8 = 4;
+    /// Fewer bids were revealed than settlement requires
+    pub const TOO_FEW_REVEALS: u8 = 5;
+}
+ 
+/// Reason codes for refunds
+pub mod refund_reasons {
+    /// Bid was outbid by a higher bid (superseded by LOSING_BID)
+    pub const OUTBID: u8 = 0;
+    /// Bidder failed to reveal before the deadline
+    pub const FAILED_TO_REVEAL: u8 = 1;
+    /// Auction was cancelled
+    pub const AUCTION_CANCELLED: u8 = 2;
+    /// Dispute resolved in favor of the buyer
+    pub const DISPUTE_RESOLVED: u8 = 3;
+    /// Bid was revealed within the late-reveal grace period
+    pub const LATE_REVEAL: u8 = 4;
+    /// Auction expired without a winner
+    pub const AUCTION_EXPIRED: u8 = 5;
+    /// Auction closed because the highest bid missed the reserve
+    pub const RESERVE_NOT_MET: u8 = 6;
+    /// Seller cancelled the auction after bids were placed
+    pub const SELLER_ABORTED: u8 = 7;
+    /// Revealed bid lost to the winning bid
+    pub const LOSING_BID: u8 = 8;
+}
+ 
+/// Reason codes for reputation changes
+pub mod reputation_reasons {
+    /// Bidder failed to reveal before the deadline
+    pub const FAILED_TO_REVEAL: u8 = 0;
+    /// Lost a dispute as the defending party
+    pub const DISPUTE_LOST: u8 = 1;
+    /// Non-delivery claim resolved in favor of the seller
+    pub const FRIVOLOUS_DISPUTE: u8 = 2;
+    /// Score recomputed from the profile's counters
+    pub const RECOMPUTED: u8 = 3;
+    /// Seller cancelled an auction after bids were placed
+    pub const CANCELLED_WITH_BIDS: u8 = 4;
+}
+ 
+// ============================================================================
+// Auction Events
+// ============================================================================
+ 
+/// Emitted when a new auction is created
+#[event]
+pub struct AuctionCreated {
+    pub auction_id: Pubkey,
+    pub seller: Pubkey,
+    pub product_type: u8,
+    pub category: u8,
+    pub ipfs_hash: String,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub payment_mint: Pubkey,
+    pub bid_collateral: u64,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when the seller edits a listing before any bids
+#[event]
+pub struct ProductMetadataUpdated {
+    pub auction_id: Pubkey,
+    pub product_id: Pubkey,
+    pub seller: Pubkey,
+    pub ipfs_hash: String,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when the auction moves from bidding to reveal phase
+#[event]
+pub struct RevealPhaseStarted {
+    pub auction_id: Pubkey,
+    pub total_bids: u32,
+    pub reveal_deadline: i64,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when the seller extends an auction's reveal window
+#[event]
+pub struct RevealWindowExtended {
+    pub auction_id: Pubkey,
+    pub additional: i64,
+    pub reveal_deadline: i64,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when an auction is settled and the winner determined
+#[event]
+pub struct AuctionSettled {
+    pub auction_id: Pubkey,
+    pub winner: Pubkey,
+    /// Amount fields are zeroed when the settlement is private; a commitment is set instead
+    pub winning_amount: u64,
+    pub second_price: u64,
+    pub platform_fee: u64,
+    pub seller_receives: u64,
+    pub total_bids: u32,
+    pub revealed_bids: u32,
+    pub winner_revealed_at: Option<i64>,
+    pub leader_changes: u8,
+    /// Highest revealed bids in the winning mint, descending (no bidder identities)
+    pub top_bids: Vec<u64>,
+    pub collateral_credited: u64,
+    pub settlement_reimbursement: u64,
+    /// hash(winning_amount || payment_amount || auction || winner || blinding), set when
+    /// the settlement is private
+    pub amount_commitment: Option<[u8; 32]>,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when an auction is cancelled
+#[event]
+pub struct AuctionCancelled {
+    pub auction_id: Pubkey,
+    pub seller: Pubkey,
+    pub reason: u8,
+    pub bidders_to_refund: u32,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when an auction ends without receiving any bids
+#[event]
+pub struct AuctionExpired {
+    pub auction_id: Pubkey,
+    pub seller: Pubkey,
+    pub end_time: i64,
+    pub nft_returned: bool,
+    pub reason: u8,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when the seller reveals the reserve price
+#[event]
+pub struct ReserveRevealed {
+    pub auction_id: Pubkey,
+    pub reserve_price: u64,
+    pub reserve_met: bool,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when an auction closes without meeting its reserve
+#[event]
+pub struct AuctionFailed {
+    pub auction_id: Pubkey,
+    pub seller: Pubkey,
+    pub highest_value: u64,
+    pub reserve_value: u64,
+    pub nft_returned: bool,
+    pub reason: u8,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when a keeper is paid for cranking an auction
+#[event]
+pub struct KeeperRewarded {
+    pub auction_id: Pubkey,
+    pub keeper: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted after each batch of a chunked settlement
+#[event]
+pub struct SettlementProgress {
+    pub auction_id: Pubkey,
+    pub processed: u32,
+    pub total: u32,
+    pub provisional_winner: Option<Pubkey>,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when the seller accepts or declines the highest below-reserve bid
+#[event]
+pub struct CounterofferResolved {
+    pub auction_id: Pubkey,
+    pub seller: Pubkey,
+    pub accepted: bool,
+    pub highest_amount: u64,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+// ============================================================================
+// Bid Events
+// ============================================================================
+ 
+/// Emitted when a sealed bid commitment is submitted
+#[event]
+pub struct BidSubmitted {
+    pub bid_id: Pubkey,
+    pub auction_id: Pubkey,
+    pub commitment_hash: [u8; 32],
+    pub proof_hash: [u8; 32],
+    pub bid_count: u32,
+    pub bid_index: u32,
+    pub collateral: u64,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when a bid is revealed
+#[event]
+pub struct BidRevealed {
+    pub bid_id: Pubkey,
+    pub auction_id: Pubkey,
+    pub bidder: Pubkey,
+    pub amount: u64,
+    pub current_highest: u64,
+    pub revealed_count: u32,
+    pub late: bool,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted during reveal whenever the provisional winner changes
+#[event]
+pub struct LeaderChanged {
+    pub auction_id: Pubkey,
+    pub previous_leader: Option<Pubkey>,
+    pub new_leader: Pubkey,
+    pub highest_amount: u64,
+    pub payment_mint: Pubkey,
+    pub second_price: u64,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when a bidder claims their collateral refund
+#[event]
+pub struct RefundClaimed {
+    pub bid_id: Pubkey,
+    pub auction_id: Pubkey,
+    pub bidder: Pubkey,
+    pub collateral_amount: u64,
+    pub deposit_amount: u64,
+    pub reveal_rebate: u64,
+    pub reason: u8,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+// ============================================================================
+// Escrow Events
+// ============================================================================
+ 
+/// Emitted when the winner's payment is deposited into escrow
+#[event]
+pub struct EscrowFunded {
+    pub escrow_id: Pubkey,
+    pub auction_id: Pubkey,
+    pub payer: Pubkey,
+    pub amount: u64,
+    pub late_penalty: u64,
+    pub token_mint: Pubkey,
+    pub security_level: u8,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when the winner locks or tops up funds toward the payment
+#[event]
+pub struct EscrowToppedUp {
+    pub escrow_id: Pubkey,
+    pub auction_id: Pubkey,
+    pub amount: u64,
+    pub total_locked: u64,
+    pub required_amount: u64,
+    pub late_penalty: u64,
+    pub funding_deadline: i64,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when a winner fails to fund the escrow in time
+#[event]
+pub struct WinnerForfeited {
+    pub auction_id: Pubkey,
+    pub winner: Pubkey,
+    pub refunded_amount: u64,
+    pub collateral_forfeited: u64,
+    pub promoted_winner: Option<Pubkey>,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when escrowed funds are released to the seller
+#[event]
+pub struct EscrowReleased {
+    pub escrow_id: Pubkey,
+    pub auction_id: Pubkey,
+    pub beneficiary: Pubkey,
+    pub amount: u64,
+    pub platform_fee: u64,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when escrowed funds are refunded to the buyer
+#[event]
+pub struct EscrowRefunded {
+    pub escrow_id: Pubkey,
+    pub auction_id: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub reason: u8,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when a collateral pool is created for a payment mint
+#[event]
+pub struct CollateralPoolInitialized {
+    pub pool: Pubkey,
+    pub token_mint: Pubkey,
+    pub vault: Pubkey,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when the authority rescues untracked tokens from a program vault
+#[event]
+pub struct TokensRescued {
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when a collateral pool's vault shortfall is topped up
+#[event]
+pub struct CollateralPoolToppedUp {
+    pub pool: Pubkey,
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub total_collateral: u64,
+    pub penalty_reserve: u64,
+    pub vault_balance: u64,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+// ============================================================================
+// Fulfillment Events
+// ============================================================================
+ 
+/// Emitted when the buyer confirms delivery
+#[event]
+pub struct DeliveryConfirmed {
+    pub auction_id: Pubkey,
+    pub escrow_id: Pubkey,
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub proof_hash: [u8; 32],
+    /// Amounts committed to at a private settlement, revealed now
+    pub revealed_winning_amount: Option<u64>,
+    pub revealed_second_price: Option<u64>,
+    /// Blinding factor that opens the settlement commitment
+    pub settlement_blinding: Option<[u8; 32]>,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when the buyer hands off an encrypted shipping address
+#[event]
+pub struct ShippingInfoSubmitted {
+    pub auction_id: Pubkey,
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when the winner's encrypted contact is surfaced to the seller
+#[event]
+pub struct WinnerContactShared {
+    pub auction_id: Pubkey,
+    pub winner: Pubkey,
+    pub seller: Pubkey,
+    pub encrypted_contact: Vec<u8>,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when the seller records a tracking number for a shipment
+#[event]
+pub struct ShipmentDispatched {
+    pub auction_id: Pubkey,
+    pub seller: Pubkey,
+    pub carrier: String,
+    pub tracking_number: String,
+    pub release_deadline: i64,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when the buyer claims the digital delivery link
+#[event]
+pub struct DigitalDeliveryClaimed {
+    pub auction_id: Pubkey,
+    pub buyer: Pubkey,
+    pub downloads_remaining: u8,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when the buyer redeems a service, starting the delivery window
+#[event]
+pub struct ServiceRedeemed {
+    pub auction_id: Pubkey,
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub release_deadline: i64,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+// ============================================================================
+// Dispute Events
+// ============================================================================
+ 
+/// Emitted when a dispute is raised
+#[event]
+pub struct DisputeRaised {
+    pub dispute_id: Pubkey,
+    pub auction_id: Pubkey,
+    pub escrow_id: Pubkey,
+    pub raised_by: Pubkey,
+    pub reason: u8,
+    pub amount: u64,
+    pub evidence_deadline: i64,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when a dispute is resolved
+#[event]
+pub struct DisputeResolved {
+    pub dispute_id: Pubkey,
+    pub auction_id: Pubkey,
+    /// 0 = buyer, 1 = seller, 2 = split
+    pub outcome: u8,
+    pub refund_amount: u64,
+    /// Distribution of the escrow (buyer + seller + platform fee = escrow amount,
+    /// except on a return-for-refund, where the refund is held until the item comes back)
+    pub buyer_received: u64,
+    pub seller_received: u64,
+    pub platform_fee: u64,
+    /// Share of the platform fee credited to the arbitrator
+    pub arbitrator_fee: u64,
+    pub arbitrator: Pubkey,
+    pub votes_buyer: u8,
+    pub votes_seller: u8,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when a return-for-refund completes and the buyer is refunded
+#[event]
+pub struct ReturnCompleted {
+    pub dispute_id: Pubkey,
+    pub auction_id: Pubkey,
+    pub buyer: Pubkey,
+    pub amount: u64,
+    /// Refunded because the return window lapsed, not on the seller's confirmation
+    pub timed_out: bool,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when an arbitrator reopens evidence submission
+#[event]
+pub struct MoreInfoRequested {
+    pub dispute_id: Pubkey,
+    pub auction_id: Pubkey,
+    pub arbitrator: Pubkey,
+    pub evidence_deadline: i64,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when a party submits evidence to an open dispute
+#[event]
+pub struct EvidenceSubmitted {
+    pub dispute_id: Pubkey,
+    pub submitter: Pubkey,
+    pub evidence_type: u8,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when the assigned arbitrator marks an evidence item as reviewed
+#[event]
+pub struct EvidenceReviewed {
+    pub dispute_id: Pubkey,
+    pub arbitrator: Pubkey,
+    pub buyer_evidence: bool,
+    pub index: u32,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when the raiser withdraws a dispute before arbitration
+#[event]
+pub struct DisputeCancelled {
+    pub dispute_id: Pubkey,
+    pub auction_id: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when a dispute bond is returned to the raiser or forfeited to the counterparty
+#[event]
+pub struct DisputeBondSettled {
+    pub dispute_id: Pubkey,
+    pub raised_by: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub refunded: bool,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when an arbitrator withdraws their pooled fees
+#[event]
+pub struct ArbitratorFeesWithdrawn {
+    pub arbitrator: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub total_earned: u64,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+// ============================================================================
+// Profile Events
+// ============================================================================
+ 
+/// Emitted when a user profile is created
+#[event]
+pub struct ProfileCreated {
+    pub user: Pubkey,
+    pub reputation_score: u16,
+    pub kyc_level: u8,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when a secondary wallet is linked to a user's profile
+#[event]
+pub struct WalletLinked {
+    pub primary: Pubkey,
+    pub secondary: Pubkey,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when a user's reputation score changes
+#[event]
+pub struct ReputationUpdated {
+    pub user: Pubkey,
+    pub old_score: u16,
+    pub new_score: u16,
+    pub reason: u8,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when a user deposits reputation stake
+#[event]
+pub struct StakeDeposited {
+    pub user: Pubkey,
+    pub amount: u64,
+    pub total_stake: u64,
+    pub lock_until: i64,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when a user's stake is slashed after losing a dispute
+#[event]
+pub struct StakeSlashed {
+    pub user: Pubkey,
+    pub dispute_id: Pubkey,
+    pub amount: u64,
+    pub to_counterparty: u64,
+    pub to_platform: u64,
+    pub remaining_stake: u64,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when a user withdraws reputation stake
+#[event]
+pub struct StakeWithdrawn {
+    pub user: Pubkey,
+    pub amount: u64,
+    pub remaining_stake: u64,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+// ============================================================================
+// Config Events
+// ============================================================================
+ 
+/// Emitted when the program authority updates the configuration
+#[event]
+pub struct ConfigUpdated {
+    pub authority: Pubkey,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when the authority unwinds an auction while the program is frozen
+#[event]
+pub struct EmergencyWithdrawal {
+    pub auction_id: Pubkey,
+    pub authority: Pubkey,
+    pub payer: Option<Pubkey>,
+    pub refunded_amount: u64,
+    pub nft_returned: bool,
+    pub bidders_to_refund: u32,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when the program authority proposes a successor
+#[event]
+pub struct AuthorityProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+/// Emitted when a proposed authority accepts control of the program
+#[event]
+pub struct AuthorityTransferred {
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+ 
+// ============================================================================
+// Oracle Events
+// ============================================================================
+ 
+/// Emitted when a mint's USD price feed is updated
+#[event]
+pub struct PriceFeedUpdated {
+    pub mint: Pubkey,
+    pub price: u64,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
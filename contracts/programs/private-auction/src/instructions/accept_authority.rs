@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+ 
+use crate::state::{ProgramConfig, ProgramStats};
+use crate::errors::ConfigError;
+use crate::events::AuthorityTransferred;
+ 
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = config.pending_authority == Some(new_authority.key()) @ ConfigError::InvalidAuthority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+ 
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    /// Proposed authority (must sign to prove control of the key)
+    pub new_authority: Signer<'info>,
+}
+ 
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+ 
+    let previous_authority = config.authority;
+    config.authority = ctx.accounts.new_authority.key();
+    config.pending_authority = None;
+ 
+    emit!(AuthorityTransferred {
+        previous_authority,
+        new_authority: config.authority,
+        timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
+    });
+ 
+    msg!(
+        "Program authority transferred from {} to {}",
+        previous_authority,
+        config.authority
+    );
+ 
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::SettlementProgress;
+ 
+#[derive(Accounts)]
+pub struct BeginSettlement<'info> {
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+ 
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        mut,
+        seeds = [b"auction", auction.seller.as_ref(), &auction.listing_seed.to_le_bytes()],
+        bump = auction.bump,
+        constraint = auction.status == AuctionStatus::Revealing @ AuctionError::InvalidAuctionState
+    )]
+    pub auction: Account<'info, AuctionState>,
+ 
+    /// Anyone may advance a chunked settlement
+    pub caller: Signer<'info>,
+    // remaining_accounts: the auction's BidCommitment accounts in bid_index order,
+    // starting at auction.settlement_cursor
+}
+ 
+pub fn handler(ctx: Context<BeginSettlement>) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    let clock = Clock::get()?;
+ 
+    // Reveals are final only once the reveal window has elapsed
+    require!(
+        auction.can_settle(ctx.accounts.config.phase_time(clock.unix_timestamp)),
+        AuctionError::CannotSettleYet
+    );
+    // An empty batch would make no progress
+    require!(
+        !ctx.remaining_accounts.is_empty()
+            && ctx.remaining_accounts.len() <= AuctionState::MAX_SETTLEMENT_BATCH,
+        AuctionError::InvalidSettlementBid
+    );
+ 
+    let auction_key = auction.key();
+    for bid_info in ctx.remaining_accounts.iter() {
+        require_keys_eq!(*bid_info.owner, crate::ID, AuctionError::InvalidSettlementBid);
+        let bid = BidCommitment::try_deserialize(&mut &bid_info.try_borrow_data()?[..])?;
+        auction.process_settlement_bid(auction_key, &bid)?;
+    }
+ 
+    emit!(SettlementProgress {
+        auction_id: auction_key,
+        processed: auction.settlement_cursor,
+        total: auction.next_bid_index,
+        provisional_winner: auction.provisional_ranking.winner,
+        timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
+    });
+ 
+    msg!(
+        "Settlement of auction {}: processed {}/{} bids",
+        auction_key,
+        auction.settlement_cursor,
+        auction.next_bid_index
+    );
+ 
+    Ok(())
+}
@@ -22,7 +22,7 @@ pub struct CancelAuction<'info> {
  
     #[account(
         mut,
-        seeds = [b"auction", auction.seller.as_ref(), &auction.start_time.to_le_bytes()],
+        seeds = [b"auction", auction.seller.as_ref(), &auction.listing_seed.to_le_bytes()],
         bump = auction.bump,
         constraint = auction.seller == seller.key() @ AuctionError::OnlySeller,
         constraint = auction.status == AuctionStatus::Active @ AuctionError::InvalidAuctionState
@@ -38,7 +38,7 @@ pub struct CancelAuction<'info> {
  
     #[account(
         mut,
-        seeds = [b"escrow_vault", auction.key().as_ref()],
+        seeds = [b"escrow_vault", auction.key().as_ref(), escrow.token_mint.as_ref()],
         bump
     )]
     pub escrow_vault: Account<'info, TokenAccount>,
@@ -51,9 +51,32 @@ pub struct CancelAuction<'info> {
     #[account(mut)]
     pub seller_nft_account: Option<Account<'info, TokenAccount>>,
  
+    /// Relist guard for this seller and NFT (required for NFT auctions)
+    #[account(
+        init_if_needed,
+        payer = seller,
+        space = RelistGuard::LEN,
+        seeds = [
+            b"relist_guard",
+            auction.seller.as_ref(),
+            auction.nft_mint.unwrap_or_default().as_ref()
+        ],
+        bump
+    )]
+    pub relist_guard: Option<Account<'info, RelistGuard>>,
+ 
     #[account(mut)]
     pub seller: Signer<'info>,
  
+    /// CHECK: Seller's storefront aggregate at its seed-derived address. Always passed, and
+    /// updated whenever the seller has created one, so its listing count can't drift.
+    #[account(
+        mut,
+        seeds = [b"storefront", auction.seller.as_ref()],
+        bump
+    )]
+    pub storefront: UncheckedAccount<'info>,
+ 
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
@@ -93,7 +116,7 @@ pub fn handler(ctx: Context<CancelAuction>) -> Result<()> {
                     },
                     &[escrow_seeds],
                 ),
-                1,
+                auction.token_amount,
             )?;
  
             // Close the NFT escrow account
@@ -107,6 +130,20 @@ pub fn handler(ctx: Context<CancelAuction>) -> Result<()> {
                 &[escrow_seeds],
             ))?;
         }
+ 
+        // Start the relist cooldown for this NFT
+        let nft_mint = auction.nft_mint.ok_or(AuctionError::InvalidProductType)?;
+        let relist_guard = ctx
+            .accounts
+            .relist_guard
+            .as_mut()
+            .ok_or(AuctionError::InvalidProductType)?;
+        relist_guard.record_cancellation(
+            auction.seller,
+            nft_mint,
+            clock.unix_timestamp,
+            ctx.bumps.relist_guard.unwrap_or_default(),
+        );
     }
  
     // Update auction state
@@ -118,6 +155,9 @@ pub fn handler(ctx: Context<CancelAuction>) -> Result<()> {
     // Update stats
     stats.active_auctions = stats.active_auctions.saturating_sub(1);
     stats.last_updated = clock.unix_timestamp;
+    SellerStorefront::update(&ctx.accounts.storefront, |storefront| {
+        storefront.listing_closed(clock.unix_timestamp);
+    })?;
  
     // Emit event
     emit!(AuctionCancelled {
@@ -126,6 +166,7 @@ pub fn handler(ctx: Context<CancelAuction>) -> Result<()> {
         reason: cancellation_reasons::SELLER_REQUEST,
         bidders_to_refund: 0,
         timestamp: clock.unix_timestamp,
+        event_seq: stats.next_event_seq(),
     });
  
     msg!("Auction {} cancelled by seller", auction.key());
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, transfer, Transfer};
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::{DisputeBondSettled, DisputeCancelled};
+ 
+#[derive(Accounts)]
+pub struct CancelDispute<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        mut,
+        seeds = [b"auction", auction.seller.as_ref(), &auction.listing_seed.to_le_bytes()],
+        bump = auction.bump,
+        constraint = auction.status == AuctionStatus::Disputed @ AuctionError::InvalidAuctionState
+    )]
+    pub auction: Account<'info, AuctionState>,
+ 
+    #[account(
+        mut,
+        seeds = [b"escrow", auction.key().as_ref()],
+        bump = escrow.bump,
+        constraint = escrow.status == EscrowStatus::Disputed @ EscrowError::InvalidEscrowState
+    )]
+    pub escrow: Account<'info, EscrowAccount>,
+ 
+    #[account(
+        mut,
+        seeds = [b"dispute", auction.key().as_ref()],
+        bump = dispute.bump,
+        constraint = dispute.raised_by == raiser.key() @ DisputeError::NotAParty
+    )]
+    pub dispute: Account<'info, Dispute>,
+ 
+    /// Dispute bond vault (required if the raiser posted a bond)
+    #[account(
+        mut,
+        seeds = [b"dispute_bond", auction.key().as_ref()],
+        bump
+    )]
+    pub bond_vault: Option<Account<'info, TokenAccount>>,
+ 
+    /// Raiser's token account to return the bond to (required if a bond was posted)
+    #[account(
+        mut,
+        constraint = raiser_token_account.owner == raiser.key(),
+        constraint = raiser_token_account.mint == escrow.token_mint @ EscrowError::InvalidTokenMint
+    )]
+    pub raiser_token_account: Option<Account<'info, TokenAccount>>,
+ 
+    pub raiser: Signer<'info>,
+ 
+    pub token_program: Program<'info, Token>,
+}
+ 
+pub fn handler(ctx: Context<CancelDispute>) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    let escrow = &mut ctx.accounts.escrow;
+    let dispute = &mut ctx.accounts.dispute;
+    let stats = &mut ctx.accounts.stats;
+    let clock = Clock::get()?;
+ 
+    // Withdrawal is only possible before arbitration begins
+    require!(dispute.can_cancel(), DisputeError::InvalidDisputeState);
+ 
+    dispute.status = DisputeStatus::Cancelled;
+    dispute.last_activity = clock.unix_timestamp;
+ 
+    // Withdrawing before arbitration costs nothing: the bond goes back to the raiser
+    let bond = dispute.bond_amount;
+    if bond > 0 {
+        let (Some(bond_vault), Some(raiser_token_account)) = (
+            &ctx.accounts.bond_vault,
+            &ctx.accounts.raiser_token_account,
+        ) else {
+            return err!(DisputeError::InvalidDisputeState);
+        };
+        let auction_key = auction.key();
+        let dispute_seeds = &[b"dispute".as_ref(), auction_key.as_ref(), &[dispute.bump]];
+ 
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: bond_vault.to_account_info(),
+                    to: raiser_token_account.to_account_info(),
+                    authority: dispute.to_account_info(),
+                },
+                &[dispute_seeds],
+            ),
+            bond,
+        )?;
+        dispute.bond_amount = 0;
+ 
+        emit!(DisputeBondSettled {
+            dispute_id: dispute.key(),
+            raised_by: dispute.raised_by,
+            recipient: dispute.raised_by,
+            amount: bond,
+            refunded: true,
+            timestamp: clock.unix_timestamp,
+            event_seq: stats.next_event_seq(),
+        });
+    }
+ 
+    // Unlock escrow and return auction to settled state
+    escrow.status = EscrowStatus::Funded;
+    auction.status = AuctionStatus::Settled;
+ 
+    stats.dispute_cancelled(clock.unix_timestamp);
+ 
+    emit!(DisputeCancelled {
+        dispute_id: dispute.key(),
+        auction_id: auction.key(),
+        cancelled_by: ctx.accounts.raiser.key(),
+        timestamp: clock.unix_timestamp,
+        event_seq: stats.next_event_seq(),
+    });
+ 
+    msg!("Dispute {} cancelled by {}", dispute.key(), ctx.accounts.raiser.key());
+ 
+    Ok(())
+}
@@ -0,0 +1,201 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, transfer, Transfer, close_account, CloseAccount};
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::{AuctionCancelled, cancellation_reasons, ReputationUpdated, reputation_reasons};
+ 
+#[derive(Accounts)]
+pub struct CancelWithRefunds<'info> {
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+ 
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        mut,
+        seeds = [b"auction", auction.seller.as_ref(), &auction.listing_seed.to_le_bytes()],
+        bump = auction.bump,
+        constraint = auction.seller == seller.key() @ AuctionError::OnlySeller,
+        constraint = auction.status == AuctionStatus::Active @ AuctionError::InvalidAuctionState
+    )]
+    pub auction: Account<'info, AuctionState>,
+ 
+    #[account(
+        mut,
+        seeds = [b"escrow", auction.key().as_ref()],
+        bump = escrow.bump
+    )]
+    pub escrow: Account<'info, EscrowAccount>,
+ 
+    /// NFT escrow (optional, for NFT auctions)
+    #[account(
+        mut,
+        constraint = nft_escrow.owner == escrow.key(),
+        constraint = Some(nft_escrow.mint) == auction.nft_mint
+    )]
+    pub nft_escrow: Option<Account<'info, TokenAccount>>,
+ 
+    /// Seller's NFT token account (optional, for returning NFT)
+    #[account(
+        mut,
+        constraint = seller_nft_account.owner == auction.seller,
+        constraint = Some(seller_nft_account.mint) == auction.nft_mint
+    )]
+    pub seller_nft_account: Option<Account<'info, TokenAccount>>,
+ 
+    /// Relist guard for this seller and NFT (required for NFT auctions)
+    #[account(
+        init_if_needed,
+        payer = seller,
+        space = RelistGuard::LEN,
+        seeds = [
+            b"relist_guard",
+            auction.seller.as_ref(),
+            auction.nft_mint.unwrap_or_default().as_ref()
+        ],
+        bump
+    )]
+    pub relist_guard: Option<Account<'info, RelistGuard>>,
+ 
+    #[account(
+        mut,
+        seeds = [b"user_profile", seller.key().as_ref()],
+        bump = seller_profile.bump
+    )]
+    pub seller_profile: Account<'info, UserProfile>,
+ 
+    #[account(mut)]
+    pub seller: Signer<'info>,
+ 
+    /// CHECK: Seller's storefront aggregate at its seed-derived address. Always passed, and
+    /// updated whenever the seller has created one, so its listing count can't drift.
+    #[account(
+        mut,
+        seeds = [b"storefront", auction.seller.as_ref()],
+        bump
+    )]
+    pub storefront: UncheckedAccount<'info>,
+ 
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+ 
+pub fn handler(ctx: Context<CancelWithRefunds>) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    let escrow = &mut ctx.accounts.escrow;
+    let seller_profile = &mut ctx.accounts.seller_profile;
+    let stats = &mut ctx.accounts.stats;
+    let clock = Clock::get()?;
+    let phase_time = ctx.accounts.config.phase_time(clock.unix_timestamp);
+ 
+    // Auctions without bids go through cancel_auction
+    require!(auction.bid_count > 0, AuctionError::InvalidAuctionState);
+ 
+    // Once bidding closes the auction runs its course and bids are revealed as usual
+    require!(phase_time < auction.end_time, AuctionError::BiddingEnded);
+ 
+    // Return NFT to seller if NFT auction
+    if auction.product_type == ProductType::Nft {
+        let (Some(nft_escrow), Some(seller_nft)) = (
+            &ctx.accounts.nft_escrow,
+            &ctx.accounts.seller_nft_account,
+        ) else {
+            return err!(AuctionError::InvalidProductType);
+        };
+ 
+        let auction_key = auction.key();
+        let escrow_seeds = &[
+            b"escrow".as_ref(),
+            auction_key.as_ref(),
+            &[escrow.bump],
+        ];
+ 
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: nft_escrow.to_account_info(),
+                    to: seller_nft.to_account_info(),
+                    authority: escrow.to_account_info(),
+                },
+                &[escrow_seeds],
+            ),
+            auction.token_amount,
+        )?;
+ 
+        // Close the NFT escrow account
+        close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: nft_escrow.to_account_info(),
+                destination: ctx.accounts.seller.to_account_info(),
+                authority: escrow.to_account_info(),
+            },
+            &[escrow_seeds],
+        ))?;
+ 
+        // Start the relist cooldown for this NFT
+        let nft_mint = auction.nft_mint.ok_or(AuctionError::InvalidProductType)?;
+        let relist_guard = ctx
+            .accounts
+            .relist_guard
+            .as_mut()
+            .ok_or(AuctionError::InvalidProductType)?;
+        relist_guard.record_cancellation(
+            auction.seller,
+            nft_mint,
+            clock.unix_timestamp,
+            ctx.bumps.relist_guard.unwrap_or_default(),
+        );
+    }
+ 
+    // Bidders reclaim full collateral through claim_refund's Cancelled branch
+    auction.status = AuctionStatus::Cancelled;
+    escrow.status = EscrowStatus::Cancelled;
+ 
+    // Penalize the seller for cancelling after bids were committed
+    let old_score = seller_profile.reputation_score;
+    seller_profile.record_cancellation_with_bids();
+ 
+    emit!(ReputationUpdated {
+        user: ctx.accounts.seller.key(),
+        old_score,
+        new_score: seller_profile.reputation_score,
+        reason: reputation_reasons::CANCELLED_WITH_BIDS,
+        timestamp: clock.unix_timestamp,
+        event_seq: stats.next_event_seq(),
+    });
+ 
+    // Update stats
+    stats.active_auctions = stats.active_auctions.saturating_sub(1);
+    stats.last_updated = clock.unix_timestamp;
+    SellerStorefront::update(&ctx.accounts.storefront, |storefront| {
+        storefront.listing_closed(clock.unix_timestamp);
+    })?;
+ 
+    emit!(AuctionCancelled {
+        auction_id: auction.key(),
+        seller: ctx.accounts.seller.key(),
+        reason: cancellation_reasons::SELLER_FAULT,
+        bidders_to_refund: auction.bid_count,
+        timestamp: clock.unix_timestamp,
+        event_seq: stats.next_event_seq(),
+    });
+ 
+    msg!(
+        "Auction {} cancelled by seller with {} bids to refund",
+        auction.key(),
+        auction.bid_count
+    );
+ 
+    Ok(())
+}
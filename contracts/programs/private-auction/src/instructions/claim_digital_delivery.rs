@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::DigitalDeliveryClaimed;
+ 
+#[derive(Accounts)]
+pub struct ClaimDigitalDelivery<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        seeds = [b"auction", auction.seller.as_ref(), &auction.listing_seed.to_le_bytes()],
+        bump = auction.bump,
+        constraint = auction.status == AuctionStatus::Settled @ AuctionError::InvalidAuctionState,
+        constraint = auction.product_type == ProductType::Digital @ AuctionError::InvalidProductType
+    )]
+    pub auction: Account<'info, AuctionState>,
+ 
+    #[account(
+        seeds = [b"product", auction.key().as_ref()],
+        bump = product_metadata.bump
+    )]
+    pub product_metadata: Account<'info, ProductMetadata>,
+ 
+    #[account(
+        mut,
+        seeds = [b"fulfillment", auction.key().as_ref()],
+        bump = fulfillment.bump
+    )]
+    pub fulfillment: Account<'info, FulfillmentRecord>,
+ 
+    /// Buyer must match auction winner
+    #[account(
+        constraint = Some(buyer.key()) == auction.winner @ FulfillmentError::OnlyBuyer
+    )]
+    pub buyer: Signer<'info>,
+}
+ 
+/// Returns the encrypted download link
+pub fn handler(ctx: Context<ClaimDigitalDelivery>) -> Result<Vec<u8>> {
+    let auction = &ctx.accounts.auction;
+    let fulfillment = &mut ctx.accounts.fulfillment;
+    let clock = Clock::get()?;
+ 
+    let digital = ctx
+        .accounts
+        .product_metadata
+        .digital_delivery
+        .as_ref()
+        .ok_or(AuctionError::InvalidProductType)?;
+ 
+    // Download window (0 = never expires)
+    require!(
+        digital.expires_at == 0 || clock.unix_timestamp <= digital.expires_at,
+        FulfillmentError::RedemptionExpired
+    );
+ 
+    require!(
+        fulfillment.downloads_remaining > 0,
+        FulfillmentError::DownloadsExhausted
+    );
+    fulfillment.downloads_remaining -= 1;
+ 
+    emit!(DigitalDeliveryClaimed {
+        auction_id: auction.key(),
+        buyer: ctx.accounts.buyer.key(),
+        downloads_remaining: fulfillment.downloads_remaining,
+        timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
+    });
+ 
+    msg!(
+        "Digital delivery claimed for auction {} ({} downloads remaining)",
+        auction.key(),
+        fulfillment.downloads_remaining
+    );
+ 
+    Ok(digital.encrypted_link.clone())
+}
@@ -5,6 +5,81 @@ use crate::state::*;
 use crate::errors::*;
 use crate::events::{RefundClaimed, refund_reasons, ReputationUpdated, reputation_reasons};
  
+/// Refund a bidder would receive from claim_refund
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RefundPreview {
+    /// Whether claim_refund would succeed for this bid
+    pub eligible: bool,
+    /// Collateral that would be returned
+    pub amount: u64,
+    /// Refund reason code (see events::refund_reasons)
+    pub reason: u8,
+    /// Whether a penalty reduces the refund
+    pub penalized: bool,
+}
+ 
+impl RefundPreview {
+    /// Preview for a bid with nothing to claim
+    pub fn ineligible() -> Self {
+        Self {
+            eligible: false,
+            amount: 0,
+            reason: refund_reasons::LOSING_BID,
+            penalized: false,
+        }
+    }
+}
+ 
+/// Compute the refund owed on a non-winning bid once the auction has closed
+pub fn compute_refund(auction: &AuctionState, bid: &BidCommitment) -> Result<RefundPreview> {
+    let mut refund_amount = bid.collateral_deposited;
+    let mut reason = refund_reasons::LOSING_BID;
+ 
+    match auction.status {
+        AuctionStatus::Settled => {
+            // Check if bid was revealed
+            if !bid.revealed {
+                // Failed to reveal - penalize
+                refund_amount /= 2; // 50% penalty for not revealing
+                reason = refund_reasons::FAILED_TO_REVEAL;
+            } else if bid.late_revealed {
+                // Revealed within the grace period - smaller penalty
+                refund_amount -= (refund_amount as u128
+                    * BidCommitment::LATE_REVEAL_PENALTY_BPS as u128
+                    / 10_000) as u64;
+                reason = refund_reasons::LATE_REVEAL;
+            }
+        }
+        AuctionStatus::Cancelled => {
+            // Cancellation is only possible with bids via cancel_with_refunds
+            reason = if auction.bid_count > 0 {
+                refund_reasons::SELLER_ABORTED
+            } else {
+                refund_reasons::AUCTION_CANCELLED
+            };
+        }
+        AuctionStatus::Expired => {
+            // Failed auctions keep the leading value for comparison with the reserve;
+            // a USD reserve only expires when it was missed
+            reason = if auction.reserve_usd_cents.is_none() && auction.reserve_met() {
+                refund_reasons::AUCTION_EXPIRED
+            } else {
+                refund_reasons::RESERVE_NOT_MET
+            };
+        }
+        _ => {
+            return Err(AuctionError::InvalidAuctionState.into());
+        }
+    }
+ 
+    Ok(RefundPreview {
+        eligible: true,
+        amount: refund_amount,
+        reason,
+        penalized: refund_amount < bid.collateral_deposited,
+    })
+}
+ 
 #[derive(Accounts)]
 pub struct ClaimRefund<'info> {
     #[account(
@@ -14,7 +89,14 @@ pub struct ClaimRefund<'info> {
     pub config: Account<'info, ProgramConfig>,
  
     #[account(
-        seeds = [b"auction", auction.seller.as_ref(), &auction.start_time.to_le_bytes()],
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        seeds = [b"auction", auction.seller.as_ref(), &auction.listing_seed.to_le_bytes()],
         bump = auction.bump,
         constraint =
             auction.status == AuctionStatus::Settled ||
@@ -35,18 +117,21 @@ pub struct ClaimRefund<'info> {
  
     #[account(
         mut,
-        seeds = [b"collateral_pool", auction.payment_mint.as_ref()],
+        seeds = [b"collateral_pool", bid.collateral_mint.as_ref()],
         bump
     )]
     pub collateral_pool: Account<'info, CollateralPool>,
  
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = collateral_pool_vault.key() == collateral_pool.token_account
+    )]
     pub collateral_pool_vault: Account<'info, TokenAccount>,
  
     #[account(
         mut,
         constraint = bidder_token_account.owner == bidder.key(),
-        constraint = bidder_token_account.mint == auction.payment_mint
+        constraint = bidder_token_account.mint == bid.collateral_mint
     )]
     pub bidder_token_account: Account<'info, TokenAccount>,
  
@@ -78,36 +163,31 @@ pub fn handler(ctx: Context<ClaimRefund>) -> Result<()> {
     }
  
     // Determine refund amount and reason
-    let mut refund_amount = bid.collateral_deposited;
-    let mut reason = refund_reasons::OUTBID;
-    let mut penalize = false;
+    let RefundPreview { amount: refund_amount, reason, .. } = compute_refund(auction, bid)?;
+    // Failing to reveal also costs reputation
+    let penalize = reason == refund_reasons::FAILED_TO_REVEAL;
  
-    match auction.status {
-        AuctionStatus::Settled => {
-            // Check if bid was revealed
-            if !bid.revealed {
-                // Failed to reveal - penalize
-                penalize = true;
-                refund_amount = refund_amount / 2; // 50% penalty for not revealing
-                reason = refund_reasons::FAILED_TO_REVEAL;
-            }
-        }
-        AuctionStatus::Cancelled => {
-            reason = refund_reasons::AUCTION_CANCELLED;
-        }
-        AuctionStatus::Expired => {
-            reason = refund_reasons::AUCTION_CANCELLED;
-        }
-        _ => {
-            return Err(AuctionError::InvalidAuctionState.into());
-        }
-    }
+    // Fail clearly if the vault has drifted below what the pool owes
+    require!(
+        ctx.accounts.collateral_pool_vault.amount >= refund_amount,
+        EscrowError::CollateralPoolInsolvent
+    );
+ 
+    // Whatever a penalty withholds stays in the pool to fund rebates
+    collateral_pool.withhold_penalty(bid.collateral_deposited.saturating_sub(refund_amount));
+ 
+    // Prompt revealers share in the penalties withheld from other bidders
+    let reveal_rebate = if bid.early_revealed {
+        collateral_pool.pay_rebate(ctx.accounts.config.reveal_rebate(bid.collateral_deposited))
+    } else {
+        0
+    };
  
     // Transfer refund from collateral pool
-    let payment_mint = auction.payment_mint;
+    let collateral_mint = bid.collateral_mint;
     let pool_seeds = &[
         b"collateral_pool".as_ref(),
-        payment_mint.as_ref(),
+        collateral_mint.as_ref(),
         &[ctx.bumps.collateral_pool],
     ];
  
@@ -121,20 +201,30 @@ pub fn handler(ctx: Context<ClaimRefund>) -> Result<()> {
             },
             &[pool_seeds],
         ),
-        refund_amount,
+        refund_amount + reveal_rebate,
     )?;
  
     // Update collateral pool
     collateral_pool.withdraw(bid.collateral_deposited)?;
  
-    // Mark collateral as returned
+    // Mark collateral as returned; a losing bidder's contact is never shared
     bid.collateral_returned = true;
+    bid.encrypted_contact.clear();
+    if bid.collateral_waived > 0 {
+        bidder_profile.close_waived_bid();
+    }
  
     // Update reputation if penalized
     if penalize {
         let old_score = bidder_profile.reputation_score;
-        // Reduce reputation by 50 points for failing to reveal
-        bidder_profile.reputation_score = bidder_profile.reputation_score.saturating_sub(50);
+        // Reduce reputation by 50 points for failing to reveal, more if the reputation
+        // stood in for waived collateral
+        let penalty = if bid.collateral_waived > 0 {
+            BidCommitment::WAIVED_REVEAL_PENALTY
+        } else {
+            50
+        };
+        bidder_profile.reputation_score = bidder_profile.reputation_score.saturating_sub(penalty);
  
         emit!(ReputationUpdated {
             user: ctx.accounts.bidder.key(),
@@ -142,6 +232,7 @@ pub fn handler(ctx: Context<ClaimRefund>) -> Result<()> {
             new_score: bidder_profile.reputation_score,
             reason: reputation_reasons::FAILED_TO_REVEAL,
             timestamp: clock.unix_timestamp,
+            event_seq: ctx.accounts.stats.next_event_seq(),
         });
     }
  
@@ -152,13 +243,18 @@ pub fn handler(ctx: Context<ClaimRefund>) -> Result<()> {
         bidder: ctx.accounts.bidder.key(),
         collateral_amount: refund_amount,
         deposit_amount: 0,
+        reveal_rebate,
+        reason,
         timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
     });
  
     msg!(
         "Refund claimed: {} lamports ({}% of collateral)",
         refund_amount,
-        (refund_amount * 100) / bid.collateral_deposited
+        (refund_amount as u128 * 100)
+            .checked_div(bid.collateral_deposited as u128)
+            .unwrap_or(0)
     );
  
     Ok(())
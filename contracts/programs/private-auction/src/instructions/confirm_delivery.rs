@@ -4,6 +4,7 @@ use anchor_spl::token::{Token, TokenAccount, transfer, Transfer};
 use crate::state::*;
 use crate::errors::*;
 use crate::events::{DeliveryConfirmed, EscrowReleased};
+use crate::verifier::{to_field, verify_delivery_proof};
  
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct ConfirmDeliveryParams {
@@ -13,6 +14,8 @@ pub struct ConfirmDeliveryParams {
     pub proof_hash: [u8; 32],
     /// Optional rating for seller (0-50, /10 for stars)
     pub seller_rating: Option<u8>,
+    /// Blinding factor behind a private settlement's commitment, revealed now
+    pub settlement_blinding: Option<[u8; 32]>,
 }
  
 #[derive(Accounts)]
@@ -26,37 +29,51 @@ pub struct ConfirmDelivery<'info> {
  
     #[account(
         mut,
-        seeds = [b"auction", auction.seller.as_ref(), &auction.start_time.to_le_bytes()],
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        mut,
+        seeds = [b"auction", auction.seller.as_ref(), &auction.listing_seed.to_le_bytes()],
         bump = auction.bump,
         constraint = auction.status == AuctionStatus::Settled @ AuctionError::InvalidAuctionState
     )]
     pub auction: Account<'info, AuctionState>,
  
+    /// Product metadata (category selects the platform fee)
+    #[account(
+        seeds = [b"product", auction.key().as_ref()],
+        bump = product_metadata.bump
+    )]
+    pub product_metadata: Account<'info, ProductMetadata>,
+ 
     #[account(
         mut,
         seeds = [b"escrow", auction.key().as_ref()],
-        bump = escrow.bump,
-        constraint = escrow.status == EscrowStatus::Funded @ EscrowError::InvalidEscrowState
+        bump = escrow.bump
     )]
     pub escrow: Account<'info, EscrowAccount>,
  
     #[account(
         mut,
-        seeds = [b"escrow_vault", auction.key().as_ref()],
-        bump
+        seeds = [b"escrow_vault", auction.key().as_ref(), escrow.token_mint.as_ref()],
+        bump = escrow.vault_bump
     )]
     pub escrow_vault: Account<'info, TokenAccount>,
  
     #[account(
         mut,
         constraint = seller_token_account.owner == auction.seller,
-        constraint = seller_token_account.mint == auction.payment_mint
+        constraint = seller_token_account.mint == escrow.token_mint
     )]
     pub seller_token_account: Account<'info, TokenAccount>,
  
     #[account(
         mut,
-        constraint = fee_collector.key() == config.fee_collector
+        constraint = config.is_fee_collector(&fee_collector.key(), &fee_collector.owner),
+        constraint = fee_collector.mint == escrow.token_mint
     )]
     pub fee_collector: Account<'info, TokenAccount>,
  
@@ -67,6 +84,13 @@ pub struct ConfirmDelivery<'info> {
     )]
     pub seller_profile: Account<'info, UserProfile>,
  
+    /// Seller's stake, which must cover the tier the sale was valued at
+    #[account(
+        seeds = [b"reputation_stake", auction.seller.as_ref()],
+        bump = seller_stake.bump
+    )]
+    pub seller_stake: Account<'info, ReputationStake>,
+ 
     #[account(
         mut,
         seeds = [b"user_profile", buyer.key().as_ref()],
@@ -74,6 +98,22 @@ pub struct ConfirmDelivery<'info> {
     )]
     pub buyer_profile: Account<'info, UserProfile>,
  
+    /// Fulfillment record opened at settlement
+    #[account(
+        mut,
+        seeds = [b"fulfillment", auction.key().as_ref()],
+        bump = fulfillment.bump,
+        constraint = !fulfillment.is_delivery_confirmed() @ FulfillmentError::DeliveryAlreadyConfirmed
+    )]
+    pub fulfillment: Account<'info, FulfillmentRecord>,
+ 
+    /// Price feed for the escrowed mint (for USD volume tracking)
+    #[account(
+        seeds = [b"price_feed", escrow.token_mint.as_ref()],
+        bump = price_feed.bump
+    )]
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+ 
     /// CHECK: Buyer must match auction winner
     #[account(
         constraint = Some(buyer.key()) == auction.winner @ FulfillmentError::OnlyBuyerCanConfirm
@@ -91,22 +131,87 @@ pub fn handler(ctx: Context<ConfirmDelivery>, params: ConfirmDeliveryParams) ->
     let buyer_profile = &mut ctx.accounts.buyer_profile;
     let clock = Clock::get()?;
  
-    // Verify delivery proof (in production, this would verify ZK proof)
+    // NFTs are delivered at settlement and never need confirming
+    if auction.product_type == ProductType::Nft {
+        msg!("NFT auctions are delivered at settlement; no confirmation needed");
+        if escrow.status == EscrowStatus::Released {
+            return err!(FulfillmentError::DeliveryAlreadyConfirmed);
+        }
+        return err!(AuctionError::InvalidProductType);
+    }
+    // Replay guard: only a funded escrow can be released, exactly once
+    require!(
+        escrow.status == EscrowStatus::Funded,
+        FulfillmentError::DeliveryAlreadyConfirmed
+    );
+ 
+    // Verify the delivery proof against its on-chain hash
+    verify_delivery_proof(
+        &ctx.accounts.config,
+        &params.delivery_proof,
+        &[to_field(&params.proof_hash)],
+    )?;
+ 
+    // A seller who understated the item's value is paid once their stake covers the sale
     require!(
-        !params.delivery_proof.is_empty(),
-        FulfillmentError::InvalidDeliveryProof
+        ctx.accounts.seller_stake.amount >= escrow.required_seller_stake,
+        ProfileError::InsufficientStake
     );
  
-    // Calculate payment distribution
+    // A private settlement's amounts are only revealed along with the blinding that opens
+    // the commitment published at settlement
+    let settlement_blinding = if auction.private_settlement {
+        let blinding = params
+            .settlement_blinding
+            .ok_or(AuctionError::SettlementCommitmentMismatch)?;
+        require!(
+            auction.opens_settlement_commitment(&blinding),
+            AuctionError::SettlementCommitmentMismatch
+        );
+        Some(blinding)
+    } else {
+        None
+    };
+ 
+    // Physical goods can't be delivered without a shipping address
+    if auction.product_type == ProductType::Physical {
+        require!(
+            ctx.accounts.fulfillment.has_shipping_info(),
+            FulfillmentError::ShippingAddressRequired
+        );
+    }
+ 
+    // Flip the status before moving funds so a re-entrant call fails fast
+    escrow.status = EscrowStatus::Released;
+    escrow.released_at = Some(clock.unix_timestamp);
+    ctx.accounts.fulfillment.delivery_confirmed_at = Some(clock.unix_timestamp);
+ 
+    // Calculate payment distribution; the fee is charged on the full sale price,
+    // while any settlement reimbursement has already left the escrow
     let payment_amount = escrow.amount;
-    let platform_fee = config.calculate_fee(payment_amount);
-    let seller_receives = payment_amount - platform_fee;
+    let platform_fee = config
+        .calculate_fee(
+            escrow.gross_amount(),
+            &escrow.token_mint,
+            ctx.accounts.product_metadata.category,
+            seller_profile.reputation_score,
+        )
+        .min(payment_amount);
+    let seller_receives = payment_amount
+        .checked_sub(platform_fee)
+        .ok_or(ConfigError::ArithmeticOverflow)?;
+ 
+    // Profile volume is tracked in USD cents, falling back to the settlement estimate
+    let volume_usd_cents = match &ctx.accounts.price_feed {
+        Some(price_feed) => price_feed.to_usd_cents(payment_amount)?,
+        None => escrow.estimated_value_usd_cents,
+    };
  
     let auction_key = auction.key();
-    let escrow_vault_seeds = &[
-        b"escrow_vault".as_ref(),
+    let escrow_seeds = &[
+        b"escrow".as_ref(),
         auction_key.as_ref(),
-        &[ctx.bumps.escrow_vault],
+        &[escrow.bump],
     ];
  
     // Transfer platform fee
@@ -116,9 +221,9 @@ pub fn handler(ctx: Context<ConfirmDelivery>, params: ConfirmDeliveryParams) ->
             Transfer {
                 from: ctx.accounts.escrow_vault.to_account_info(),
                 to: ctx.accounts.fee_collector.to_account_info(),
-                authority: ctx.accounts.escrow_vault.to_account_info(),
+                authority: escrow.to_account_info(),
             },
-            &[escrow_vault_seeds],
+            &[escrow_seeds],
         ),
         platform_fee,
     )?;
@@ -130,22 +235,25 @@ pub fn handler(ctx: Context<ConfirmDelivery>, params: ConfirmDeliveryParams) ->
             Transfer {
                 from: ctx.accounts.escrow_vault.to_account_info(),
                 to: ctx.accounts.seller_token_account.to_account_info(),
-                authority: ctx.accounts.escrow_vault.to_account_info(),
+                authority: escrow.to_account_info(),
             },
-            &[escrow_vault_seeds],
+            &[escrow_seeds],
         ),
         seller_receives,
     )?;
  
-    // Update escrow state
-    escrow.status = EscrowStatus::Released;
-    escrow.released_at = Some(clock.unix_timestamp);
- 
     // Update seller reputation
-    seller_profile.update_after_auction(true, true, params.seller_rating);
+    seller_profile.update_after_auction(true, true, params.seller_rating, volume_usd_cents);
  
     // Update buyer reputation
-    buyer_profile.update_after_auction(false, true, None);
+    buyer_profile.update_after_auction(false, true, None, volume_usd_cents);
+ 
+    // A private settlement's committed amounts are emitted once delivery is confirmed
+    let (revealed_winning_amount, revealed_second_price) = if auction.private_settlement {
+        (auction.winning_amount, auction.second_price)
+    } else {
+        (None, None)
+    };
  
     // Emit events
     emit!(DeliveryConfirmed {
@@ -154,7 +262,11 @@ pub fn handler(ctx: Context<ConfirmDelivery>, params: ConfirmDeliveryParams) ->
         buyer: ctx.accounts.buyer.key(),
         seller: auction.seller,
         proof_hash: params.proof_hash,
+        revealed_winning_amount,
+        revealed_second_price,
+        settlement_blinding,
         timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
     });
  
     emit!(EscrowReleased {
@@ -164,6 +276,7 @@ pub fn handler(ctx: Context<ConfirmDelivery>, params: ConfirmDeliveryParams) ->
         amount: seller_receives,
         platform_fee,
         timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
     });
  
     msg!(
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, transfer, Transfer};
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::{EscrowRefunded, ReturnCompleted, refund_reasons};
+ 
+#[derive(Accounts)]
+pub struct ConfirmReturn<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        mut,
+        seeds = [b"dispute", dispute.auction_id.as_ref()],
+        bump = dispute.bump,
+        constraint =
+            dispute.status == DisputeStatus::AwaitingReturn
+            @ DisputeError::InvalidDisputeState
+    )]
+    pub dispute: Account<'info, Dispute>,
+ 
+    #[account(
+        mut,
+        seeds = [b"escrow", dispute.auction_id.as_ref()],
+        bump = escrow.bump,
+        constraint = escrow.status == EscrowStatus::Disputed @ EscrowError::InvalidEscrowState
+    )]
+    pub escrow: Account<'info, EscrowAccount>,
+ 
+    #[account(
+        mut,
+        seeds = [b"escrow_vault", dispute.auction_id.as_ref(), escrow.token_mint.as_ref()],
+        bump = escrow.vault_bump
+    )]
+    pub escrow_vault: Account<'info, TokenAccount>,
+ 
+    #[account(
+        mut,
+        constraint = buyer_token_account.owner == dispute.buyer,
+        constraint = buyer_token_account.mint == escrow.token_mint @ EscrowError::InvalidTokenMint
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+ 
+    /// The seller confirming receipt, or anyone once the return window has lapsed
+    pub caller: Signer<'info>,
+ 
+    pub token_program: Program<'info, Token>,
+}
+ 
+pub fn handler(ctx: Context<ConfirmReturn>) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let escrow = &mut ctx.accounts.escrow;
+    let stats = &mut ctx.accounts.stats;
+    let clock = Clock::get()?;
+ 
+    let confirmed_by_seller = ctx.accounts.caller.key() == dispute.seller;
+    require!(
+        dispute.can_complete_return(confirmed_by_seller, clock.unix_timestamp),
+        DisputeError::ReturnPending
+    );
+    let refund_amount = dispute
+        .return_tracking
+        .map_or(0, |tracking| tracking.refund_amount);
+ 
+    // Refund the buyer from the funds held since resolution
+    let auction_id = dispute.auction_id;
+    let escrow_seeds = &[b"escrow".as_ref(), auction_id.as_ref(), &[escrow.bump]];
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.escrow_vault.to_account_info(),
+                to: ctx.accounts.buyer_token_account.to_account_info(),
+                authority: escrow.to_account_info(),
+            },
+            &[escrow_seeds],
+        ),
+        refund_amount,
+    )?;
+ 
+    escrow.status = EscrowStatus::Refunded;
+    escrow.released_at = Some(clock.unix_timestamp);
+    dispute.complete_return();
+ 
+    emit!(EscrowRefunded {
+        escrow_id: escrow.key(),
+        auction_id,
+        recipient: dispute.buyer,
+        amount: refund_amount,
+        reason: refund_reasons::DISPUTE_RESOLVED,
+        timestamp: clock.unix_timestamp,
+        event_seq: stats.next_event_seq(),
+    });
+ 
+    emit!(ReturnCompleted {
+        dispute_id: dispute.key(),
+        auction_id,
+        buyer: dispute.buyer,
+        amount: refund_amount,
+        timed_out: !confirmed_by_seller,
+        timestamp: clock.unix_timestamp,
+        event_seq: stats.next_event_seq(),
+    });
+ 
+    msg!(
+        "Return completed for dispute {}: {} refunded to {}",
+        dispute.key(),
+        refund_amount,
+        dispute.buyer
+    );
+ 
+    Ok(())
+}
\ No newline at end of file
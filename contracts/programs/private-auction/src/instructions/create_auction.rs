@@ -27,12 +27,35 @@ pub struct CreateAuctionParams {
     pub images: Vec<String>,
     /// Payment token mint
     pub payment_mint: Pubkey,
+    /// Additional mints bidders may pay in (empty = payment_mint only)
+    pub accepted_mints: Vec<Pubkey>,
     /// Minimum bid increment
     pub min_bid_increment: u64,
+    /// Minimum acceptable bid in the payment mint
+    pub min_bid: u64,
+    /// Optional cap on any single bid in the payment mint
+    pub max_bid_cap: Option<u64>,
+    /// Reserve commitment is in USD cents (checked via the oracle at settlement)
+    pub reserve_in_usd: bool,
     /// Bid collateral required
     pub bid_collateral: u64,
+    /// How bid collateral is sized (flat or a share of each bid's maximum)
+    pub collateral_mode: CollateralMode,
+    /// Mint collateral is posted in, e.g. a stablecoin (None = the bid's payment mint)
+    pub collateral_mint: Option<Pubkey>,
+    /// Minimum bidder reputation score, to keep out sybil accounts (None = open bidding)
+    pub min_bidder_reputation: Option<u16>,
+    /// Reimburse whoever settles the auction out of the sale proceeds
+    pub seller_pays_settlement: bool,
+    /// Keep the winning amount out of settlement events until delivery is confirmed
+    /// (events only; the amounts stay readable from account state)
+    pub private_settlement: bool,
+    /// Estimated item value in USD cents (high-value listings need more stake)
+    pub estimated_value_usd_cents: u64,
     /// NFT mint (if NFT auction)
     pub nft_mint: Option<Pubkey>,
+    /// Quantity of the token to auction (1 for a unique NFT, more for SFT editions)
+    pub token_amount: u64,
     /// Shipping options (for physical products)
     pub shipping: Option<ShippingOptions>,
     /// Digital delivery options
@@ -90,7 +113,7 @@ pub struct CreateAuction<'info> {
         payer = seller,
         token::mint = payment_mint,
         token::authority = escrow,
-        seeds = [b"escrow_vault", auction.key().as_ref()],
+        seeds = [b"escrow_vault", auction.key().as_ref(), payment_mint.key().as_ref()],
         bump
     )]
     pub escrow_vault: Account<'info, TokenAccount>,
@@ -102,6 +125,13 @@ pub struct CreateAuction<'info> {
     )]
     pub seller_profile: Account<'info, UserProfile>,
  
+    #[account(
+        seeds = [b"reputation_stake", seller.key().as_ref()],
+        bump = seller_stake.bump
+    )]
+    pub seller_stake: Account<'info, ReputationStake>,
+ 
+    #[account(constraint = payment_mint.key() == params.payment_mint @ EscrowError::InvalidTokenMint)]
     pub payment_mint: Account<'info, Mint>,
  
     /// NFT mint (optional, for NFT auctions)
@@ -119,6 +149,18 @@ pub struct CreateAuction<'info> {
     #[account(mut)]
     pub nft_escrow_account: Option<Account<'info, TokenAccount>>,
  
+    /// CHECK: Relist guard for this seller and NFT, possibly uninitialized (required for NFT
+    /// auctions; read by RelistGuard::check)
+    #[account(
+        seeds = [
+            b"relist_guard",
+            seller.key().as_ref(),
+            params.nft_mint.unwrap_or_default().as_ref()
+        ],
+        bump
+    )]
+    pub relist_guard: Option<AccountInfo<'info>>,
+ 
     #[account(mut)]
     pub seller: Signer<'info>,
  
@@ -126,6 +168,15 @@ pub struct CreateAuction<'info> {
     #[account(constraint = state_tree.key() == config.state_tree)]
     pub state_tree: AccountInfo<'info>,
  
+    /// CHECK: Seller's storefront aggregate at its seed-derived address. Always passed, and
+    /// updated whenever the seller has created one, so its listing count can't drift.
+    #[account(
+        mut,
+        seeds = [b"storefront", seller.key().as_ref()],
+        bump
+    )]
+    pub storefront: UncheckedAccount<'info>,
+ 
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
@@ -134,46 +185,206 @@ pub fn handler(ctx: Context<CreateAuction>, params: CreateAuctionParams) -> Resu
     let config = &ctx.accounts.config;
     let stats = &mut ctx.accounts.stats;
     let auction = &mut ctx.accounts.auction;
-    let product = &mut ctx.accounts.product_metadata;
-    let escrow = &mut ctx.accounts.escrow;
     let clock = Clock::get()?;
  
     // Validate parameters
-    config.validate_auction_params(params.duration, params.bid_collateral)?;
+    params.validate(
+        config,
+        ctx.accounts.seller_stake.amount,
+        ctx.accounts.nft_token_account.is_some(),
+    )?;
+    config.check_listing_capacity(SellerStorefront::load(&ctx.accounts.storefront)?.as_ref(), 1)?;
+ 
+    init_listing(
+        config,
+        stats,
+        ctx.accounts.seller.key(),
+        NewListing {
+            auction,
+            product: &mut ctx.accounts.product_metadata,
+            escrow: &mut ctx.accounts.escrow,
+            escrow_vault: ctx.accounts.escrow_vault.key(),
+            escrow_vault_bump: ctx.bumps.escrow_vault,
+            listing_seed: clock.unix_timestamp,
+            auction_bump: ctx.bumps.auction,
+            product_bump: ctx.bumps.product_metadata,
+            escrow_bump: ctx.bumps.escrow,
+        },
+        params,
+        clock.unix_timestamp,
+    )?;
+ 
+    // Transfer NFT to escrow if NFT auction; the listing only goes live once the
+    // escrow actually holds it
+    if auction.product_type == ProductType::Nft {
+        // Throttle cancel/relist cycles on the same NFT
+        let relist_guard = ctx
+            .accounts
+            .relist_guard
+            .as_ref()
+            .ok_or(AuctionError::InvalidProductType)?;
+        RelistGuard::check(relist_guard, config.relist_cooldown, clock.unix_timestamp)?;
  
-    require!(
-        config.is_mint_supported(&params.payment_mint),
-        ConfigError::UnsupportedMint
+        let (Some(nft_source), Some(nft_dest)) = (
+            &ctx.accounts.nft_token_account,
+            ctx.accounts.nft_escrow_account.as_mut(),
+        ) else {
+            return err!(AuctionError::InvalidProductType);
+        };
+        require!(
+            Some(nft_dest.mint) == auction.nft_mint && nft_dest.owner == ctx.accounts.escrow.key(),
+            AuctionError::InvalidProductType
+        );
+ 
+        transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: nft_source.to_account_info(),
+                    to: nft_dest.to_account_info(),
+                    authority: ctx.accounts.seller.to_account_info(),
+                },
+            ),
+            auction.token_amount,
+        )?;
+ 
+        nft_dest.reload()?;
+        require!(
+            nft_dest.amount == auction.token_amount,
+            AuctionError::InvalidProductType
+        );
+    }
+ 
+    // Update stats
+    stats.auction_created(clock.unix_timestamp);
+    SellerStorefront::update(&ctx.accounts.storefront, |storefront| {
+        storefront.listing_created(clock.unix_timestamp);
+    })?;
+ 
+    msg!(
+        "Auction {} created by {} ending at {}",
+        auction.key(),
+        ctx.accounts.seller.key(),
+        auction.end_time
     );
  
-    // Validate product type specific requirements
-    match params.product_type {
-        ProductType::Nft => {
-            require!(params.nft_mint.is_some(), AuctionError::InvalidProductType);
+    Ok(())
+}
+ 
+impl CreateAuctionParams {
+    /// Validate the listing against the program config and the seller's stake
+    pub fn validate(
+        &self,
+        config: &ProgramConfig,
+        seller_stake: u64,
+        has_nft_source: bool,
+    ) -> Result<()> {
+        config.validate_auction_params(self.duration, self.bid_collateral)?;
+        if let Some(cap) = self.max_bid_cap {
+            require!(cap > 0 && cap >= self.min_bid, ConfigError::InvalidParameter);
+        }
+        if let Some(min_reputation) = self.min_bidder_reputation {
             require!(
-                ctx.accounts.nft_token_account.is_some(),
-                AuctionError::InvalidProductType
+                min_reputation <= ProgramConfig::MAX_REPUTATION,
+                ConfigError::InvalidParameter
             );
         }
-        ProductType::Physical => {
-            require!(params.shipping.is_some(), AuctionError::InvalidProductType);
+        if let CollateralMode::Percentage { bps } = self.collateral_mode {
+            require!(
+                bps > 0 && bps <= 10_000,
+                AuctionError::CollateralTooHigh
+            );
         }
-        ProductType::Digital => {
+ 
+        require!(
+            config.is_mint_supported(&self.payment_mint),
+            ConfigError::UnsupportedMint
+        );
+        if let Some(collateral_mint) = self.collateral_mint {
             require!(
-                params.digital_delivery.is_some(),
-                AuctionError::InvalidProductType
+                config.is_mint_supported(&collateral_mint),
+                ConfigError::UnsupportedMint
             );
         }
-        ProductType::Service => {
+ 
+        // Sellers must have stake at risk before listing (re-checked against the
+        // oracle-valued payment at settlement, since the estimate is the seller's own)
+        require!(
+            seller_stake >= config.required_seller_stake(self.estimated_value_usd_cents),
+            ProfileError::InsufficientStake
+        );
+ 
+        // Validate alternate payment mints
+        require!(
+            self.accepted_mints.len() <= AuctionState::MAX_ACCEPTED_MINTS,
+            ConfigError::InvalidParameter
+        );
+        for (i, mint) in self.accepted_mints.iter().enumerate() {
+            require!(config.is_mint_supported(mint), ConfigError::UnsupportedMint);
             require!(
-                params.service_details.is_some(),
-                AuctionError::InvalidProductType
+                *mint != self.payment_mint && !self.accepted_mints[..i].contains(mint),
+                ConfigError::InvalidParameter
             );
         }
+ 
+        require!(
+            self.category.is_valid_for(self.product_type),
+            AuctionError::CategoryProductMismatch
+        );
+ 
+        // Validate product type specific requirements
+        match self.product_type {
+            ProductType::Nft => {
+                require!(self.nft_mint.is_some(), AuctionError::InvalidProductType);
+                require!(self.token_amount > 0, AuctionError::InvalidTokenAmount);
+                require!(has_nft_source, AuctionError::InvalidProductType);
+            }
+            ProductType::Physical => {
+                require!(self.shipping.is_some(), AuctionError::InvalidProductType);
+            }
+            ProductType::Digital => {
+                require!(
+                    self.digital_delivery.is_some(),
+                    AuctionError::InvalidProductType
+                );
+            }
+            ProductType::Service => {
+                require!(
+                    self.service_details.is_some(),
+                    AuctionError::InvalidProductType
+                );
+            }
+        }
+ 
+        Ok(())
     }
+}
+ 
+/// Freshly created accounts backing a single listing
+pub(crate) struct NewListing<'a, 'info> {
+    pub auction: &'a mut Account<'info, AuctionState>,
+    pub product: &'a mut Account<'info, ProductMetadata>,
+    pub escrow: &'a mut Account<'info, EscrowAccount>,
+    pub escrow_vault: Pubkey,
+    pub escrow_vault_bump: u8,
+    pub listing_seed: i64,
+    pub auction_bump: u8,
+    pub product_bump: u8,
+    pub escrow_bump: u8,
+}
+ 
+/// Initialize a listing's auction, product metadata and escrow from validated params
+pub(crate) fn init_listing(
+    config: &ProgramConfig,
+    stats: &mut ProgramStats,
+    seller: Pubkey,
+    listing: NewListing,
+    params: CreateAuctionParams,
+    start_time: i64,
+) -> Result<()> {
+    let NewListing { auction, product, escrow, .. } = listing;
  
     // Calculate timestamps
-    let start_time = clock.unix_timestamp;
     let end_time = start_time + params.duration;
     let reveal_duration = if params.reveal_duration > 0 {
         params.reveal_duration
@@ -182,16 +393,20 @@ pub fn handler(ctx: Context<CreateAuction>, params: CreateAuctionParams) -> Resu
     };
  
     // Initialize auction state
+    auction.schema_version = AuctionState::SCHEMA_VERSION;
     auction.auction_id = auction.key();
-    auction.seller = ctx.accounts.seller.key();
+    auction.seller = seller;
     auction.product_type = params.product_type;
     auction.reserve_price_hash = params.reserve_price_hash;
     auction.start_time = start_time;
     auction.end_time = end_time;
     auction.reveal_duration = reveal_duration;
+    auction.late_reveal_grace = config.late_reveal_grace;
     auction.status = AuctionStatus::Active;
     auction.bid_count = 0;
     auction.revealed_count = 0;
+    auction.next_bid_index = 0;
+    auction.settlement_cursor = 0;
     auction.bid_merkle_root = [0u8; 32];
     auction.product_metadata = product.key();
     auction.escrow_account = escrow.key();
@@ -199,11 +414,41 @@ pub fn handler(ctx: Context<CreateAuction>, params: CreateAuctionParams) -> Resu
     auction.winning_amount = None;
     auction.second_price = None;
     auction.nft_mint = params.nft_mint;
+    auction.token_amount = params.token_amount;
     auction.payment_mint = params.payment_mint;
+    auction.accepted_mints = params.accepted_mints;
+    auction.winning_mint = None;
+    auction.winning_value = None;
+    auction.second_value = None;
+    auction.runner_up = None;
+    auction.runner_up_amount = None;
+    auction.runner_up_mint = None;
+    auction.winner_committed_at = None;
+    auction.runner_up_committed_at = None;
+    auction.claim_deadline =
+        end_time + reveal_duration + config.late_reveal_grace + config.claim_window;
+    auction.winner_revealed_at = None;
+    auction.leader_changes = 0;
+    auction.top_bid_values = [0; AuctionState::TOP_BIDS];
+    auction.provisional_ranking = BidRanking::default();
+    auction.settlement_commitment = [0u8; 32];
+    auction.keeper_rewarded = false;
+    auction.reserve_value = None;
+    auction.reserve_in_usd = params.reserve_in_usd;
+    auction.reserve_usd_cents = None;
+    auction.estimated_value_usd_cents = params.estimated_value_usd_cents;
     auction.min_bid_increment = params.min_bid_increment;
+    auction.min_bid = params.min_bid;
+    auction.max_bid_cap = params.max_bid_cap;
     auction.bid_collateral = params.bid_collateral;
+    auction.collateral_mode = params.collateral_mode;
+    auction.collateral_mint = params.collateral_mint;
+    auction.min_bidder_reputation = params.min_bidder_reputation;
+    auction.seller_pays_settlement = params.seller_pays_settlement;
+    auction.private_settlement = params.private_settlement;
     auction.per_session_id = [0u8; 32]; // Will be set by PER integration
-    auction.bump = ctx.bumps.auction;
+    auction.listing_seed = listing.listing_seed;
+    auction.bump = listing.auction_bump;
  
     // Initialize product metadata
     product.product_id = product.key();
@@ -214,57 +459,48 @@ pub fn handler(ctx: Context<CreateAuction>, params: CreateAuctionParams) -> Resu
     product.title = params.title;
     product.description = params.description;
     product.images = params.images;
-    product.seller = ctx.accounts.seller.key();
+    product.seller = seller;
     product.shipping = params.shipping;
     product.digital_delivery = params.digital_delivery;
     product.service_details = params.service_details;
     product.nft_mint = params.nft_mint;
-    product.created_at = clock.unix_timestamp;
+    product.created_at = start_time;
+    product.updated_at = start_time;
     product.verified = false;
-    product.bump = ctx.bumps.product_metadata;
+    product.bump = listing.product_bump;
+ 
+    // Reject metadata that would overflow the account before it is serialized
+    product.validate()?;
  
     // Initialize escrow
     escrow.escrow_id = escrow.key();
     escrow.auction_id = auction.key();
     escrow.amount = 0;
+    escrow.required_amount = 0;
+    escrow.settlement_reimbursement = 0;
+    escrow.funding_deadline = 0;
+    escrow.grace_deadline = 0;
+    escrow.late_penalty_bps_per_day = 0;
+    escrow.max_late_penalty_bps = 0;
+    escrow.late_penalty = 0;
+    escrow.required_seller_stake = 0;
     escrow.token_mint = params.payment_mint;
-    escrow.token_account = ctx.accounts.escrow_vault.key();
-    escrow.beneficiary = ctx.accounts.seller.key();
+    escrow.token_account = listing.escrow_vault;
+    escrow.vault_bump = listing.escrow_vault_bump;
+    escrow.beneficiary = seller;
     escrow.payer = None;
+    escrow.estimated_value_usd_cents = params.estimated_value_usd_cents;
     escrow.security_level = EscrowSecurityLevel::Standard;
     escrow.release_conditions = ReleaseConditions::default();
     escrow.status = EscrowStatus::Created;
-    escrow.created_at = clock.unix_timestamp;
+    escrow.created_at = start_time;
     escrow.released_at = None;
-    escrow.bump = ctx.bumps.escrow;
- 
-    // Transfer NFT to escrow if NFT auction
-    if params.product_type == ProductType::Nft {
-        if let (Some(nft_source), Some(nft_dest)) = (
-            &ctx.accounts.nft_token_account,
-            &ctx.accounts.nft_escrow_account,
-        ) {
-            transfer(
-                CpiContext::new(
-                    ctx.accounts.token_program.to_account_info(),
-                    Transfer {
-                        from: nft_source.to_account_info(),
-                        to: nft_dest.to_account_info(),
-                        authority: ctx.accounts.seller.to_account_info(),
-                    },
-                ),
-                1,
-            )?;
-        }
-    }
- 
-    // Update stats
-    stats.auction_created();
+    escrow.bump = listing.escrow_bump;
  
     // Emit event
     emit!(AuctionCreated {
         auction_id: auction.key(),
-        seller: ctx.accounts.seller.key(),
+        seller,
         product_type: params.product_type as u8,
         category: params.category as u8,
         ipfs_hash: params.ipfs_hash,
@@ -272,16 +508,9 @@ pub fn handler(ctx: Context<CreateAuction>, params: CreateAuctionParams) -> Resu
         end_time,
         payment_mint: params.payment_mint,
         bid_collateral: params.bid_collateral,
-        timestamp: clock.unix_timestamp,
+        timestamp: start_time,
+        event_seq: stats.next_event_seq(),
     });
  
-    msg!(
-        "Auction {} created by {} ending at {}",
-        auction.key(),
-        ctx.accounts.seller.key(),
-        end_time
-    );
- 
     Ok(())
-}
- 
\ No newline at end of file
+}
\ No newline at end of file
@@ -0,0 +1,315 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{
+    self, allocate, assign, create_account, Allocate, Assign, CreateAccount,
+};
+use anchor_spl::token::{
+    initialize_account3, transfer, InitializeAccount3, Mint, Token, TokenAccount, Transfer,
+};
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::instructions::create_auction::{init_listing, CreateAuctionParams, NewListing};
+ 
+#[derive(Accounts)]
+pub struct CreateAuctionsBatch<'info> {
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.paused @ ConfigError::ProgramPaused
+    )]
+    pub config: Account<'info, ProgramConfig>,
+ 
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        seeds = [b"user_profile", seller.key().as_ref()],
+        bump = seller_profile.bump,
+        constraint = seller_profile.reputation_score >= config.min_seller_reputation @ ProfileError::InsufficientReputation
+    )]
+    pub seller_profile: Account<'info, UserProfile>,
+ 
+    #[account(
+        seeds = [b"reputation_stake", seller.key().as_ref()],
+        bump = seller_stake.bump
+    )]
+    pub seller_stake: Account<'info, ReputationStake>,
+ 
+    #[account(mut)]
+    pub seller: Signer<'info>,
+ 
+    /// CHECK: Light Protocol state tree
+    #[account(constraint = state_tree.key() == config.state_tree)]
+    pub state_tree: AccountInfo<'info>,
+ 
+    /// CHECK: Seller's storefront aggregate at its seed-derived address. Always passed, and
+    /// updated whenever the seller has created one, so its listing count can't drift.
+    #[account(
+        mut,
+        seeds = [b"storefront", seller.key().as_ref()],
+        bump
+    )]
+    pub storefront: UncheckedAccount<'info>,
+ 
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    // remaining_accounts, per listing in params order:
+    // [auction, product_metadata, escrow, escrow_vault, payment_mint]
+    // followed by [nft_token_account, nft_escrow_account, relist_guard] for NFT listings
+}
+ 
+/// Accounts per listing, plus the NFT source, escrow and relist guard for NFT listings
+const LISTING_ACCOUNTS: usize = 5;
+const NFT_ACCOUNTS: usize = 3;
+ 
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateAuctionsBatch<'info>>,
+    params: Vec<CreateAuctionParams>,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let seller = &ctx.accounts.seller;
+    let clock = Clock::get()?;
+ 
+    require!(
+        !params.is_empty() && params.len() <= AuctionState::MAX_CREATE_BATCH,
+        AuctionError::BatchTooLarge
+    );
+    config.check_listing_capacity(
+        SellerStorefront::load(&ctx.accounts.storefront)?.as_ref(),
+        params.len() as u32,
+    )?;
+ 
+    // Validate every listing and the account layout up front so the batch is all-or-nothing
+    let mut expected_accounts = 0;
+    for item in params.iter() {
+        let is_nft = item.product_type == ProductType::Nft;
+        item.validate(config, ctx.accounts.seller_stake.amount, is_nft)?;
+        expected_accounts += LISTING_ACCOUNTS + if is_nft { NFT_ACCOUNTS } else { 0 };
+    }
+    require!(
+        ctx.remaining_accounts.len() == expected_accounts,
+        AuctionError::InvalidBatchAccounts
+    );
+ 
+    let listing_count = params.len();
+    let mut accounts = ctx.remaining_accounts.iter();
+    let mut next_account = || accounts.next().ok_or(AuctionError::InvalidBatchAccounts);
+ 
+    let start_time = clock.unix_timestamp;
+    for (i, item) in params.into_iter().enumerate() {
+        // Every listing starts now; each gets its own address seed from its batch index
+        let listing_seed = AuctionState::batch_listing_seed(start_time, i);
+ 
+        let auction_info = next_account()?;
+        let product_info = next_account()?;
+        let escrow_info = next_account()?;
+        let escrow_vault_info = next_account()?;
+        let payment_mint = Account::<Mint>::try_from(next_account()?)?;
+        require_keys_eq!(
+            payment_mint.key(),
+            item.payment_mint,
+            EscrowError::InvalidTokenMint
+        );
+ 
+        let seller_key = seller.key();
+        let seed_bytes = listing_seed.to_le_bytes();
+        let auction_seeds: &[&[u8]] = &[b"auction", seller_key.as_ref(), &seed_bytes];
+        let auction_bump = create_pda(
+            &ctx,
+            auction_info,
+            auction_seeds,
+            AuctionState::LEN,
+            &crate::ID,
+        )?;
+ 
+        let auction_key = auction_info.key();
+        let product_bump = create_pda(
+            &ctx,
+            product_info,
+            &[b"product", auction_key.as_ref()],
+            ProductMetadata::LEN,
+            &crate::ID,
+        )?;
+        let escrow_bump = create_pda(
+            &ctx,
+            escrow_info,
+            &[b"escrow", auction_key.as_ref()],
+            EscrowAccount::LEN,
+            &crate::ID,
+        )?;
+ 
+        // Escrow vault for the payment mint, owned by the escrow PDA
+        let payment_mint_key = payment_mint.key();
+        let escrow_vault_bump = create_pda(
+            &ctx,
+            escrow_vault_info,
+            &[b"escrow_vault", auction_key.as_ref(), payment_mint_key.as_ref()],
+            TokenAccount::LEN,
+            &ctx.accounts.token_program.key(),
+        )?;
+        initialize_account3(CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            InitializeAccount3 {
+                account: escrow_vault_info.clone(),
+                mint: payment_mint.to_account_info(),
+                authority: escrow_info.clone(),
+            },
+        ))?;
+ 
+        let mut auction = Account::<AuctionState>::try_from_unchecked(auction_info)?;
+        let mut product = Account::<ProductMetadata>::try_from_unchecked(product_info)?;
+        let mut escrow = Account::<EscrowAccount>::try_from_unchecked(escrow_info)?;
+ 
+        init_listing(
+            config,
+            &mut ctx.accounts.stats,
+            seller_key,
+            NewListing {
+                auction: &mut auction,
+                product: &mut product,
+                escrow: &mut escrow,
+                escrow_vault: escrow_vault_info.key(),
+                escrow_vault_bump,
+                listing_seed,
+                auction_bump,
+                product_bump,
+                escrow_bump,
+            },
+            item,
+            start_time,
+        )?;
+ 
+        // Move the NFT into escrow
+        if auction.product_type == ProductType::Nft {
+            let nft_source = Account::<TokenAccount>::try_from(next_account()?)?;
+            let mut nft_escrow = Account::<TokenAccount>::try_from(next_account()?)?;
+            require!(
+                Some(nft_source.mint) == auction.nft_mint
+                    && Some(nft_escrow.mint) == auction.nft_mint
+                    && nft_escrow.owner == escrow.key(),
+                AuctionError::InvalidBatchAccounts
+            );
+ 
+            // Throttle cancel/relist cycles on the same NFT
+            let relist_guard = next_account()?;
+            let (expected_guard, _) = Pubkey::find_program_address(
+                &[b"relist_guard", seller.key().as_ref(), nft_source.mint.as_ref()],
+                &crate::ID,
+            );
+            require_keys_eq!(
+                relist_guard.key(),
+                expected_guard,
+                AuctionError::InvalidBatchAccounts
+            );
+            RelistGuard::check(relist_guard, config.relist_cooldown, clock.unix_timestamp)?;
+ 
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: nft_source.to_account_info(),
+                        to: nft_escrow.to_account_info(),
+                        authority: seller.to_account_info(),
+                    },
+                ),
+                auction.token_amount,
+            )?;
+ 
+            // The listing only goes live once the escrow actually holds the NFT
+            nft_escrow.reload()?;
+            require!(
+                nft_escrow.amount == auction.token_amount,
+                AuctionError::InvalidProductType
+            );
+        }
+ 
+        auction.exit(&crate::ID)?;
+        product.exit(&crate::ID)?;
+        escrow.exit(&crate::ID)?;
+ 
+        ctx.accounts.stats.auction_created(clock.unix_timestamp);
+        SellerStorefront::update(&ctx.accounts.storefront, |storefront| {
+            storefront.listing_created(clock.unix_timestamp);
+        })?;
+    }
+ 
+    msg!("{} auctions created by {}", listing_count, seller.key());
+ 
+    Ok(())
+}
+ 
+/// Create a program-derived account at the expected address, returning its bump.
+/// An address someone already sent lamports to is topped up, allocated and assigned
+/// instead, since create_account refuses an account with a balance.
+fn create_pda<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, CreateAuctionsBatch<'info>>,
+    account: &AccountInfo<'info>,
+    seeds: &[&[u8]],
+    space: usize,
+    owner: &Pubkey,
+) -> Result<u8> {
+    let (expected, bump) = Pubkey::find_program_address(seeds, &crate::ID);
+    require_keys_eq!(account.key(), expected, AuctionError::InvalidBatchAccounts);
+ 
+    let bump_seed = [bump];
+    let signer_seeds = [seeds, &[&bump_seed[..]]].concat();
+    let system = ctx.accounts.system_program.to_account_info();
+    let rent = Rent::get()?.minimum_balance(space);
+ 
+    if account.lamports() == 0 {
+        create_account(
+            CpiContext::new_with_signer(
+                system,
+                CreateAccount {
+                    from: ctx.accounts.seller.to_account_info(),
+                    to: account.clone(),
+                },
+                &[&signer_seeds],
+            ),
+            rent,
+            space as u64,
+            owner,
+        )?;
+        return Ok(bump);
+    }
+ 
+    let top_up = rent.saturating_sub(account.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system.clone(),
+                system_program::Transfer {
+                    from: ctx.accounts.seller.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    allocate(
+        CpiContext::new_with_signer(
+            system.clone(),
+            Allocate {
+                account_to_allocate: account.clone(),
+            },
+            &[&signer_seeds],
+        ),
+        space as u64,
+    )?;
+    assign(
+        CpiContext::new_with_signer(
+            system,
+            Assign {
+                account_to_assign: account.clone(),
+            },
+            &[&signer_seeds],
+        ),
+        owner,
+    )?;
+ 
+    Ok(bump)
+}
\ No newline at end of file
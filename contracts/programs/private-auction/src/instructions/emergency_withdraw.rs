@@ -0,0 +1,195 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, transfer, Transfer};
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::EmergencyWithdrawal;
+ 
+#[derive(Accounts)]
+pub struct EmergencyWithdraw<'info> {
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ ConfigError::InvalidAuthority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+ 
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        mut,
+        seeds = [b"auction", auction.seller.as_ref(), &auction.listing_seed.to_le_bytes()],
+        bump = auction.bump
+    )]
+    pub auction: Account<'info, AuctionState>,
+ 
+    /// Escrow that still holds (or may still receive) funds
+    #[account(
+        mut,
+        seeds = [b"escrow", auction.key().as_ref()],
+        bump = escrow.bump,
+        constraint = !matches!(
+            escrow.status,
+            EscrowStatus::Released | EscrowStatus::Refunded | EscrowStatus::Cancelled
+        ) @ EscrowError::InvalidEscrowState
+    )]
+    pub escrow: Account<'info, EscrowAccount>,
+ 
+    /// Escrow vault (required if the escrow holds funds)
+    #[account(
+        mut,
+        constraint = escrow_vault.key() == escrow.token_account @ EscrowError::InvalidEscrowState
+    )]
+    pub escrow_vault: Option<Account<'info, TokenAccount>>,
+ 
+    /// Payer's token account to return escrowed funds to (required if the escrow holds funds)
+    #[account(
+        mut,
+        constraint = Some(payer_token_account.owner) == escrow.payer @ EscrowError::InvalidEscrowState,
+        constraint = payer_token_account.mint == escrow.token_mint @ EscrowError::InvalidTokenMint
+    )]
+    pub payer_token_account: Option<Account<'info, TokenAccount>>,
+ 
+    /// NFT escrow (optional, for NFT auctions)
+    #[account(
+        mut,
+        constraint = nft_escrow.owner == escrow.key(),
+        constraint = Some(nft_escrow.mint) == auction.nft_mint
+    )]
+    pub nft_escrow: Option<Account<'info, TokenAccount>>,
+ 
+    /// Seller's NFT token account (optional, for returning NFT)
+    #[account(
+        mut,
+        constraint = seller_nft_account.owner == auction.seller,
+        constraint = Some(seller_nft_account.mint) == auction.nft_mint
+    )]
+    pub seller_nft_account: Option<Account<'info, TokenAccount>>,
+ 
+    /// CHECK: Seller's storefront aggregate at its seed-derived address. Always passed, and
+    /// updated whenever the seller has created one, so its listing count can't drift.
+    #[account(
+        mut,
+        seeds = [b"storefront", auction.seller.as_ref()],
+        bump
+    )]
+    pub storefront: UncheckedAccount<'info>,
+ 
+    pub authority: Signer<'info>,
+ 
+    pub token_program: Program<'info, Token>,
+}
+ 
+/// Last-resort recovery: unwind an auction stuck in a frozen program, returning
+/// escrowed funds to the payer and any escrowed NFT to the seller. Bidders then
+/// reclaim their full collateral through claim_refund's Cancelled branch.
+pub fn handler(ctx: Context<EmergencyWithdraw>) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    let escrow = &mut ctx.accounts.escrow;
+    let stats = &mut ctx.accounts.stats;
+    let clock = Clock::get()?;
+ 
+    // Paused + timelock + authority: only usable once normal operation has stopped
+    require!(
+        ctx.accounts.config.emergency_withdraw_open(clock.unix_timestamp),
+        ConfigError::EmergencyWithdrawLocked
+    );
+ 
+    let auction_key = auction.key();
+    let escrow_seeds = &[
+        b"escrow".as_ref(),
+        auction_key.as_ref(),
+        &[escrow.bump],
+    ];
+ 
+    // Return the locked payment to whoever funded the escrow
+    let refunded_amount = escrow.amount;
+    if refunded_amount > 0 {
+        let (Some(escrow_vault), Some(payer_token_account)) = (
+            &ctx.accounts.escrow_vault,
+            &ctx.accounts.payer_token_account,
+        ) else {
+            return err!(EscrowError::InvalidEscrowState);
+        };
+ 
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: escrow_vault.to_account_info(),
+                    to: payer_token_account.to_account_info(),
+                    authority: escrow.to_account_info(),
+                },
+                &[escrow_seeds],
+            ),
+            refunded_amount,
+        )?;
+    }
+ 
+    // Return an escrowed NFT to the seller
+    let mut nft_returned = false;
+    if let Some(nft_escrow) = ctx.accounts.nft_escrow.as_ref().filter(|nft| nft.amount > 0) {
+        let seller_nft = ctx
+            .accounts
+            .seller_nft_account
+            .as_ref()
+            .ok_or(AuctionError::InvalidProductType)?;
+ 
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: nft_escrow.to_account_info(),
+                    to: seller_nft.to_account_info(),
+                    authority: escrow.to_account_info(),
+                },
+                &[escrow_seeds],
+            ),
+            nft_escrow.amount,
+        )?;
+        nft_returned = true;
+    }
+ 
+    if matches!(auction.status, AuctionStatus::Active | AuctionStatus::Revealing) {
+        stats.active_auctions = stats.active_auctions.saturating_sub(1);
+        SellerStorefront::update(&ctx.accounts.storefront, |storefront| {
+            storefront.listing_closed(clock.unix_timestamp);
+        })?;
+    }
+    stats.last_updated = clock.unix_timestamp;
+ 
+    // No winner: every bidder, including the former winner, reclaims collateral in full
+    auction.winner = None;
+    auction.status = AuctionStatus::Cancelled;
+ 
+    escrow.amount = 0;
+    escrow.status = if refunded_amount > 0 {
+        EscrowStatus::Refunded
+    } else {
+        EscrowStatus::Cancelled
+    };
+ 
+    emit!(EmergencyWithdrawal {
+        auction_id: auction_key,
+        authority: ctx.accounts.authority.key(),
+        payer: escrow.payer,
+        refunded_amount,
+        nft_returned,
+        bidders_to_refund: auction.bid_count,
+        timestamp: clock.unix_timestamp,
+        event_seq: stats.next_event_seq(),
+    });
+ 
+    msg!(
+        "Emergency withdrawal on auction {}: {} returned to payer",
+        auction_key,
+        refunded_amount
+    );
+ 
+    Ok(())
+}
\ No newline at end of file
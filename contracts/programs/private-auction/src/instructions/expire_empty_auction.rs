@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, transfer, Transfer, close_account, CloseAccount};
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::{AuctionExpired, cancellation_reasons};
+ 
+#[derive(Accounts)]
+pub struct ExpireEmptyAuction<'info> {
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+ 
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        mut,
+        seeds = [b"auction", auction.seller.as_ref(), &auction.listing_seed.to_le_bytes()],
+        bump = auction.bump,
+        constraint = auction.status == AuctionStatus::Active @ AuctionError::InvalidAuctionState
+    )]
+    pub auction: Account<'info, AuctionState>,
+ 
+    #[account(
+        mut,
+        seeds = [b"escrow", auction.key().as_ref()],
+        bump = escrow.bump
+    )]
+    pub escrow: Account<'info, EscrowAccount>,
+ 
+    /// NFT escrow (optional, for NFT auctions)
+    #[account(
+        mut,
+        constraint = nft_escrow.owner == escrow.key(),
+        constraint = Some(nft_escrow.mint) == auction.nft_mint
+    )]
+    pub nft_escrow: Option<Account<'info, TokenAccount>>,
+ 
+    /// Seller's NFT token account (optional, for returning NFT)
+    #[account(
+        mut,
+        constraint = seller_nft_account.owner == auction.seller,
+        constraint = Some(seller_nft_account.mint) == auction.nft_mint
+    )]
+    pub seller_nft_account: Option<Account<'info, TokenAccount>>,
+ 
+    /// CHECK: Seller receives the NFT escrow rent
+    #[account(mut, constraint = seller.key() == auction.seller @ AuctionError::OnlySeller)]
+    pub seller: AccountInfo<'info>,
+ 
+    /// Anyone may expire an empty auction (rewarded from the keeper pool)
+    #[account(mut)]
+    pub caller: Signer<'info>,
+ 
+    /// Keeper reward pool (optional; pays the caller once per auction)
+    #[account(
+        mut,
+        seeds = [b"keeper_pool"],
+        bump = keeper_pool.bump
+    )]
+    pub keeper_pool: Option<Account<'info, KeeperPool>>,
+ 
+    /// CHECK: Seller's storefront aggregate at its seed-derived address. Always passed, and
+    /// updated whenever the seller has created one, so its listing count can't drift.
+    #[account(
+        mut,
+        seeds = [b"storefront", auction.seller.as_ref()],
+        bump
+    )]
+    pub storefront: UncheckedAccount<'info>,
+ 
+    pub token_program: Program<'info, Token>,
+}
+ 
+pub fn handler(ctx: Context<ExpireEmptyAuction>) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    let escrow = &mut ctx.accounts.escrow;
+    let stats = &mut ctx.accounts.stats;
+    let clock = Clock::get()?;
+ 
+    require!(
+        ctx.accounts.config.phase_time(clock.unix_timestamp) >= auction.end_time,
+        AuctionError::AuctionNotEnded
+    );
+    require!(auction.bid_count == 0, AuctionError::AuctionHasBids);
+ 
+    // Return NFT to seller if NFT auction
+    let mut nft_returned = false;
+    if auction.product_type == ProductType::Nft {
+        let (Some(nft_escrow), Some(seller_nft)) = (
+            &ctx.accounts.nft_escrow,
+            &ctx.accounts.seller_nft_account,
+        ) else {
+            return err!(AuctionError::InvalidProductType);
+        };
+ 
+        let auction_key = auction.key();
+        let escrow_seeds = &[
+            b"escrow".as_ref(),
+            auction_key.as_ref(),
+            &[escrow.bump],
+        ];
+ 
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: nft_escrow.to_account_info(),
+                    to: seller_nft.to_account_info(),
+                    authority: escrow.to_account_info(),
+                },
+                &[escrow_seeds],
+            ),
+            auction.token_amount,
+        )?;
+ 
+        // Close the NFT escrow account
+        close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: nft_escrow.to_account_info(),
+                destination: ctx.accounts.seller.to_account_info(),
+                authority: escrow.to_account_info(),
+            },
+            &[escrow_seeds],
+        ))?;
+ 
+        nft_returned = true;
+    }
+ 
+    // Update auction state
+    auction.status = AuctionStatus::Expired;
+ 
+    // Update escrow state
+    escrow.status = EscrowStatus::Cancelled;
+ 
+    // Update stats
+    stats.active_auctions = stats.active_auctions.saturating_sub(1);
+    stats.last_updated = clock.unix_timestamp;
+    SellerStorefront::update(&ctx.accounts.storefront, |storefront| {
+        storefront.listing_closed(clock.unix_timestamp);
+    })?;
+ 
+    // Emit event
+    emit!(AuctionExpired {
+        auction_id: auction.key(),
+        seller: auction.seller,
+        end_time: auction.end_time,
+        nft_returned,
+        reason: cancellation_reasons::NO_BIDS,
+        timestamp: clock.unix_timestamp,
+        event_seq: stats.next_event_seq(),
+    });
+ 
+    // Pay the keeper once per auction
+    if let Some(keeper_pool) = ctx.accounts.keeper_pool.as_mut() {
+        let keeper = ctx.accounts.caller.to_account_info();
+        KeeperPool::reward_keeper(
+            keeper_pool,
+            stats,
+            auction,
+            &keeper,
+            ctx.accounts.config.keeper_reward,
+        )?;
+    }
+ 
+    msg!("Auction {} expired with no bids", auction.key());
+ 
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::RevealWindowExtended;
+ 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ExtendRevealParams {
+    /// Seconds to add to the reveal window
+    pub additional: i64,
+}
+ 
+#[derive(Accounts)]
+#[instruction(params: ExtendRevealParams)]
+pub struct ExtendReveal<'info> {
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.paused @ ConfigError::ProgramPaused
+    )]
+    pub config: Account<'info, ProgramConfig>,
+ 
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        mut,
+        seeds = [b"auction", auction.seller.as_ref(), &auction.listing_seed.to_le_bytes()],
+        bump = auction.bump,
+        constraint = auction.seller == seller.key() @ AuctionError::OnlySeller,
+        constraint =
+            auction.is_active() || auction.is_revealing()
+            @ AuctionError::InvalidAuctionState
+    )]
+    pub auction: Account<'info, AuctionState>,
+ 
+    pub seller: Signer<'info>,
+}
+ 
+pub fn handler(ctx: Context<ExtendReveal>, params: ExtendRevealParams) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let auction = &mut ctx.accounts.auction;
+    let clock = Clock::get()?;
+ 
+    // Only an open reveal window can be extended; late reveals are not reopened
+    require!(
+        config.phase_time(clock.unix_timestamp) < auction.reveal_deadline(),
+        BidError::RevealDeadlinePassed
+    );
+    require!(params.additional > 0, ConfigError::InvalidParameter);
+ 
+    let reveal_duration = auction
+        .reveal_duration
+        .checked_add(params.additional)
+        .ok_or(ConfigError::ArithmeticOverflow)?;
+    require!(
+        reveal_duration <= config.max_reveal_duration,
+        AuctionError::RevealWindowTooLong
+    );
+ 
+    // The winner's funding window moves with the reveal deadline
+    auction.reveal_duration = reveal_duration;
+    auction.claim_deadline += params.additional;
+ 
+    emit!(RevealWindowExtended {
+        auction_id: auction.key(),
+        additional: params.additional,
+        reveal_deadline: auction.reveal_deadline(),
+        timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
+    });
+ 
+    msg!(
+        "Reveal window of auction {} extended by {}s to {}",
+        auction.key(),
+        params.additional,
+        auction.reveal_deadline()
+    );
+ 
+    Ok(())
+}
\ No newline at end of file
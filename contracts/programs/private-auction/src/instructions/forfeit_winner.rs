@@ -0,0 +1,220 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, transfer, Transfer};
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::WinnerForfeited;
+ 
+#[derive(Accounts)]
+pub struct ForfeitWinner<'info> {
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+ 
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        mut,
+        seeds = [b"auction", auction.seller.as_ref(), &auction.listing_seed.to_le_bytes()],
+        bump = auction.bump,
+        constraint = auction.status == AuctionStatus::Revealing @ AuctionError::InvalidAuctionState
+    )]
+    pub auction: Account<'info, AuctionState>,
+ 
+    #[account(
+        mut,
+        seeds = [b"escrow", auction.key().as_ref()],
+        bump = escrow.bump,
+        constraint = escrow.status == EscrowStatus::Funding @ EscrowError::InvalidEscrowState
+    )]
+    pub escrow: Account<'info, EscrowAccount>,
+ 
+    #[account(
+        mut,
+        seeds = [b"escrow_vault", auction.key().as_ref(), escrow.token_mint.as_ref()],
+        bump
+    )]
+    pub escrow_vault: Account<'info, TokenAccount>,
+ 
+    /// Winner's bid, whose collateral is forfeited to the seller
+    #[account(
+        mut,
+        seeds = [b"bid", auction.key().as_ref(), winner.key().as_ref()],
+        bump = winner_bid.bump,
+        constraint = !winner_bid.collateral_returned @ BidError::RefundAlreadyClaimed
+    )]
+    pub winner_bid: Account<'info, BidCommitment>,
+ 
+    #[account(
+        mut,
+        seeds = [b"collateral_pool", winner_bid.collateral_mint.as_ref()],
+        bump
+    )]
+    pub collateral_pool: Account<'info, CollateralPool>,
+ 
+    #[account(
+        mut,
+        constraint = collateral_pool_vault.key() == collateral_pool.token_account
+    )]
+    pub collateral_pool_vault: Account<'info, TokenAccount>,
+ 
+    /// Winner's token account for the refund of partially locked funds
+    #[account(
+        mut,
+        constraint = winner_token_account.owner == winner.key(),
+        constraint = winner_token_account.mint == escrow.token_mint
+    )]
+    pub winner_token_account: Account<'info, TokenAccount>,
+ 
+    /// Seller's token account receiving the forfeited collateral
+    #[account(
+        mut,
+        constraint = seller_token_account.owner == auction.seller,
+        constraint = seller_token_account.mint == winner_bid.collateral_mint
+    )]
+    pub seller_token_account: Account<'info, TokenAccount>,
+ 
+    /// CHECK: Provisional winner who failed to fund the escrow
+    #[account(constraint = Some(winner.key()) == auction.winner @ AuctionError::InvalidAuctionState)]
+    pub winner: AccountInfo<'info>,
+ 
+    /// Anyone may forfeit an unfunded winner (rewarded from the keeper pool)
+    #[account(mut)]
+    pub caller: Signer<'info>,
+ 
+    /// Keeper reward pool (optional; pays the caller once per auction)
+    #[account(
+        mut,
+        seeds = [b"keeper_pool"],
+        bump = keeper_pool.bump
+    )]
+    pub keeper_pool: Option<Account<'info, KeeperPool>>,
+ 
+    /// CHECK: Seller's storefront aggregate at its seed-derived address. Always passed, and
+    /// updated whenever the seller has created one, so its listing count can't drift.
+    #[account(
+        mut,
+        seeds = [b"storefront", auction.seller.as_ref()],
+        bump
+    )]
+    pub storefront: UncheckedAccount<'info>,
+ 
+    pub token_program: Program<'info, Token>,
+}
+ 
+pub fn handler(ctx: Context<ForfeitWinner>) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    let escrow = &mut ctx.accounts.escrow;
+    let winner_bid = &mut ctx.accounts.winner_bid;
+    let collateral_pool = &mut ctx.accounts.collateral_pool;
+    let stats = &mut ctx.accounts.stats;
+    let clock = Clock::get()?;
+ 
+    require!(
+        clock.unix_timestamp > escrow.funding_deadline,
+        EscrowError::FundingWindowOpen
+    );
+ 
+    let auction_key = auction.key();
+ 
+    // Return whatever the winner managed to lock
+    let refunded_amount = escrow.amount;
+    if refunded_amount > 0 {
+        let escrow_seeds = &[
+            b"escrow".as_ref(),
+            auction_key.as_ref(),
+            &[escrow.bump],
+        ];
+ 
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.escrow_vault.to_account_info(),
+                    to: ctx.accounts.winner_token_account.to_account_info(),
+                    authority: escrow.to_account_info(),
+                },
+                &[escrow_seeds],
+            ),
+            refunded_amount,
+        )?;
+    }
+ 
+    // Winner's bid collateral compensates the seller
+    let collateral_forfeited = winner_bid.collateral_deposited;
+    let collateral_mint = winner_bid.collateral_mint;
+    let pool_seeds = &[
+        b"collateral_pool".as_ref(),
+        collateral_mint.as_ref(),
+        &[ctx.bumps.collateral_pool],
+    ];
+ 
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.collateral_pool_vault.to_account_info(),
+                to: ctx.accounts.seller_token_account.to_account_info(),
+                authority: collateral_pool.to_account_info(),
+            },
+            &[pool_seeds],
+        ),
+        collateral_forfeited,
+    )?;
+ 
+    collateral_pool.withdraw(collateral_forfeited)?;
+    winner_bid.collateral_returned = true;
+ 
+    let winner = ctx.accounts.winner.key();
+ 
+    // No winner: remaining bidders reclaim collateral as on an expired auction
+    auction.winner = None;
+    auction.status = AuctionStatus::Expired;
+ 
+    escrow.amount = 0;
+    escrow.status = EscrowStatus::Cancelled;
+ 
+    stats.active_auctions = stats.active_auctions.saturating_sub(1);
+    stats.last_updated = clock.unix_timestamp;
+    SellerStorefront::update(&ctx.accounts.storefront, |storefront| {
+        storefront.listing_closed(clock.unix_timestamp);
+    })?;
+ 
+    emit!(WinnerForfeited {
+        auction_id: auction_key,
+        winner,
+        refunded_amount,
+        collateral_forfeited,
+        promoted_winner: None,
+        timestamp: clock.unix_timestamp,
+        event_seq: stats.next_event_seq(),
+    });
+ 
+    // Pay the keeper once per auction
+    if let Some(keeper_pool) = ctx.accounts.keeper_pool.as_mut() {
+        let keeper = ctx.accounts.caller.to_account_info();
+        KeeperPool::reward_keeper(
+            keeper_pool,
+            stats,
+            auction,
+            &keeper,
+            ctx.accounts.config.keeper_reward,
+        )?;
+    }
+ 
+    msg!(
+        "Winner {} forfeited auction {} ({} collateral to seller)",
+        winner,
+        auction_key,
+        collateral_forfeited
+    );
+ 
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+ 
+use crate::state::*;
+ 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct FundKeeperPoolParams {
+    /// Lamports to deposit
+    pub amount: u64,
+}
+ 
+#[derive(Accounts)]
+pub struct FundKeeperPool<'info> {
+    #[account(
+        mut,
+        seeds = [b"keeper_pool"],
+        bump = keeper_pool.bump
+    )]
+    pub keeper_pool: Account<'info, KeeperPool>,
+ 
+    /// Anyone may top up the pool
+    #[account(mut)]
+    pub funder: Signer<'info>,
+ 
+    pub system_program: Program<'info, System>,
+}
+ 
+pub fn handler(ctx: Context<FundKeeperPool>, params: FundKeeperPoolParams) -> Result<()> {
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.funder.to_account_info(),
+                to: ctx.accounts.keeper_pool.to_account_info(),
+            },
+        ),
+        params.amount,
+    )?;
+ 
+    let keeper_pool = &mut ctx.accounts.keeper_pool;
+    keeper_pool.total_funded += params.amount;
+ 
+    msg!("Keeper pool funded with {} lamports", params.amount);
+ 
+    Ok(())
+}
\ No newline at end of file
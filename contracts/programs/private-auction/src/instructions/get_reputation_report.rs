@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+ 
+use crate::state::*;
+ 
+/// Derived reputation metrics for a profile, from the same formula as its score
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ReputationReport {
+    /// Profile owner
+    pub user: Pubkey,
+    /// Score stored on the profile
+    pub reputation_score: u16,
+    /// Score the current counters produce (differs from the stored score until recomputed)
+    pub computed_score: u16,
+    /// Successful deliveries per auction sold (bps)
+    pub delivery_rate_bps: u32,
+    /// Disputes against the user per completed auction (bps)
+    pub dispute_rate_bps: u32,
+    /// Average rating received (0-50, divide by 10 for stars)
+    pub average_rating: u8,
+    /// Number of ratings received
+    pub rating_count: u32,
+    /// Order of magnitude of total volume in USD cents
+    pub volume_tier: u8,
+    /// Score gained from deliveries
+    pub delivery_bonus: i32,
+    /// Score lost to disputes against the user
+    pub dispute_penalty: i32,
+    /// Score gained from volume
+    pub volume_bonus: i32,
+    /// Score gained or lost from ratings
+    pub rating_bonus: i32,
+    /// Score lost to frivolous disputes and cancellations with bids
+    pub incident_penalty: i32,
+}
+ 
+#[derive(Accounts)]
+pub struct GetReputationReport<'info> {
+    #[account(
+        seeds = [b"user_profile", profile.user_pubkey.as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, UserProfile>,
+}
+ 
+/// Read-only: break a profile's reputation down into the metrics behind its score
+pub fn handler(ctx: Context<GetReputationReport>) -> Result<ReputationReport> {
+    let profile = &ctx.accounts.profile;
+ 
+    Ok(ReputationReport {
+        user: profile.user_pubkey,
+        reputation_score: profile.reputation_score,
+        computed_score: profile.calculate_reputation(),
+        delivery_rate_bps: profile.delivery_rate_bps(),
+        dispute_rate_bps: profile.dispute_rate_bps(),
+        average_rating: profile.average_rating,
+        rating_count: profile.rating_count,
+        volume_tier: profile.volume_tier(),
+        delivery_bonus: profile.delivery_bonus(),
+        dispute_penalty: profile.dispute_penalty(),
+        volume_bonus: profile.volume_bonus(),
+        rating_bonus: profile.rating_bonus(),
+        incident_penalty: profile.incident_penalty(),
+    })
+}
\ No newline at end of file
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+ 
+use crate::state::*;
+use crate::errors::*;
+ 
+#[derive(Accounts)]
+pub struct InitArbitratorFeePool<'info> {
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ ConfigError::InvalidAuthority,
+        constraint = config.is_mint_supported(&mint.key()) @ ConfigError::UnsupportedMint
+    )]
+    pub config: Account<'info, ProgramConfig>,
+ 
+    #[account(
+        init,
+        payer = authority,
+        space = ArbitratorFeePool::LEN,
+        seeds = [b"arbitrator_fee_pool"],
+        bump
+    )]
+    pub arbitrator_fee_pool: Account<'info, ArbitratorFeePool>,
+ 
+    /// Vault holding pooled arbitrator fees, owned by the pool
+    #[account(
+        init,
+        payer = authority,
+        token::mint = mint,
+        token::authority = arbitrator_fee_pool,
+        seeds = [b"arbitrator_fee_vault"],
+        bump
+    )]
+    pub arbitrator_fee_vault: Account<'info, TokenAccount>,
+ 
+    pub mint: Account<'info, Mint>,
+ 
+    #[account(mut)]
+    pub authority: Signer<'info>,
+ 
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+ 
+pub fn handler(ctx: Context<InitArbitratorFeePool>) -> Result<()> {
+    let fee_pool = &mut ctx.accounts.arbitrator_fee_pool;
+ 
+    fee_pool.token_mint = ctx.accounts.mint.key();
+    fee_pool.token_account = ctx.accounts.arbitrator_fee_vault.key();
+    fee_pool.total_accrued = 0;
+    fee_pool.total_withdrawn = 0;
+    fee_pool.bump = ctx.bumps.arbitrator_fee_pool;
+ 
+    msg!(
+        "Arbitrator fee pool {} initialized for mint {}",
+        fee_pool.key(),
+        fee_pool.token_mint
+    );
+ 
+    Ok(())
+}
\ No newline at end of file
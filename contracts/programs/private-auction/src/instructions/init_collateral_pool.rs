@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::CollateralPoolInitialized;
+ 
+#[derive(Accounts)]
+pub struct InitCollateralPool<'info> {
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = config.is_mint_supported(&mint.key()) @ ConfigError::UnsupportedMint
+    )]
+    pub config: Account<'info, ProgramConfig>,
+ 
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        init,
+        payer = payer,
+        space = CollateralPool::LEN,
+        seeds = [b"collateral_pool", mint.key().as_ref()],
+        bump
+    )]
+    pub collateral_pool: Account<'info, CollateralPool>,
+ 
+    /// Vault holding bid collateral, owned by the pool
+    #[account(
+        init,
+        payer = payer,
+        token::mint = mint,
+        token::authority = collateral_pool,
+        seeds = [b"collateral_pool_vault", mint.key().as_ref()],
+        bump
+    )]
+    pub collateral_pool_vault: Account<'info, TokenAccount>,
+ 
+    pub mint: Account<'info, Mint>,
+ 
+    /// Anyone may pay to create the pool for a supported mint
+    #[account(mut)]
+    pub payer: Signer<'info>,
+ 
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+ 
+pub fn handler(ctx: Context<InitCollateralPool>) -> Result<()> {
+    let collateral_pool = &mut ctx.accounts.collateral_pool;
+    let clock = Clock::get()?;
+ 
+    collateral_pool.authority = collateral_pool.key();
+    collateral_pool.token_mint = ctx.accounts.mint.key();
+    collateral_pool.token_account = ctx.accounts.collateral_pool_vault.key();
+    collateral_pool.total_collateral = 0;
+    collateral_pool.total_credited = 0;
+    collateral_pool.penalty_reserve = 0;
+    collateral_pool.active_bids = 0;
+    collateral_pool.bump = ctx.bumps.collateral_pool;
+ 
+    emit!(CollateralPoolInitialized {
+        pool: collateral_pool.key(),
+        token_mint: collateral_pool.token_mint,
+        vault: collateral_pool.token_account,
+        timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
+    });
+ 
+    msg!(
+        "Collateral pool initialized for mint {}",
+        collateral_pool.token_mint
+    );
+ 
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+ 
+use crate::state::*;
+use crate::errors::*;
+ 
+#[derive(Accounts)]
+pub struct InitKeeperPool<'info> {
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ ConfigError::InvalidAuthority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+ 
+    #[account(
+        init,
+        payer = authority,
+        space = KeeperPool::LEN,
+        seeds = [b"keeper_pool"],
+        bump
+    )]
+    pub keeper_pool: Account<'info, KeeperPool>,
+ 
+    #[account(mut)]
+    pub authority: Signer<'info>,
+ 
+    pub system_program: Program<'info, System>,
+}
+ 
+pub fn handler(ctx: Context<InitKeeperPool>) -> Result<()> {
+    let keeper_pool = &mut ctx.accounts.keeper_pool;
+ 
+    keeper_pool.total_funded = 0;
+    keeper_pool.total_paid = 0;
+    keeper_pool.rewards_paid = 0;
+    keeper_pool.bump = ctx.bumps.keeper_pool;
+ 
+    msg!("Keeper pool {} initialized", keeper_pool.key());
+ 
+    Ok(())
+}
\ No newline at end of file
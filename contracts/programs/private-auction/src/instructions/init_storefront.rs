@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+ 
+use crate::state::*;
+ 
+#[derive(Accounts)]
+pub struct InitStorefront<'info> {
+    #[account(
+        init,
+        payer = seller,
+        space = SellerStorefront::LEN,
+        seeds = [b"storefront", seller.key().as_ref()],
+        bump
+    )]
+    pub storefront: Account<'info, SellerStorefront>,
+ 
+    #[account(mut)]
+    pub seller: Signer<'info>,
+ 
+    pub system_program: Program<'info, System>,
+}
+ 
+pub fn handler(ctx: Context<InitStorefront>) -> Result<()> {
+    let storefront = &mut ctx.accounts.storefront;
+    let clock = Clock::get()?;
+ 
+    storefront.seller = ctx.accounts.seller.key();
+    storefront.active_listings = 0;
+    storefront.total_listings = 0;
+    storefront.total_sales = 0;
+    storefront.lifetime_volume = 0;
+    storefront.created_at = clock.unix_timestamp;
+    storefront.last_updated = clock.unix_timestamp;
+    storefront.bump = ctx.bumps.storefront;
+ 
+    msg!("Storefront created for seller {}", storefront.seller);
+ 
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::state::{ProgramConfig, ProgramStats};
+use anchor_spl::token::Mint;
+use crate::state::{MintDisputeBond, MintFeeBounds, ProgramConfig, ProgramStats};
 use crate::errors::ConfigError;
  
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -22,7 +23,69 @@ pub struct InitializeProgramParams {
     pub min_high_value_reputation: u16,
     /// High-value threshold (USD cents)
     pub high_value_threshold: u64,
-    /// Supported payment token mints
+    /// Escrow time-lock for physical products (seconds)
+    pub physical_timelock: i64,
+    /// Escrow time-lock for digital products (seconds)
+    pub digital_timelock: i64,
+    /// Escrow time-lock for services (seconds)
+    pub service_timelock: i64,
+    /// Share of slashed stake paid to the wronged party (basis points)
+    pub slash_split_bps: u16,
+    /// Evidence deadline extension when more info is requested (seconds)
+    pub info_request_extension: i64,
+    /// Evidence submission window for new disputes (seconds)
+    pub evidence_period: i64,
+    /// Resolution window for new disputes (seconds)
+    pub resolution_period: i64,
+    /// Window for a winner to fund the escrow before the runner-up is promoted (seconds)
+    pub claim_window: i64,
+    /// Grace after the reveal deadline for penalized late reveals (seconds)
+    pub late_reveal_grace: i64,
+    /// Minimum time between a user's dispute filings (seconds, 0 disables)
+    pub dispute_cooldown: i64,
+    /// Minimum reputation stake for voting arbitrators
+    pub min_arbitrator_stake: u64,
+    /// Lamports rewarded to callers of permissionless cranks
+    pub keeper_reward: u64,
+    /// Revealed bids required before an auction can settle (at least 1)
+    pub min_reveals_for_settlement: u32,
+    /// Seller reputation above which fees are discounted
+    pub fee_discount_reputation: u16,
+    /// Fee discount at a perfect reputation score (bps)
+    pub max_fee_discount_bps: u16,
+    /// Seconds phase boundaries are deferred by to absorb clock skew (0-60)
+    pub clock_skew_tolerance: i64,
+    /// Bidder reputation that earns a collateral waiver (0 = no waiver)
+    pub collateral_waiver_reputation: u16,
+    /// Share of collateral waived for trusted bidders (bps)
+    pub collateral_waiver_bps: u16,
+    /// Oldest price feed update settlement accepts (seconds)
+    pub max_oracle_age: i64,
+    /// Largest price move since the previous update settlement accepts (bps)
+    pub max_price_deviation_bps: u16,
+    /// Per-mint platform fee floors and ceilings (token base units)
+    pub mint_fee_bounds: Vec<MintFeeBounds>,
+    /// Per-mint bonds a party posts to raise a dispute (token base units)
+    pub dispute_bonds: Vec<MintDisputeBond>,
+    /// Wait before a seller can relist an NFT after cancelling it (seconds, 0 disables)
+    pub relist_cooldown: i64,
+    /// Paid to the settler when the seller covers settlement costs (USD cents)
+    pub settlement_reimbursement_usd_cents: u64,
+    /// Auctions a seller may have open at once (0 = unlimited)
+    pub max_active_auctions_per_seller: u32,
+    /// Longest total reveal window a seller can extend an auction to (seconds)
+    pub max_reveal_duration: i64,
+    /// Window for returning an item on a return-for-refund outcome (seconds)
+    pub return_window: i64,
+    /// Leading share of the reveal window that counts as a prompt reveal (bps)
+    pub early_reveal_window_bps: u16,
+    /// Collateral rebate for prompt revealers, paid from withheld penalties (bps, 0 disables)
+    pub reveal_rebate_bps: u16,
+    /// Penalty per started day a winner funds past the grace period (bps, 0 disables)
+    pub late_funding_penalty_bps_per_day: u16,
+    /// Cap on the late-funding penalty, after which the winner forfeits (bps)
+    pub max_late_funding_penalty_bps: u16,
+    /// Supported payment token mints (each passed in remaining_accounts, in order)
     pub supported_mints: Vec<Pubkey>,
     /// Initial arbitrators
     pub arbitrators: Vec<Pubkey>,
@@ -66,7 +129,28 @@ pub struct InitializeProgram<'info> {
     pub system_program: Program<'info, System>,
 }
  
-pub fn handler(ctx: Context<InitializeProgram>, params: InitializeProgramParams) -> Result<()> {
+/// Check each listed mint against its account in `mint_accounts`, which must be an
+/// initialized SPL mint, so a mistyped address can't be configured
+pub(crate) fn validate_supported_mints<'info>(
+    mints: &[Pubkey],
+    mint_accounts: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    require!(
+        mints.len() <= ProgramConfig::MAX_SUPPORTED_MINTS && mints.len() == mint_accounts.len(),
+        ConfigError::InvalidParameter
+    );
+    for (mint, mint_info) in mints.iter().zip(mint_accounts) {
+        require_keys_eq!(mint_info.key(), *mint, ConfigError::UnsupportedMint);
+        Account::<Mint>::try_from(mint_info).map_err(|_| ConfigError::UnsupportedMint)?;
+    }
+    Ok(())
+}
+ 
+// remaining_accounts: one Mint account per supported_mints entry, in order
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, InitializeProgram<'info>>,
+    params: InitializeProgramParams,
+) -> Result<()> {
     let config = &mut ctx.accounts.config;
     let stats = &mut ctx.accounts.stats;
     let clock = Clock::get()?;
@@ -88,10 +172,46 @@ pub fn handler(ctx: Context<InitializeProgram>, params: InitializeProgramParams)
         params.min_bid_collateral > 0,
         ConfigError::InvalidParameter
     );
+    ProgramConfig::validate_timelock(params.physical_timelock)?;
+    ProgramConfig::validate_timelock(params.digital_timelock)?;
+    ProgramConfig::validate_timelock(params.service_timelock)?;
+    require!(
+        params.slash_split_bps <= 10_000,
+        ConfigError::InvalidParameter
+    );
+    ProgramConfig::validate_period(params.info_request_extension)?;
+    ProgramConfig::validate_dispute_periods(params.evidence_period, params.resolution_period)?;
+    ProgramConfig::validate_period(params.claim_window)?;
+    ProgramConfig::validate_timelock(params.late_reveal_grace)?;
+    ProgramConfig::validate_timelock(params.dispute_cooldown)?;
     require!(
-        params.supported_mints.len() <= ProgramConfig::MAX_SUPPORTED_MINTS,
+        params.min_reveals_for_settlement > 0,
         ConfigError::InvalidParameter
     );
+    ProgramConfig::validate_fee_discount(
+        params.fee_discount_reputation,
+        params.max_fee_discount_bps,
+    )?;
+    ProgramConfig::validate_clock_skew_tolerance(params.clock_skew_tolerance)?;
+    ProgramConfig::validate_collateral_waiver(
+        params.collateral_waiver_reputation,
+        params.collateral_waiver_bps,
+    )?;
+    ProgramConfig::validate_oracle_bounds(params.max_oracle_age, params.max_price_deviation_bps)?;
+    ProgramConfig::validate_fee_bounds(&params.mint_fee_bounds)?;
+    ProgramConfig::validate_dispute_bonds(&params.dispute_bonds)?;
+    ProgramConfig::validate_timelock(params.relist_cooldown)?;
+    ProgramConfig::validate_period(params.max_reveal_duration)?;
+    ProgramConfig::validate_period(params.return_window)?;
+    ProgramConfig::validate_reveal_rebate(
+        params.early_reveal_window_bps,
+        params.reveal_rebate_bps,
+    )?;
+    ProgramConfig::validate_late_funding_penalty(
+        params.late_funding_penalty_bps_per_day,
+        params.max_late_funding_penalty_bps,
+    )?;
+    validate_supported_mints(&params.supported_mints, ctx.remaining_accounts)?;
     require!(
         params.arbitrators.len() <= ProgramConfig::MAX_ARBITRATORS,
         ConfigError::InvalidParameter
@@ -100,6 +220,7 @@ pub fn handler(ctx: Context<InitializeProgram>, params: InitializeProgramParams)
     // Initialize config
     config.authority = ctx.accounts.authority.key();
     config.upgrade_authority = ctx.accounts.authority.key();
+    config.pending_authority = None;
     config.fee_collector = ctx.accounts.fee_collector.key();
     config.platform_fee_bps = params.platform_fee_bps;
     config.min_auction_duration = params.min_auction_duration;
@@ -110,12 +231,50 @@ pub fn handler(ctx: Context<InitializeProgram>, params: InitializeProgramParams)
     config.min_seller_reputation = params.min_seller_reputation;
     config.min_high_value_reputation = params.min_high_value_reputation;
     config.high_value_threshold = params.high_value_threshold;
+    config.physical_timelock = params.physical_timelock;
+    config.digital_timelock = params.digital_timelock;
+    config.service_timelock = params.service_timelock;
+    config.slash_split_bps = params.slash_split_bps;
+    config.info_request_extension = params.info_request_extension;
+    config.evidence_period = params.evidence_period;
+    config.resolution_period = params.resolution_period;
+    config.claim_window = params.claim_window;
+    config.late_reveal_grace = params.late_reveal_grace;
+    config.credit_winner_collateral = true;
+    config.dispute_cooldown = params.dispute_cooldown;
+    config.min_arbitrator_stake = params.min_arbitrator_stake;
+    config.keeper_reward = params.keeper_reward;
+    config.min_reveals_for_settlement = params.min_reveals_for_settlement;
+    config.fee_discount_reputation = params.fee_discount_reputation;
+    config.max_fee_discount_bps = params.max_fee_discount_bps;
+    config.clock_skew_tolerance = params.clock_skew_tolerance;
+    config.collateral_waiver_reputation = params.collateral_waiver_reputation;
+    config.collateral_waiver_bps = params.collateral_waiver_bps;
+    config.max_oracle_age = params.max_oracle_age;
+    config.max_price_deviation_bps = params.max_price_deviation_bps;
+    config.mint_fee_bounds = params.mint_fee_bounds;
+    config.dispute_bonds = params.dispute_bonds;
+    config.relist_cooldown = params.relist_cooldown;
+    config.settlement_reimbursement_usd_cents = params.settlement_reimbursement_usd_cents;
+    config.max_active_auctions_per_seller = params.max_active_auctions_per_seller;
+    config.max_reveal_duration = params.max_reveal_duration;
+    config.return_window = params.return_window;
+    config.early_reveal_window_bps = params.early_reveal_window_bps;
+    config.reveal_rebate_bps = params.reveal_rebate_bps;
+    config.late_funding_penalty_bps_per_day = params.late_funding_penalty_bps_per_day;
+    config.max_late_funding_penalty_bps = params.max_late_funding_penalty_bps;
     config.paused = false;
+    config.paused_at = 0;
     config.state_tree = ctx.accounts.state_tree.key();
     config.nullifier_queue = ctx.accounts.nullifier_queue.key();
     config.per_config = ctx.accounts.per_config.key();
     config.supported_mints = params.supported_mints;
     config.arbitrators = params.arbitrators;
+    config.category_fee_overrides = vec![];
+    config.bid_verifying_key = None;
+    config.delivery_verifying_key = None;
+    config.reveal_verifying_key = None;
+    config.range_verifying_key = None;
     config.version = 1;
     config.bump = ctx.bumps.config;
  
@@ -128,6 +287,7 @@ pub fn handler(ctx: Context<InitializeProgram>, params: InitializeProgramParams)
     stats.total_users = 0;
     stats.total_disputes = 0;
     stats.disputes_resolved = 0;
+    stats.open_disputes = 0;
     stats.last_updated = clock.unix_timestamp;
     stats.bump = ctx.bumps.stats;
  
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::WalletLinked;
+ 
+#[derive(Accounts)]
+pub struct LinkWallet<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    /// Profile whose identity the secondary wallet joins
+    #[account(
+        mut,
+        seeds = [b"user_profile", primary.key().as_ref()],
+        bump = primary_profile.bump
+    )]
+    pub primary_profile: Account<'info, UserProfile>,
+ 
+    /// CHECK: Primary's own link PDA, which must not exist (a secondary can't link others)
+    #[account(
+        seeds = [b"wallet_link", primary.key().as_ref()],
+        bump,
+        constraint = primary_link.data_is_empty() @ ProfileError::InvalidWalletLink
+    )]
+    pub primary_link: AccountInfo<'info>,
+ 
+    /// CHECK: Secondary's profile PDA, if it has one; checked in the handler to have no
+    /// wallets linked to it
+    #[account(
+        seeds = [b"user_profile", secondary.key().as_ref()],
+        bump
+    )]
+    pub secondary_profile: AccountInfo<'info>,
+ 
+    /// One link per secondary wallet; creation fails if it is already linked
+    #[account(
+        init,
+        payer = primary,
+        space = WalletLink::LEN,
+        seeds = [b"wallet_link", secondary.key().as_ref()],
+        bump
+    )]
+    pub wallet_link: Account<'info, WalletLink>,
+ 
+    #[account(mut)]
+    pub primary: Signer<'info>,
+ 
+    /// Secondary wallet signs to prove it consents to the link
+    #[account(
+        constraint = secondary.key() != primary.key() @ ProfileError::InvalidWalletLink
+    )]
+    pub secondary: Signer<'info>,
+ 
+    pub system_program: Program<'info, System>,
+}
+ 
+pub fn handler(ctx: Context<LinkWallet>) -> Result<()> {
+    let wallet_link = &mut ctx.accounts.wallet_link;
+    let clock = Clock::get()?;
+ 
+    // A wallet others are linked to can't become a secondary itself, or identities would
+    // chain (S -> P1 -> P2) and S would still resolve to P1
+    let secondary_profile = &ctx.accounts.secondary_profile;
+    if *secondary_profile.owner == crate::ID && !secondary_profile.data_is_empty() {
+        let profile =
+            UserProfile::try_deserialize(&mut &secondary_profile.try_borrow_data()?[..])?;
+        require!(profile.linked_wallets == 0, ProfileError::InvalidWalletLink);
+    }
+ 
+    let primary_profile = &mut ctx.accounts.primary_profile;
+    primary_profile.linked_wallets = primary_profile.linked_wallets.saturating_add(1);
+ 
+    wallet_link.primary = ctx.accounts.primary.key();
+    wallet_link.secondary = ctx.accounts.secondary.key();
+    wallet_link.linked_at = clock.unix_timestamp;
+    wallet_link.bump = ctx.bumps.wallet_link;
+ 
+    emit!(WalletLinked {
+        primary: wallet_link.primary,
+        secondary: wallet_link.secondary,
+        timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
+    });
+ 
+    msg!("Wallet {} linked to {}", wallet_link.secondary, wallet_link.primary);
+ 
+    Ok(())
+}
\ No newline at end of file
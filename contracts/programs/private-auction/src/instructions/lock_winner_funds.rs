@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount, transfer, Transfer};
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::EscrowToppedUp;
+ 
+#[derive(Accounts)]
+pub struct LockWinnerFunds<'info> {
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+ 
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        seeds = [b"auction", auction.seller.as_ref(), &auction.listing_seed.to_le_bytes()],
+        bump = auction.bump,
+        constraint = auction.status == AuctionStatus::Revealing @ AuctionError::InvalidAuctionState
+    )]
+    pub auction: Account<'info, AuctionState>,
+ 
+    #[account(
+        mut,
+        seeds = [b"escrow", auction.key().as_ref()],
+        bump = escrow.bump,
+        constraint = escrow.status == EscrowStatus::Created @ EscrowError::InvalidEscrowState
+    )]
+    pub escrow: Account<'info, EscrowAccount>,
+ 
+    /// Escrow vault for the winner's mint
+    #[account(
+        init_if_needed,
+        payer = winner,
+        token::mint = winning_mint,
+        token::authority = escrow,
+        seeds = [b"escrow_vault", auction.key().as_ref(), winning_mint.key().as_ref()],
+        bump
+    )]
+    pub escrow_vault: Account<'info, TokenAccount>,
+ 
+    #[account(
+        constraint = Some(winning_mint.key()) == auction.winning_mint @ EscrowError::InvalidTokenMint
+    )]
+    pub winning_mint: Account<'info, Mint>,
+ 
+    #[account(
+        mut,
+        constraint = winner_token_account.owner == winner.key(),
+        constraint = winner_token_account.mint == winning_mint.key(),
+        // Catch a frozen account up front rather than mid-transfer
+        constraint = !winner_token_account.is_frozen() @ EscrowError::TokenAccountFrozen
+    )]
+    pub winner_token_account: Account<'info, TokenAccount>,
+ 
+    #[account(
+        mut,
+        constraint = Some(winner.key()) == auction.winner @ AuctionError::InvalidAuctionState
+    )]
+    pub winner: Signer<'info>,
+ 
+    /// Price feed for the winner's mint (required to check a USD reserve on single-mint auctions)
+    #[account(
+        seeds = [b"price_feed", winning_mint.key().as_ref()],
+        bump = price_feed.bump
+    )]
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+ 
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+ 
+pub fn handler(ctx: Context<LockWinnerFunds>) -> Result<()> {
+    let auction = &ctx.accounts.auction;
+    let escrow = &mut ctx.accounts.escrow;
+    let clock = Clock::get()?;
+    let phase_time = ctx.accounts.config.phase_time(clock.unix_timestamp);
+ 
+    require!(
+        auction.can_settle(phase_time),
+        AuctionError::CannotSettleYet
+    );
+ 
+    // Never settle before the full reveal window has elapsed, whatever the status
+    require!(
+        phase_time >= auction.reveal_close_time(),
+        AuctionError::CannotSettleYet
+    );
+    require!(
+        auction.revealed_count >= ctx.accounts.config.min_reveals_for_settlement,
+        AuctionError::InsufficientReveals
+    );
+    if let Some(price_feed) = &ctx.accounts.price_feed {
+        ctx.accounts.config.check_price_feed(price_feed, clock.unix_timestamp)?;
+    }
+    require!(
+        auction.reserve_met_at(ctx.accounts.price_feed.as_deref())?,
+        AuctionError::ReserveNotMet
+    );
+ 
+    let payment_amount = auction.payment_amount().ok_or(AuctionError::NoBidsPlaced)?;
+ 
+    // Lock whatever the winner can cover now; the rest must be topped up
+    let locked = payment_amount.min(ctx.accounts.winner_token_account.amount);
+    if locked > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.winner_token_account.to_account_info(),
+                    to: ctx.accounts.escrow_vault.to_account_info(),
+                    authority: ctx.accounts.winner.to_account_info(),
+                },
+            ),
+            locked,
+        )?;
+    }
+ 
+    escrow.token_mint = ctx.accounts.winning_mint.key();
+    escrow.token_account = ctx.accounts.escrow_vault.key();
+    escrow.vault_bump = ctx.bumps.escrow_vault;
+    escrow.payer = Some(ctx.accounts.winner.key());
+    escrow.amount = locked;
+    escrow.required_amount = payment_amount;
+    // The schedule is fixed now so later config changes can't move the winner's deadlines
+    let config = &ctx.accounts.config;
+    escrow.open_funding_window(
+        clock.unix_timestamp,
+        config.late_funding_window(),
+        config.late_funding_penalty_bps_per_day,
+        config.max_late_funding_penalty_bps,
+    );
+    escrow.status = EscrowStatus::Funding;
+ 
+    emit!(EscrowToppedUp {
+        escrow_id: escrow.key(),
+        auction_id: auction.key(),
+        amount: locked,
+        total_locked: escrow.amount,
+        required_amount: escrow.required_amount,
+        late_penalty: 0,
+        funding_deadline: escrow.funding_deadline,
+        timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
+    });
+ 
+    msg!(
+        "Locked {} of {} for auction {} (shortfall {})",
+        locked,
+        payment_amount,
+        auction.key(),
+        escrow.shortfall()
+    );
+ 
+    Ok(())
+}
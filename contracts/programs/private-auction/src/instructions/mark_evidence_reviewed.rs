@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::EvidenceReviewed;
+ 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MarkEvidenceReviewedParams {
+    /// Whose evidence to mark (true = buyer, false = seller)
+    pub party: bool,
+    /// Position of the item in that party's evidence list
+    pub index: u32,
+}
+ 
+#[derive(Accounts)]
+#[instruction(params: MarkEvidenceReviewedParams)]
+pub struct MarkEvidenceReviewed<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        mut,
+        seeds = [b"dispute", dispute.auction_id.as_ref()],
+        bump = dispute.bump,
+        constraint = dispute.resolved_at.is_none() @ DisputeError::InvalidDisputeState,
+        constraint = dispute.arbitrator == Some(arbitrator.key()) @ DisputeError::OnlyArbitrator
+    )]
+    pub dispute: Account<'info, Dispute>,
+ 
+    pub arbitrator: Signer<'info>,
+}
+ 
+pub fn handler(ctx: Context<MarkEvidenceReviewed>, params: MarkEvidenceReviewedParams) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let clock = Clock::get()?;
+ 
+    dispute.mark_evidence_reviewed(params.party, params.index)?;
+ 
+    emit!(EvidenceReviewed {
+        dispute_id: dispute.key(),
+        arbitrator: ctx.accounts.arbitrator.key(),
+        buyer_evidence: params.party,
+        index: params.index,
+        timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
+    });
+ 
+    msg!(
+        "Evidence {} ({}) on dispute {} marked reviewed",
+        params.index,
+        if params.party { "buyer" } else { "seller" },
+        dispute.key()
+    );
+ 
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+ 
+use crate::state::*;
+use crate::errors::*;
+ 
+/// Migrates any account that has grown since the original release, other than auctions
+/// (see migrate_auction). The config must be migrated first, then auctions, then their bids
+/// and everything else.
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// CHECK: Program config, possibly still under the original layout; its authority sits
+    /// right after the discriminator in every layout and is checked in the handler
+    #[account(seeds = [b"program_config"], bump)]
+    pub config: UncheckedAccount<'info>,
+ 
+    /// CHECK: Account under the original layout; owner checked here, type by discriminator
+    #[account(mut, owner = crate::ID)]
+    pub target: UncheckedAccount<'info>,
+ 
+    /// Auction the bid belongs to (bids only, migrated beforehand)
+    #[account(mut)]
+    pub auction: Option<Account<'info, AuctionState>>,
+ 
+    /// Program authority (pays rent for the larger account)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+ 
+    pub system_program: Program<'info, System>,
+}
+ 
+pub fn handler(ctx: Context<MigrateAccount>) -> Result<()> {
+    let authority: Pubkey = {
+        let data = ctx.accounts.config.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == *ProgramConfig::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        read_field(&mut &data[8..])?
+    };
+    require_keys_eq!(
+        authority,
+        ctx.accounts.authority.key(),
+        ConfigError::InvalidAuthority
+    );
+ 
+    let migrate = {
+        let data = ctx.accounts.target.try_borrow_data()?;
+        match data.get(..8) {
+            Some(d) if d == ProgramConfig::DISCRIMINATOR => migrate::<ProgramConfig>,
+            Some(d) if d == ProgramStats::DISCRIMINATOR => migrate::<ProgramStats>,
+            Some(d) if d == BidCommitment::DISCRIMINATOR => migrate::<BidCommitment>,
+            Some(d) if d == EscrowAccount::DISCRIMINATOR => migrate::<EscrowAccount>,
+            Some(d) if d == CollateralPool::DISCRIMINATOR => migrate::<CollateralPool>,
+            Some(d) if d == Dispute::DISCRIMINATOR => migrate::<Dispute>,
+            Some(d) if d == ArbitratorRecord::DISCRIMINATOR => migrate::<ArbitratorRecord>,
+            Some(d) if d == ProductMetadata::DISCRIMINATOR => migrate::<ProductMetadata>,
+            Some(d) if d == UserProfile::DISCRIMINATOR => migrate::<UserProfile>,
+            Some(_) => return err!(ConfigError::NotMigratable),
+            None => return err!(ErrorCode::AccountDiscriminatorNotFound),
+        }
+    };
+ 
+    migrate(ctx.accounts)
+}
+ 
+fn migrate<T: LegacyLayout>(accounts: &mut MigrateAccount) -> Result<()> {
+    let target = accounts.target.to_account_info();
+    let old_len = target.data_len();
+    require!(old_len <= T::LEGACY_SPACE, ConfigError::LayoutUpToDate);
+ 
+    let migrated = T::decode_legacy(
+        &mut &target.try_borrow_data()?[8..],
+        accounts.auction.as_deref_mut(),
+    )?;
+    rewrite_account(
+        &target,
+        &migrated,
+        T::SPACE,
+        &accounts.authority.to_account_info(),
+        &accounts.system_program.to_account_info(),
+    )?;
+ 
+    msg!(
+        "Account {} migrated from {} to {} bytes",
+        target.key(),
+        old_len,
+        T::SPACE
+    );
+ 
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+ 
+use crate::state::*;
+use crate::errors::*;
+ 
+#[derive(Accounts)]
+pub struct MigrateAuction<'info> {
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ ConfigError::InvalidAuthority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+ 
+    /// CHECK: May be laid out under an older schema; owner checked here, discriminator on decode
+    #[account(mut, owner = crate::ID)]
+    pub auction: UncheckedAccount<'info>,
+ 
+    /// Program authority (pays rent for the larger account)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+ 
+    pub system_program: Program<'info, System>,
+}
+ 
+pub fn handler(ctx: Context<MigrateAuction>) -> Result<()> {
+    let auction = &ctx.accounts.auction;
+ 
+    let (version, migrated) = AuctionState::decode_versioned(
+        &auction.try_borrow_data()?,
+        ctx.accounts.config.claim_window,
+    )?;
+    require!(
+        version < AuctionState::SCHEMA_VERSION,
+        AuctionError::SchemaUpToDate
+    );
+ 
+    // Re-encode under the current layout, growing the account (and its rent) to fit
+    rewrite_account(
+        &auction.to_account_info(),
+        &migrated,
+        AuctionState::LEN,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+ 
+    msg!(
+        "Auction {} migrated from schema v{} to v{}",
+        auction.key(),
+        version,
+        AuctionState::SCHEMA_VERSION
+    );
+ 
+    Ok(())
+}
\ No newline at end of file
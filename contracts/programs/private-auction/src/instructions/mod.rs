@@ -9,6 +9,52 @@ pub mod raise_dispute;
 pub mod resolve_dispute;
 pub mod update_profile;
 pub mod claim_refund;
+pub mod update_price_feed;
+pub mod expire_empty_auction;
+pub mod update_config;
+pub mod cancel_dispute;
+pub mod submit_shipping_info;
+pub mod record_tracking;
+pub mod claim_digital_delivery;
+pub mod redeem_service;
+pub mod reveal_reserve;
+pub mod close_failed_auction;
+pub mod slash_stake;
+pub mod lock_winner_funds;
+pub mod top_up_escrow;
+pub mod forfeit_winner;
+pub mod recompute_reputation;
+pub mod cancel_with_refunds;
+pub mod request_more_info;
+pub mod submit_evidence;
+pub mod seller_counteroffer;
+pub mod begin_settlement;
+pub mod init_collateral_pool;
+pub mod promote_runner_up;
+pub mod propose_authority;
+pub mod accept_authority;
+pub mod migrate_auction;
+pub mod migrate_account;
+pub mod init_storefront;
+pub mod preview_refund;
+pub mod update_product_metadata;
+pub mod init_keeper_pool;
+pub mod fund_keeper_pool;
+pub mod create_auctions_batch;
+pub mod reveal_on_behalf;
+pub mod mark_evidence_reviewed;
+pub mod rescue_tokens;
+pub mod init_arbitrator_fee_pool;
+pub mod withdraw_arbitrator_fees;
+pub mod pool_health;
+pub mod top_up_collateral_pool;
+pub mod suggest_dispute_outcome;
+pub mod share_winner_contact;
+pub mod emergency_withdraw;
+pub mod link_wallet;
+pub mod extend_reveal;
+pub mod confirm_return;
+pub mod get_reputation_report;
  
 pub use initialize_program::*;
 pub use create_auction::*;
@@ -20,4 +66,50 @@ pub use confirm_delivery::*;
 pub use raise_dispute::*;
 pub use resolve_dispute::*;
 pub use update_profile::*;
-pub use claim_refund::*;
\ No newline at end of file
+pub use claim_refund::*;
+pub use update_price_feed::*;
+pub use expire_empty_auction::*;
+pub use update_config::*;
+pub use cancel_dispute::*;
+pub use submit_shipping_info::*;
+pub use record_tracking::*;
+pub use claim_digital_delivery::*;
+pub use redeem_service::*;
+pub use reveal_reserve::*;
+pub use close_failed_auction::*;
+pub use slash_stake::*;
+pub use lock_winner_funds::*;
+pub use top_up_escrow::*;
+pub use forfeit_winner::*;
+pub use recompute_reputation::*;
+pub use cancel_with_refunds::*;
+pub use request_more_info::*;
+pub use submit_evidence::*;
+pub use seller_counteroffer::*;
+pub use begin_settlement::*;
+pub use init_collateral_pool::*;
+pub use promote_runner_up::*;
+pub use propose_authority::*;
+pub use accept_authority::*;
+pub use migrate_auction::*;
+pub use migrate_account::*;
+pub use init_storefront::*;
+pub use preview_refund::*;
+pub use update_product_metadata::*;
+pub use init_keeper_pool::*;
+pub use fund_keeper_pool::*;
+pub use create_auctions_batch::*;
+pub use reveal_on_behalf::*;
+pub use mark_evidence_reviewed::*;
+pub use rescue_tokens::*;
+pub use init_arbitrator_fee_pool::*;
+pub use withdraw_arbitrator_fees::*;
+pub use pool_health::*;
+pub use top_up_collateral_pool::*;
+pub use suggest_dispute_outcome::*;
+pub use share_winner_contact::*;
+pub use emergency_withdraw::*;
+pub use link_wallet::*;
+pub use extend_reveal::*;
+pub use confirm_return::*;
+pub use get_reputation_report::*;
\ No newline at end of file
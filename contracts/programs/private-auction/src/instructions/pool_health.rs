@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+ 
+use crate::state::*;
+use crate::errors::*;
+ 
+/// Tracked vs actual balance of a collateral pool
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PoolHealth {
+    /// Collateral the pool believes it holds
+    pub tracked_collateral: u64,
+    /// Withheld penalties and rebate funding set aside for reveal rebates
+    pub penalty_reserve: u64,
+    /// Tokens actually in the pool vault
+    pub vault_balance: u64,
+    /// Number of active bids with collateral
+    pub active_bids: u32,
+    /// Tracked collateral the vault can't cover (0 when solvent)
+    pub shortfall: u64,
+    /// Untracked tokens in the vault
+    pub surplus: u64,
+}
+ 
+#[derive(Accounts)]
+pub struct PoolHealthCheck<'info> {
+    #[account(
+        seeds = [b"collateral_pool", collateral_pool.token_mint.as_ref()],
+        bump = collateral_pool.bump
+    )]
+    pub collateral_pool: Account<'info, CollateralPool>,
+ 
+    #[account(
+        constraint = collateral_pool_vault.key() == collateral_pool.token_account
+            @ EscrowError::InvalidEscrowState
+    )]
+    pub collateral_pool_vault: Account<'info, TokenAccount>,
+}
+ 
+/// Read-only: compare the pool's tracked collateral with its vault balance
+pub fn handler(ctx: Context<PoolHealthCheck>) -> Result<PoolHealth> {
+    let collateral_pool = &ctx.accounts.collateral_pool;
+    let vault_balance = ctx.accounts.collateral_pool_vault.amount;
+ 
+    Ok(PoolHealth {
+        tracked_collateral: collateral_pool.total_collateral,
+        penalty_reserve: collateral_pool.penalty_reserve,
+        vault_balance,
+        active_bids: collateral_pool.active_bids,
+        shortfall: collateral_pool.shortfall(vault_balance),
+        surplus: collateral_pool.surplus(vault_balance),
+    })
+}
\ No newline at end of file
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::instructions::claim_refund::{compute_refund, RefundPreview};
+ 
+#[derive(Accounts)]
+pub struct PreviewRefund<'info> {
+    #[account(
+        seeds = [b"auction", auction.seller.as_ref(), &auction.listing_seed.to_le_bytes()],
+        bump = auction.bump
+    )]
+    pub auction: Account<'info, AuctionState>,
+ 
+    #[account(
+        seeds = [b"bid", auction.key().as_ref(), bid.bidder.as_ref()],
+        bump = bid.bump,
+        constraint = bid.auction_id == auction.key() @ BidError::BidNotFound
+    )]
+    pub bid: Account<'info, BidCommitment>,
+}
+ 
+/// Read-only: report what claim_refund would pay for this bid
+pub fn handler(ctx: Context<PreviewRefund>) -> Result<RefundPreview> {
+    Ok(refund_preview(&ctx.accounts.auction, &ctx.accounts.bid))
+}
+ 
+/// What claim_refund would pay for `bid`, or an ineligible preview
+pub fn refund_preview(auction: &AuctionState, bid: &BidCommitment) -> RefundPreview {
+    // Winners and already-refunded bids have nothing to claim
+    if bid.collateral_returned || auction.winner == Some(bid.bidder) {
+        return RefundPreview::ineligible();
+    }
+ 
+    // Auctions still in progress (or disputed) are not refundable yet
+    compute_refund(auction, bid).unwrap_or_else(|_| RefundPreview::ineligible())
+}
\ No newline at end of file
@@ -0,0 +1,175 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, transfer, Transfer};
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::WinnerForfeited;
+ 
+#[derive(Accounts)]
+pub struct PromoteRunnerUp<'info> {
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+ 
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        mut,
+        seeds = [b"auction", auction.seller.as_ref(), &auction.listing_seed.to_le_bytes()],
+        bump = auction.bump,
+        constraint = auction.status == AuctionStatus::Revealing @ AuctionError::InvalidAuctionState
+    )]
+    pub auction: Account<'info, AuctionState>,
+ 
+    /// Escrow must still be unfunded
+    #[account(
+        seeds = [b"escrow", auction.key().as_ref()],
+        bump = escrow.bump,
+        constraint = escrow.status == EscrowStatus::Created @ EscrowError::InvalidEscrowState
+    )]
+    pub escrow: Account<'info, EscrowAccount>,
+ 
+    /// Defaulting winner's bid, whose collateral is forfeited to the seller
+    #[account(
+        mut,
+        seeds = [b"bid", auction.key().as_ref(), winner.key().as_ref()],
+        bump = winner_bid.bump,
+        constraint = !winner_bid.collateral_returned @ BidError::RefundAlreadyClaimed
+    )]
+    pub winner_bid: Account<'info, BidCommitment>,
+ 
+    #[account(
+        mut,
+        seeds = [b"collateral_pool", winner_bid.collateral_mint.as_ref()],
+        bump
+    )]
+    pub collateral_pool: Account<'info, CollateralPool>,
+ 
+    #[account(
+        mut,
+        constraint = collateral_pool_vault.key() == collateral_pool.token_account
+    )]
+    pub collateral_pool_vault: Account<'info, TokenAccount>,
+ 
+    /// Seller's token account receiving the forfeited collateral
+    #[account(
+        mut,
+        constraint = seller_token_account.owner == auction.seller,
+        constraint = seller_token_account.mint == winner_bid.collateral_mint
+    )]
+    pub seller_token_account: Account<'info, TokenAccount>,
+ 
+    /// CHECK: Current winner who failed to fund within the claim window
+    #[account(constraint = Some(winner.key()) == auction.winner @ AuctionError::InvalidAuctionState)]
+    pub winner: AccountInfo<'info>,
+ 
+    /// Anyone may promote the runner-up (rewarded from the keeper pool)
+    #[account(mut)]
+    pub caller: Signer<'info>,
+ 
+    /// Keeper reward pool (optional; pays the caller once per auction)
+    #[account(
+        mut,
+        seeds = [b"keeper_pool"],
+        bump = keeper_pool.bump
+    )]
+    pub keeper_pool: Option<Account<'info, KeeperPool>>,
+ 
+    /// Price feed for the payment mint (required to check a USD reserve on single-mint auctions)
+    #[account(
+        seeds = [b"price_feed", auction.payment_mint.as_ref()],
+        bump = price_feed.bump
+    )]
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+ 
+    pub token_program: Program<'info, Token>,
+}
+ 
+pub fn handler(ctx: Context<PromoteRunnerUp>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let auction = &mut ctx.accounts.auction;
+    let winner_bid = &mut ctx.accounts.winner_bid;
+    let collateral_pool = &mut ctx.accounts.collateral_pool;
+    let clock = Clock::get()?;
+ 
+    require!(
+        clock.unix_timestamp > auction.claim_deadline,
+        AuctionError::ClaimWindowOpen
+    );
+    require!(auction.runner_up.is_some(), AuctionError::NoRunnerUp);
+ 
+    // The runner-up must clear the reserve on their own bid
+    require!(
+        auction.value_meets_reserve(
+            auction.second_value.unwrap_or(0),
+            ctx.accounts.price_feed.as_deref(),
+        )?,
+        AuctionError::ReserveNotMet
+    );
+ 
+    // Defaulting winner's collateral compensates the seller
+    let collateral_forfeited = winner_bid.collateral_deposited;
+    let collateral_mint = winner_bid.collateral_mint;
+    let pool_seeds = &[
+        b"collateral_pool".as_ref(),
+        collateral_mint.as_ref(),
+        &[ctx.bumps.collateral_pool],
+    ];
+ 
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.collateral_pool_vault.to_account_info(),
+                to: ctx.accounts.seller_token_account.to_account_info(),
+                authority: collateral_pool.to_account_info(),
+            },
+            &[pool_seeds],
+        ),
+        collateral_forfeited,
+    )?;
+ 
+    collateral_pool.withdraw(collateral_forfeited)?;
+    winner_bid.collateral_returned = true;
+ 
+    let winner = ctx.accounts.winner.key();
+    auction.promote_runner_up(clock.unix_timestamp + config.claim_window);
+ 
+    emit!(WinnerForfeited {
+        auction_id: auction.key(),
+        winner,
+        refunded_amount: 0,
+        collateral_forfeited,
+        promoted_winner: auction.winner,
+        timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
+    });
+ 
+    // Pay the keeper once per auction
+    if let Some(keeper_pool) = ctx.accounts.keeper_pool.as_mut() {
+        let keeper = ctx.accounts.caller.to_account_info();
+        KeeperPool::reward_keeper(
+            keeper_pool,
+            &mut ctx.accounts.stats,
+            auction,
+            &keeper,
+            config.keeper_reward,
+        )?;
+    }
+ 
+    msg!(
+        "Winner {} forfeited auction {}; runner-up promoted until {}",
+        winner,
+        auction.key(),
+        auction.claim_deadline
+    );
+ 
+    Ok(())
+}
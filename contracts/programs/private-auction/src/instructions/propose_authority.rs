@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+ 
+use crate::state::{ProgramConfig, ProgramStats};
+use crate::errors::ConfigError;
+use crate::events::AuthorityProposed;
+ 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ProposeAuthorityParams {
+    /// Account that must accept to become the new authority
+    pub new_authority: Pubkey,
+}
+ 
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ ConfigError::InvalidAuthority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+ 
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    pub authority: Signer<'info>,
+}
+ 
+pub fn handler(ctx: Context<ProposeAuthority>, params: ProposeAuthorityParams) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+ 
+    require!(
+        params.new_authority != Pubkey::default() && params.new_authority != config.authority,
+        ConfigError::InvalidParameter
+    );
+ 
+    // A new proposal supersedes any pending one
+    config.pending_authority = Some(params.new_authority);
+ 
+    emit!(AuthorityProposed {
+        authority: ctx.accounts.authority.key(),
+        pending_authority: params.new_authority,
+        timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
+    });
+ 
+    msg!("Authority transfer to {} proposed", params.new_authority);
+ 
+    Ok(())
+}
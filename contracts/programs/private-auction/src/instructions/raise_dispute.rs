@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount, transfer, Transfer};
  
 use crate::state::*;
 use crate::errors::*;
@@ -32,9 +33,13 @@ pub struct RaiseDispute<'info> {
  
     #[account(
         mut,
-        seeds = [b"auction", auction.seller.as_ref(), &auction.start_time.to_le_bytes()],
+        seeds = [b"auction", auction.seller.as_ref(), &auction.listing_seed.to_le_bytes()],
         bump = auction.bump,
-        constraint = auction.status == AuctionStatus::Settled @ AuctionError::InvalidAuctionState
+        // Disputed is let through so an existing dispute surfaces DisputeAlreadyExists
+        constraint =
+            auction.status == AuctionStatus::Settled ||
+            auction.status == AuctionStatus::Disputed
+            @ AuctionError::InvalidAuctionState
     )]
     pub auction: Account<'info, AuctionState>,
  
@@ -42,12 +47,17 @@ pub struct RaiseDispute<'info> {
         mut,
         seeds = [b"escrow", auction.key().as_ref()],
         bump = escrow.bump,
-        constraint = escrow.status == EscrowStatus::Funded @ EscrowError::InvalidEscrowState
+        constraint = escrow.status != EscrowStatus::Released @ DisputeError::DeliveryAlreadyConfirmed,
+        constraint =
+            escrow.status == EscrowStatus::Funded ||
+            escrow.status == EscrowStatus::Disputed
+            @ EscrowError::InvalidEscrowState
     )]
     pub escrow: Account<'info, EscrowAccount>,
  
+    /// Created on first use; an initialized account means a dispute already exists
     #[account(
-        init,
+        init_if_needed,
         payer = disputer,
         space = Dispute::LEN,
         seeds = [b"dispute", auction.key().as_ref()],
@@ -55,6 +65,32 @@ pub struct RaiseDispute<'info> {
     )]
     pub dispute: Account<'info, Dispute>,
  
+    /// Holds the raiser's bond until the dispute is resolved or withdrawn
+    #[account(
+        init_if_needed,
+        payer = disputer,
+        token::mint = bond_mint,
+        token::authority = dispute,
+        seeds = [b"dispute_bond", auction.key().as_ref()],
+        bump
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+ 
+    /// The bond is posted in the escrow's mint
+    #[account(
+        constraint = bond_mint.key() == escrow.token_mint @ EscrowError::InvalidTokenMint
+    )]
+    pub bond_mint: Account<'info, Mint>,
+ 
+    /// Disputer's token account the bond is drawn from (required if a bond is configured)
+    #[account(
+        mut,
+        constraint = disputer_token_account.owner == disputer.key(),
+        constraint = disputer_token_account.mint == escrow.token_mint
+            @ EscrowError::InvalidTokenMint
+    )]
+    pub disputer_token_account: Option<Account<'info, TokenAccount>>,
+ 
     #[account(
         mut,
         seeds = [b"user_profile", disputer.key().as_ref()],
@@ -72,6 +108,7 @@ pub struct RaiseDispute<'info> {
     )]
     pub disputer: Signer<'info>,
  
+    pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
  
@@ -83,11 +120,26 @@ pub fn handler(ctx: Context<RaiseDispute>, params: RaiseDisputeParams) -> Result
     let stats = &mut ctx.accounts.stats;
     let clock = Clock::get()?;
  
+    // Only one dispute per auction
+    dispute.ensure_not_raised()?;
+    require!(
+        auction.status == AuctionStatus::Settled,
+        AuctionError::InvalidAuctionState
+    );
+    escrow.ensure_disputable()?;
+ 
     // Determine buyer and seller
     let buyer = auction.winner.ok_or(AuctionError::InvalidAuctionState)?;
     let seller = auction.seller;
     let disputer = ctx.accounts.disputer.key();
  
+    // Throttle serial disputers
+    let dispute_cooldown = ctx.accounts.config.dispute_cooldown;
+    require!(
+        !disputer_profile.in_dispute_cooldown(dispute_cooldown, clock.unix_timestamp),
+        DisputeError::DisputeCooldownActive
+    );
+ 
     // Initialize dispute
     dispute.dispute_id = dispute.key();
     dispute.auction_id = auction.key();
@@ -108,13 +160,40 @@ pub fn handler(ctx: Context<RaiseDispute>, params: RaiseDisputeParams) -> Result
     dispute.opened_at = clock.unix_timestamp;
     dispute.last_activity = clock.unix_timestamp;
     dispute.resolved_at = None;
-    dispute.evidence_deadline = clock.unix_timestamp + Dispute::DEFAULT_EVIDENCE_PERIOD;
-    dispute.resolution_deadline = clock.unix_timestamp + Dispute::DEFAULT_RESOLUTION_PERIOD;
+    dispute.evidence_deadline = clock.unix_timestamp + ctx.accounts.config.evidence_period;
+    dispute.resolution_deadline = clock.unix_timestamp + ctx.accounts.config.resolution_period;
     dispute.votes_collected = 0;
     dispute.votes_for_buyer = 0;
     dispute.votes_for_seller = 0;
+    dispute.voted_arbitrators = vec![];
+    dispute.stake_slashed = false;
+    dispute.bond_amount = 0;
+    dispute.return_tracking = None;
     dispute.bump = ctx.bumps.dispute;
  
+    // Post the dispute bond; it comes back only if the dispute doesn't go against the raiser
+    let bond = ctx.accounts.config.dispute_bond_for(&escrow.token_mint);
+    if bond > 0 {
+        let disputer_token_account = ctx
+            .accounts
+            .disputer_token_account
+            .as_ref()
+            .ok_or(EscrowError::InvalidTokenMint)?;
+ 
+        transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: disputer_token_account.to_account_info(),
+                    to: ctx.accounts.bond_vault.to_account_info(),
+                    authority: ctx.accounts.disputer.to_account_info(),
+                },
+            ),
+            bond,
+        )?;
+        dispute.bond_amount = bond;
+    }
+ 
     // Add initial evidence if provided
     if let Some(evidence) = params.initial_evidence {
         let is_buyer = disputer == buyer;
@@ -129,9 +208,10 @@ pub fn handler(ctx: Context<RaiseDispute>, params: RaiseDisputeParams) -> Result
  
     // Update disputer profile
     disputer_profile.record_dispute_raised(false); // Not won yet
+    disputer_profile.last_dispute_at = clock.unix_timestamp;
  
-    // Update stats
-    stats.dispute_raised();
+    // Update stats (the auction already left active_auctions at settlement)
+    stats.dispute_raised(clock.unix_timestamp);
  
     // Emit event
     emit!(DisputeRaised {
@@ -143,6 +223,7 @@ pub fn handler(ctx: Context<RaiseDispute>, params: RaiseDisputeParams) -> Result
         amount: escrow.amount,
         evidence_deadline: dispute.evidence_deadline,
         timestamp: clock.unix_timestamp,
+        event_seq: stats.next_event_seq(),
     });
  
     msg!(
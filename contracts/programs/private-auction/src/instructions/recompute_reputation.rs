@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+ 
+use crate::state::*;
+use crate::events::{ReputationUpdated, reputation_reasons};
+ 
+#[derive(Accounts)]
+pub struct RecomputeReputation<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        mut,
+        seeds = [b"user_profile", profile.user_pubkey.as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, UserProfile>,
+ 
+    /// Anyone may refresh a profile's score
+    pub caller: Signer<'info>,
+}
+ 
+pub fn handler(ctx: Context<RecomputeReputation>) -> Result<()> {
+    let profile = &mut ctx.accounts.profile;
+    let clock = Clock::get()?;
+ 
+    let old_score = profile.reputation_score;
+    profile.reputation_score = profile.calculate_reputation();
+ 
+    emit!(ReputationUpdated {
+        user: profile.user_pubkey,
+        old_score,
+        new_score: profile.reputation_score,
+        reason: reputation_reasons::RECOMPUTED,
+        timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
+    });
+ 
+    msg!(
+        "Reputation for {} recomputed: {} -> {}",
+        profile.user_pubkey,
+        old_score,
+        profile.reputation_score
+    );
+ 
+    Ok(())
+}
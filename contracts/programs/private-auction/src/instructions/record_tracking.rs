@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::ShipmentDispatched;
+ 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RecordTrackingParams {
+    /// Shipping carrier (must be one of the listing's carriers, if any)
+    pub carrier: String,
+    /// Carrier tracking number
+    pub tracking_number: String,
+}
+ 
+#[derive(Accounts)]
+#[instruction(params: RecordTrackingParams)]
+pub struct RecordTracking<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        seeds = [b"auction", auction.seller.as_ref(), &auction.listing_seed.to_le_bytes()],
+        bump = auction.bump,
+        constraint = auction.seller == seller.key() @ AuctionError::OnlySeller,
+        constraint = auction.status == AuctionStatus::Settled @ AuctionError::InvalidAuctionState,
+        constraint = auction.product_type == ProductType::Physical @ AuctionError::InvalidProductType
+    )]
+    pub auction: Account<'info, AuctionState>,
+ 
+    #[account(
+        seeds = [b"product", auction.key().as_ref()],
+        bump = product_metadata.bump
+    )]
+    pub product_metadata: Account<'info, ProductMetadata>,
+ 
+    #[account(
+        mut,
+        seeds = [b"escrow", auction.key().as_ref()],
+        bump = escrow.bump,
+        constraint = escrow.status == EscrowStatus::Funded @ EscrowError::InvalidEscrowState
+    )]
+    pub escrow: Account<'info, EscrowAccount>,
+ 
+    #[account(
+        mut,
+        seeds = [b"fulfillment", auction.key().as_ref()],
+        bump = fulfillment.bump,
+        constraint = fulfillment.has_shipping_info() @ FulfillmentError::ShippingAddressRequired
+    )]
+    pub fulfillment: Account<'info, FulfillmentRecord>,
+ 
+    pub seller: Signer<'info>,
+}
+ 
+pub fn handler(ctx: Context<RecordTracking>, params: RecordTrackingParams) -> Result<()> {
+    let escrow = &mut ctx.accounts.escrow;
+    let fulfillment = &mut ctx.accounts.fulfillment;
+    let clock = Clock::get()?;
+ 
+    require!(!fulfillment.is_shipped(), FulfillmentError::AlreadyShipped);
+ 
+    // Validate tracking details
+    let tracking_number = params.tracking_number.trim();
+    require!(
+        !tracking_number.is_empty()
+            && tracking_number.len() <= FulfillmentRecord::MAX_TRACKING_NUMBER_LEN,
+        FulfillmentError::InvalidTrackingNumber
+    );
+    require!(
+        params.carrier.len() <= FulfillmentRecord::MAX_CARRIER_LEN,
+        FulfillmentError::UnsupportedCarrier
+    );
+    if let Some(shipping) = &ctx.accounts.product_metadata.shipping {
+        require!(
+            shipping.supports_carrier(&params.carrier),
+            FulfillmentError::UnsupportedCarrier
+        );
+    }
+ 
+    fulfillment.carrier = params.carrier;
+    fulfillment.tracking_number = tracking_number.to_string();
+    fulfillment.shipped_at = Some(clock.unix_timestamp);
+ 
+    // Dispatch shortens the auto-release countdown
+    let release_deadline = escrow
+        .release_conditions
+        .release_deadline
+        .min(clock.unix_timestamp + FulfillmentRecord::SHIPPED_RELEASE_WINDOW);
+    escrow.release_conditions.release_deadline = release_deadline;
+ 
+    emit!(ShipmentDispatched {
+        auction_id: ctx.accounts.auction.key(),
+        seller: ctx.accounts.seller.key(),
+        carrier: fulfillment.carrier.clone(),
+        tracking_number: fulfillment.tracking_number.clone(),
+        release_deadline,
+        timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
+    });
+ 
+    msg!(
+        "Shipment for auction {} dispatched via {}",
+        ctx.accounts.auction.key(),
+        fulfillment.carrier
+    );
+ 
+    Ok(())
+}
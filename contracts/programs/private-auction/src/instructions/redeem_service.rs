@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::ServiceRedeemed;
+ 
+#[derive(Accounts)]
+pub struct RedeemService<'info> {
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+ 
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        seeds = [b"auction", auction.seller.as_ref(), &auction.listing_seed.to_le_bytes()],
+        bump = auction.bump,
+        constraint = auction.status == AuctionStatus::Settled @ AuctionError::InvalidAuctionState,
+        constraint = auction.product_type == ProductType::Service @ AuctionError::InvalidProductType
+    )]
+    pub auction: Account<'info, AuctionState>,
+ 
+    #[account(
+        seeds = [b"product", auction.key().as_ref()],
+        bump = product_metadata.bump
+    )]
+    pub product_metadata: Account<'info, ProductMetadata>,
+ 
+    #[account(
+        mut,
+        seeds = [b"escrow", auction.key().as_ref()],
+        bump = escrow.bump,
+        constraint = escrow.status == EscrowStatus::Funded @ EscrowError::InvalidEscrowState
+    )]
+    pub escrow: Account<'info, EscrowAccount>,
+ 
+    #[account(
+        mut,
+        seeds = [b"fulfillment", auction.key().as_ref()],
+        bump = fulfillment.bump
+    )]
+    pub fulfillment: Account<'info, FulfillmentRecord>,
+ 
+    /// Buyer must match auction winner
+    #[account(
+        constraint = Some(buyer.key()) == auction.winner @ FulfillmentError::OnlyBuyer
+    )]
+    pub buyer: Signer<'info>,
+}
+ 
+pub fn handler(ctx: Context<RedeemService>) -> Result<()> {
+    let auction = &ctx.accounts.auction;
+    let escrow = &mut ctx.accounts.escrow;
+    let fulfillment = &mut ctx.accounts.fulfillment;
+    let clock = Clock::get()?;
+ 
+    let service = ctx
+        .accounts
+        .product_metadata
+        .service_details
+        .as_ref()
+        .ok_or(AuctionError::InvalidProductType)?;
+ 
+    require!(
+        !fulfillment.is_service_redeemed(),
+        FulfillmentError::ServiceAlreadyRedeemed
+    );
+    require!(
+        clock.unix_timestamp <= service.redemption_deadline,
+        FulfillmentError::RedemptionExpired
+    );
+ 
+    fulfillment.service_redeemed_at = Some(clock.unix_timestamp);
+ 
+    // Service delivery window starts at redemption
+    let release_deadline = clock.unix_timestamp + ctx.accounts.config.service_timelock;
+    escrow.release_conditions.time_lock_duration = ctx.accounts.config.service_timelock;
+    escrow.release_conditions.release_deadline = release_deadline;
+ 
+    emit!(ServiceRedeemed {
+        auction_id: auction.key(),
+        buyer: ctx.accounts.buyer.key(),
+        seller: auction.seller,
+        release_deadline,
+        timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
+    });
+ 
+    msg!("Service for auction {} redeemed", auction.key());
+ 
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::MoreInfoRequested;
+ 
+#[derive(Accounts)]
+pub struct RequestMoreInfo<'info> {
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+ 
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        mut,
+        seeds = [b"dispute", dispute.auction_id.as_ref()],
+        bump = dispute.bump,
+        constraint = dispute.status == DisputeStatus::UnderReview @ DisputeError::InvalidDisputeState,
+        constraint = dispute.arbitrator == Some(arbitrator.key()) @ DisputeError::OnlyArbitrator
+    )]
+    pub dispute: Account<'info, Dispute>,
+ 
+    pub arbitrator: Signer<'info>,
+}
+ 
+pub fn handler(ctx: Context<RequestMoreInfo>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let dispute = &mut ctx.accounts.dispute;
+    let clock = Clock::get()?;
+ 
+    // Reopen evidence for both parties, extending from now if the deadline already passed
+    dispute.status = DisputeStatus::AwaitingInfo;
+    dispute.evidence_deadline =
+        dispute.evidence_deadline.max(clock.unix_timestamp) + config.info_request_extension;
+    dispute.resolution_deadline = dispute.resolution_deadline.max(dispute.evidence_deadline);
+    dispute.last_activity = clock.unix_timestamp;
+ 
+    emit!(MoreInfoRequested {
+        dispute_id: dispute.key(),
+        auction_id: dispute.auction_id,
+        arbitrator: ctx.accounts.arbitrator.key(),
+        evidence_deadline: dispute.evidence_deadline,
+        timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
+    });
+ 
+    msg!(
+        "More info requested on dispute {}, evidence due by {}",
+        dispute.key(),
+        dispute.evidence_deadline
+    );
+ 
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, transfer, Transfer};
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::TokensRescued;
+ 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RescueTokensParams {
+    /// Amount to move out of the vault (must not exceed the untracked surplus)
+    pub amount: u64,
+}
+ 
+#[derive(Accounts)]
+#[instruction(params: RescueTokensParams)]
+pub struct RescueTokens<'info> {
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ ConfigError::InvalidAuthority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+ 
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    /// Token account held by the escrow or collateral pool PDA
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+ 
+    /// Escrow holding the vault (exactly one of escrow / collateral_pool)
+    #[account(
+        seeds = [b"escrow", escrow.auction_id.as_ref()],
+        bump = escrow.bump
+    )]
+    pub escrow: Option<Account<'info, EscrowAccount>>,
+ 
+    /// Collateral pool holding the vault (exactly one of escrow / collateral_pool)
+    #[account(
+        seeds = [b"collateral_pool", collateral_pool.token_mint.as_ref()],
+        bump = collateral_pool.bump
+    )]
+    pub collateral_pool: Option<Account<'info, CollateralPool>>,
+ 
+    #[account(
+        mut,
+        constraint = recipient.mint == vault.mint @ EscrowError::InvalidTokenMint
+    )]
+    pub recipient: Account<'info, TokenAccount>,
+ 
+    pub authority: Signer<'info>,
+ 
+    pub token_program: Program<'info, Token>,
+}
+ 
+pub fn handler(ctx: Context<RescueTokens>, params: RescueTokensParams) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let clock = Clock::get()?;
+ 
+    // Only the tracked vault's balance is spoken for; any other PDA-held account is surplus
+    let surplus = match (&ctx.accounts.escrow, &ctx.accounts.collateral_pool) {
+        (Some(escrow), None) => {
+            require_keys_eq!(vault.owner, escrow.key(), EscrowError::InvalidRescueVault);
+            if vault.key() == escrow.token_account {
+                escrow.surplus(vault.amount)
+            } else {
+                vault.amount
+            }
+        }
+        (None, Some(collateral_pool)) => {
+            require_keys_eq!(vault.owner, collateral_pool.key(), EscrowError::InvalidRescueVault);
+            if vault.key() == collateral_pool.token_account {
+                collateral_pool.surplus(vault.amount)
+            } else {
+                vault.amount
+            }
+        }
+        _ => return err!(EscrowError::InvalidRescueVault),
+    };
+ 
+    require!(
+        params.amount > 0 && params.amount <= surplus,
+        EscrowError::RescueExceedsSurplus
+    );
+ 
+    // Sign as whichever PDA owns the vault
+    let (owner, seed_prefix, seed_key, bump) = match (
+        &ctx.accounts.escrow,
+        &ctx.accounts.collateral_pool,
+    ) {
+        (Some(escrow), _) => (
+            escrow.to_account_info(),
+            b"escrow".as_ref(),
+            escrow.auction_id,
+            escrow.bump,
+        ),
+        (_, Some(collateral_pool)) => (
+            collateral_pool.to_account_info(),
+            b"collateral_pool".as_ref(),
+            collateral_pool.token_mint,
+            collateral_pool.bump,
+        ),
+        _ => return err!(EscrowError::InvalidRescueVault),
+    };
+    let signer_seeds = &[seed_prefix, seed_key.as_ref(), &[bump]];
+ 
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: vault.to_account_info(),
+                to: ctx.accounts.recipient.to_account_info(),
+                authority: owner,
+            },
+            &[signer_seeds],
+        ),
+        params.amount,
+    )?;
+ 
+    emit!(TokensRescued {
+        vault: vault.key(),
+        mint: vault.mint,
+        recipient: ctx.accounts.recipient.key(),
+        amount: params.amount,
+        timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
+    });
+ 
+    msg!(
+        "Rescued {} untracked tokens from vault {}",
+        params.amount,
+        vault.key()
+    );
+ 
+    Ok(())
+}
\ No newline at end of file
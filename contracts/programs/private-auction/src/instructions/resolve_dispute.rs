@@ -3,7 +3,10 @@ use anchor_spl::token::{Token, TokenAccount, transfer, Transfer};
  
 use crate::state::*;
 use crate::errors::*;
-use crate::events::{DisputeResolved, EscrowReleased, EscrowRefunded, refund_reasons};
+use crate::events::{
+    DisputeBondSettled, DisputeResolved, EscrowReleased, EscrowRefunded, ReputationUpdated,
+    refund_reasons, reputation_reasons,
+};
  
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct ResolveDisputeParams {
@@ -35,10 +38,18 @@ pub struct ResolveDispute<'info> {
         seeds = [b"dispute", dispute.auction_id.as_ref()],
         bump = dispute.bump,
         constraint = dispute.status == DisputeStatus::EvidenceSubmitted ||
-                     dispute.status == DisputeStatus::UnderReview
+                     dispute.status == DisputeStatus::UnderReview ||
+                     dispute.status == DisputeStatus::AwaitingInfo
                      @ DisputeError::InvalidDisputeState
     )]
     pub dispute: Account<'info, Dispute>,
+
+    /// Product metadata (category selects the platform fee)
+    #[account(
+        seeds = [b"product", dispute.auction_id.as_ref()],
+        bump = product_metadata.bump
+    )]
+    pub product_metadata: Account<'info, ProductMetadata>,
  
     #[account(
         mut,
@@ -49,8 +60,8 @@ pub struct ResolveDispute<'info> {
  
     #[account(
         mut,
-        seeds = [b"escrow_vault", dispute.auction_id.as_ref()],
-        bump
+        seeds = [b"escrow_vault", dispute.auction_id.as_ref(), escrow.token_mint.as_ref()],
+        bump = escrow.vault_bump
     )]
     pub escrow_vault: Account<'info, TokenAccount>,
  
@@ -68,7 +79,8 @@ pub struct ResolveDispute<'info> {
  
     #[account(
         mut,
-        constraint = fee_collector.key() == config.fee_collector
+        constraint = config.is_fee_collector(&fee_collector.key(), &fee_collector.owner),
+        constraint = fee_collector.mint == escrow.token_mint
     )]
     pub fee_collector: Account<'info, TokenAccount>,
  
@@ -93,6 +105,38 @@ pub struct ResolveDispute<'info> {
     )]
     pub arbitrator_record: Account<'info, ArbitratorRecord>,
  
+    /// Arbitrator's stake (slashable if their decision is overturned)
+    #[account(
+        seeds = [b"reputation_stake", arbitrator.key().as_ref()],
+        bump = arbitrator_stake.bump,
+        constraint = config.arbitrator_stake_sufficient(&arbitrator_stake)
+            @ ProfileError::InsufficientStake
+    )]
+    pub arbitrator_stake: Account<'info, ReputationStake>,
+ 
+    /// Arbitrator fee pool (optional; the arbitrator's cut is pooled when its mint matches)
+    #[account(
+        mut,
+        seeds = [b"arbitrator_fee_pool"],
+        bump = arbitrator_fee_pool.bump
+    )]
+    pub arbitrator_fee_pool: Option<Account<'info, ArbitratorFeePool>>,
+ 
+    #[account(
+        mut,
+        seeds = [b"arbitrator_fee_vault"],
+        bump
+    )]
+    pub arbitrator_fee_vault: Option<Account<'info, TokenAccount>>,
+ 
+    /// Dispute bond vault (required if the raiser posted a bond)
+    #[account(
+        mut,
+        seeds = [b"dispute_bond", dispute.auction_id.as_ref()],
+        bump
+    )]
+    pub bond_vault: Option<Account<'info, TokenAccount>>,
+ 
     pub arbitrator: Signer<'info>,
  
     pub token_program: Program<'info, Token>,
@@ -112,10 +156,13 @@ pub fn handler(ctx: Context<ResolveDispute>, params: ResolveDisputeParams) -> Re
     if dispute.status == DisputeStatus::EvidenceSubmitted {
         dispute.status = DisputeStatus::UnderReview;
         dispute.arbitrator = Some(ctx.accounts.arbitrator.key());
+    } else if dispute.status == DisputeStatus::AwaitingInfo {
+        // Voting resumes the review once more info was requested
+        dispute.status = DisputeStatus::UnderReview;
     }
  
-    // Record the vote
-    dispute.record_vote(params.vote_for_buyer);
+    // Record the vote (rejects repeat votes from the same arbitrator)
+    dispute.record_vote(ctx.accounts.arbitrator.key(), params.vote_for_buyer)?;
  
     // Store arbitrator notes if provided
     if let Some(notes) = params.notes_encrypted {
@@ -129,18 +176,37 @@ pub fn handler(ctx: Context<ResolveDispute>, params: ResolveDisputeParams) -> Re
         // Determine outcome based on votes
         let outcome = dispute.determine_outcome();
  
-        // Calculate distribution
+        // Calculate distribution; the platform fee only applies to what the seller keeps
         let payment_amount = escrow.amount;
-        let platform_fee = config.calculate_fee(payment_amount);
+        let buyer_refund = outcome.buyer_refund(payment_amount);
+        let seller_share = payment_amount
+            .checked_sub(buyer_refund)
+            .ok_or(ConfigError::ArithmeticOverflow)?;
+        let platform_fee = config.calculate_fee(
+            seller_share,
+            &escrow.token_mint,
+            ctx.accounts.product_metadata.category,
+            seller_profile.reputation_score,
+        );
+ 
+        // 10% of the platform fee goes to the arbitrator, paid into the fee pool when it
+        // holds the escrow's mint; otherwise it is only tracked in fees_earned
+        let arbitrator_fee = platform_fee / 10;
+        let token_mint = escrow.token_mint;
+        let pooled_fee = match (
+            &ctx.accounts.arbitrator_fee_pool,
+            &ctx.accounts.arbitrator_fee_vault,
+        ) {
+            (Some(fee_pool), Some(_)) if fee_pool.token_mint == token_mint => arbitrator_fee,
+            _ => 0,
+        };
+        let collector_fee = platform_fee - pooled_fee;
  
         let auction_id = dispute.auction_id;
-        let escrow_vault_seeds = &[
-            b"escrow_vault".as_ref(),
-            auction_id.as_ref(),
-            &[ctx.bumps.escrow_vault],
-        ];
+        // The vault is owned by the escrow PDA, which signs every payout
+        let escrow_seeds = &[b"escrow".as_ref(), auction_id.as_ref(), &[escrow.bump]];
  
-        match outcome {
+        let (buyer_received, seller_received) = match outcome {
             DisputeOutcome::FullRefund => {
                 // Refund full amount to buyer
                 transfer(
@@ -149,9 +215,9 @@ pub fn handler(ctx: Context<ResolveDispute>, params: ResolveDisputeParams) -> Re
                         Transfer {
                             from: ctx.accounts.escrow_vault.to_account_info(),
                             to: ctx.accounts.buyer_token_account.to_account_info(),
-                            authority: ctx.accounts.escrow_vault.to_account_info(),
+                            authority: escrow.to_account_info(),
                         },
-                        &[escrow_vault_seeds],
+                        &[escrow_seeds],
                     ),
                     payment_amount,
                 )?;
@@ -160,8 +226,19 @@ pub fn handler(ctx: Context<ResolveDispute>, params: ResolveDisputeParams) -> Re
  
                 // Update profiles
                 buyer_profile.record_dispute_raised(true);
+ 
+                let old_score = seller_profile.reputation_score;
                 seller_profile.record_dispute_against();
  
+                emit!(ReputationUpdated {
+                    user: dispute.seller,
+                    old_score,
+                    new_score: seller_profile.reputation_score,
+                    reason: reputation_reasons::DISPUTE_LOST,
+                    timestamp: clock.unix_timestamp,
+                    event_seq: stats.next_event_seq(),
+                });
+ 
                 emit!(EscrowRefunded {
                     escrow_id: escrow.key(),
                     auction_id,
@@ -169,11 +246,14 @@ pub fn handler(ctx: Context<ResolveDispute>, params: ResolveDisputeParams) -> Re
                     amount: payment_amount,
                     reason: refund_reasons::DISPUTE_RESOLVED,
                     timestamp: clock.unix_timestamp,
+                    event_seq: stats.next_event_seq(),
                 });
+ 
+                (payment_amount, 0)
             }
             DisputeOutcome::ReleaseToSeller => {
                 // Pay seller minus platform fee
-                let seller_receives = payment_amount - platform_fee;
+                let seller_receives = outcome.seller_payout(payment_amount, platform_fee)?;
  
                 transfer(
                     CpiContext::new_with_signer(
@@ -181,11 +261,11 @@ pub fn handler(ctx: Context<ResolveDispute>, params: ResolveDisputeParams) -> Re
                         Transfer {
                             from: ctx.accounts.escrow_vault.to_account_info(),
                             to: ctx.accounts.fee_collector.to_account_info(),
-                            authority: ctx.accounts.escrow_vault.to_account_info(),
+                            authority: escrow.to_account_info(),
                         },
-                        &[escrow_vault_seeds],
+                        &[escrow_seeds],
                     ),
-                    platform_fee,
+                    collector_fee,
                 )?;
  
                 transfer(
@@ -194,15 +274,30 @@ pub fn handler(ctx: Context<ResolveDispute>, params: ResolveDisputeParams) -> Re
                         Transfer {
                             from: ctx.accounts.escrow_vault.to_account_info(),
                             to: ctx.accounts.seller_token_account.to_account_info(),
-                            authority: ctx.accounts.escrow_vault.to_account_info(),
+                            authority: escrow.to_account_info(),
                         },
-                        &[escrow_vault_seeds],
+                        &[escrow_seeds],
                     ),
                     seller_receives,
                 )?;
  
                 escrow.status = EscrowStatus::Released;
  
+                // Penalize a buyer whose non-delivery claim did not hold up
+                if dispute.penalized_party(outcome) == Some(dispute.buyer) {
+                    let old_score = buyer_profile.reputation_score;
+                    buyer_profile.record_frivolous_dispute();
+ 
+                    emit!(ReputationUpdated {
+                        user: dispute.buyer,
+                        old_score,
+                        new_score: buyer_profile.reputation_score,
+                        reason: reputation_reasons::FRIVOLOUS_DISPUTE,
+                        timestamp: clock.unix_timestamp,
+                        event_seq: stats.next_event_seq(),
+                    });
+                }
+ 
                 emit!(EscrowReleased {
                     escrow_id: escrow.key(),
                     auction_id,
@@ -210,13 +305,15 @@ pub fn handler(ctx: Context<ResolveDispute>, params: ResolveDisputeParams) -> Re
                     amount: seller_receives,
                     platform_fee,
                     timestamp: clock.unix_timestamp,
+                    event_seq: stats.next_event_seq(),
                 });
+ 
+                (0, seller_receives)
             }
             DisputeOutcome::SplitFault | DisputeOutcome::PartialRefund { .. } => {
-                // Split 50/50 minus platform fee
-                let total_after_fee = payment_amount - platform_fee;
-                let buyer_receives = total_after_fee / 2;
-                let seller_receives = total_after_fee - buyer_receives;
+                // Split 50/50, platform fee taken from the seller's half
+                let buyer_receives = buyer_refund;
+                let seller_receives = outcome.seller_payout(payment_amount, platform_fee)?;
  
                 // Platform fee
                 transfer(
@@ -225,11 +322,11 @@ pub fn handler(ctx: Context<ResolveDispute>, params: ResolveDisputeParams) -> Re
                         Transfer {
                             from: ctx.accounts.escrow_vault.to_account_info(),
                             to: ctx.accounts.fee_collector.to_account_info(),
-                            authority: ctx.accounts.escrow_vault.to_account_info(),
+                            authority: escrow.to_account_info(),
                         },
-                        &[escrow_vault_seeds],
+                        &[escrow_seeds],
                     ),
-                    platform_fee,
+                    collector_fee,
                 )?;
  
                 // Buyer portion
@@ -239,9 +336,9 @@ pub fn handler(ctx: Context<ResolveDispute>, params: ResolveDisputeParams) -> Re
                         Transfer {
                             from: ctx.accounts.escrow_vault.to_account_info(),
                             to: ctx.accounts.buyer_token_account.to_account_info(),
-                            authority: ctx.accounts.escrow_vault.to_account_info(),
+                            authority: escrow.to_account_info(),
                         },
-                        &[escrow_vault_seeds],
+                        &[escrow_seeds],
                     ),
                     buyer_receives,
                 )?;
@@ -253,46 +350,109 @@ pub fn handler(ctx: Context<ResolveDispute>, params: ResolveDisputeParams) -> Re
                         Transfer {
                             from: ctx.accounts.escrow_vault.to_account_info(),
                             to: ctx.accounts.seller_token_account.to_account_info(),
-                            authority: ctx.accounts.escrow_vault.to_account_info(),
+                            authority: escrow.to_account_info(),
                         },
-                        &[escrow_vault_seeds],
+                        &[escrow_seeds],
                     ),
                     seller_receives,
                 )?;
  
                 escrow.status = EscrowStatus::Released;
                 dispute.refund_amount = Some(buyer_receives);
+ 
+                (buyer_receives, seller_receives)
             }
             DisputeOutcome::ReturnForRefund => {
-                // Same as full refund for now
+                // Funds stay escrowed until the seller confirms the item came back
+                // (or the return window lapses); see confirm_return
+                dispute.refund_amount = Some(payment_amount);
+                dispute.return_tracking = Some(ReturnTracking {
+                    refund_amount: payment_amount,
+                    return_deadline: clock.unix_timestamp + config.return_window,
+                });
+ 
+                (0, 0)
+            }
+        };
+ 
+        // Pay the arbitrator's cut into the fee pool for later withdrawal
+        if pooled_fee > 0 {
+            if let (Some(fee_pool), Some(fee_vault)) = (
+                ctx.accounts.arbitrator_fee_pool.as_mut(),
+                &ctx.accounts.arbitrator_fee_vault,
+            ) {
                 transfer(
                     CpiContext::new_with_signer(
                         ctx.accounts.token_program.to_account_info(),
                         Transfer {
                             from: ctx.accounts.escrow_vault.to_account_info(),
-                            to: ctx.accounts.buyer_token_account.to_account_info(),
-                            authority: ctx.accounts.escrow_vault.to_account_info(),
+                            to: fee_vault.to_account_info(),
+                            authority: escrow.to_account_info(),
                         },
-                        &[escrow_vault_seeds],
+                        &[escrow_seeds],
                     ),
-                    payment_amount,
+                    pooled_fee,
                 )?;
  
-                escrow.status = EscrowStatus::Refunded;
+                fee_pool.accrue(arbitrator_record, pooled_fee);
             }
         }
  
+        // Return the bond to a raiser who prevailed; otherwise it compensates the counterparty
+        let bond = dispute.bond_amount;
+        if bond > 0 {
+            let bond_vault = ctx
+                .accounts
+                .bond_vault
+                .as_ref()
+                .ok_or(DisputeError::InvalidDisputeState)?;
+            let refunded = dispute.raiser_prevailed(outcome);
+            let recipient = dispute.bond_recipient(outcome);
+            let recipient_account = if recipient == dispute.buyer {
+                ctx.accounts.buyer_token_account.to_account_info()
+            } else {
+                ctx.accounts.seller_token_account.to_account_info()
+            };
+            let dispute_bump = dispute.bump;
+            let dispute_seeds = &[b"dispute".as_ref(), auction_id.as_ref(), &[dispute_bump]];
+ 
+            transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: bond_vault.to_account_info(),
+                        to: recipient_account,
+                        authority: dispute.to_account_info(),
+                    },
+                    &[dispute_seeds],
+                ),
+                bond,
+            )?;
+            dispute.bond_amount = 0;
+ 
+            emit!(DisputeBondSettled {
+                dispute_id: dispute.key(),
+                raised_by: dispute.raised_by,
+                recipient,
+                amount: bond,
+                refunded,
+                timestamp: clock.unix_timestamp,
+                event_seq: stats.next_event_seq(),
+            });
+        }
+ 
         // Resolve dispute
         dispute.resolve(outcome, dispute.refund_amount);
-        escrow.released_at = Some(clock.unix_timestamp);
+        if dispute.return_tracking.is_none() {
+            escrow.released_at = Some(clock.unix_timestamp);
+        }
  
         // Update arbitrator record
         let resolution_time = (clock.unix_timestamp - dispute.opened_at) as u64;
-        let arbitrator_fee = platform_fee / 10; // 10% of platform fee to arbitrator
         arbitrator_record.complete_case(resolution_time, arbitrator_fee);
  
         // Update stats
-        stats.dispute_resolved();
+        stats.dispute_resolved(clock.unix_timestamp);
  
         // Emit resolution event
         emit!(DisputeResolved {
@@ -304,10 +464,15 @@ pub fn handler(ctx: Context<ResolveDispute>, params: ResolveDisputeParams) -> Re
                 _ => 2,
             },
             refund_amount: dispute.refund_amount.unwrap_or(0),
+            buyer_received,
+            seller_received,
+            platform_fee,
+            arbitrator_fee,
             arbitrator: ctx.accounts.arbitrator.key(),
             votes_buyer: dispute.votes_for_buyer,
             votes_seller: dispute.votes_for_seller,
             timestamp: clock.unix_timestamp,
+            event_seq: stats.next_event_seq(),
         });
  
         msg!(
@@ -2,7 +2,8 @@ use anchor_lang::prelude::*;
  
 use crate::state::*;
 use crate::errors::*;
-use crate::events::{BidRevealed, RevealPhaseStarted};
+use crate::events::{BidRevealed, LeaderChanged, RevealPhaseStarted};
+use crate::verifier::{to_field, u64_to_field, verify_reveal_proof};
  
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct RevealBidParams {
@@ -26,7 +27,14 @@ pub struct RevealBid<'info> {
  
     #[account(
         mut,
-        seeds = [b"auction", auction.seller.as_ref(), &auction.start_time.to_le_bytes()],
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        mut,
+        seeds = [b"auction", auction.seller.as_ref(), &auction.listing_seed.to_le_bytes()],
         bump = auction.bump
     )]
     pub auction: Account<'info, AuctionState>,
@@ -42,18 +50,69 @@ pub struct RevealBid<'info> {
  
     pub bidder: Signer<'info>,
  
+    /// Price feed for the bid's mint (required on multi-mint auctions)
+    #[account(
+        seeds = [b"price_feed", bid.payment_mint.as_ref()],
+        bump = price_feed.bump
+    )]
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+ 
+    /// Price feed for the primary payment mint (required to check a bid in another mint
+    /// against the minimum bid and bid cap)
+    #[account(
+        seeds = [b"price_feed", auction.payment_mint.as_ref()],
+        bump = primary_price_feed.bump
+    )]
+    pub primary_price_feed: Option<Account<'info, PriceFeed>>,
+ 
     /// CHECK: Light Protocol state tree
     #[account(mut, constraint = state_tree.key() == config.state_tree)]
     pub state_tree: AccountInfo<'info>,
 }
  
 pub fn handler(ctx: Context<RevealBid>, params: RevealBidParams) -> Result<()> {
-    let auction = &mut ctx.accounts.auction;
-    let bid = &mut ctx.accounts.bid;
+    apply_reveal(
+        &ctx.accounts.config,
+        &mut ctx.accounts.stats,
+        &mut ctx.accounts.auction,
+        &mut ctx.accounts.bid,
+        &params,
+        ctx.accounts.price_feed.as_deref(),
+        ctx.accounts.primary_price_feed.as_deref(),
+    )
+}
+ 
+/// Verify a reveal against the bid's commitment and record it on the auction
+pub(crate) fn apply_reveal(
+    config: &ProgramConfig,
+    stats: &mut ProgramStats,
+    auction: &mut Account<AuctionState>,
+    bid: &mut Account<BidCommitment>,
+    params: &RevealBidParams,
+    price_feed: Option<&PriceFeed>,
+    primary_price_feed: Option<&PriceFeed>,
+) -> Result<()> {
     let clock = Clock::get()?;
+    let bidder = bid.bidder;
+ 
+    // A reveal racing settlement must not change revealed_count or the ranking after
+    // the result is fixed, whether settlement finished or a chunked one has begun
+    require!(
+        auction.status != AuctionStatus::Settled && auction.settlement_cursor == 0,
+        AuctionError::AuctionAlreadySettled
+    );
+ 
+    // Terminal states (cancelled, expired, settled, disputed) never re-enter the reveal phase
+    require!(
+        auction.is_active() || auction.is_revealing(),
+        AuctionError::InvalidAuctionState
+    );
+ 
+    // Phase boundaries are judged with the configured clock skew tolerance
+    let phase_time = config.phase_time(clock.unix_timestamp);
  
     // Check if we need to transition to reveal phase
-    if auction.status == AuctionStatus::Active && clock.unix_timestamp >= auction.end_time {
+    if auction.status == AuctionStatus::Active && phase_time >= auction.end_time {
         auction.status = AuctionStatus::Revealing;
  
         emit!(RevealPhaseStarted {
@@ -61,18 +120,19 @@ pub fn handler(ctx: Context<RevealBid>, params: RevealBidParams) -> Result<()> {
             total_bids: auction.bid_count,
             reveal_deadline: auction.reveal_deadline(),
             timestamp: clock.unix_timestamp,
+            event_seq: stats.next_event_seq(),
         });
     }
  
     // Verify auction is in reveal phase
     require!(
-        auction.can_reveal_bids(clock.unix_timestamp),
+        auction.can_reveal_bids(phase_time),
         AuctionError::NotInRevealPhase
     );
  
-    // Verify reveal deadline hasn't passed
+    // Verify reveal deadline (plus any grace) hasn't passed
     require!(
-        clock.unix_timestamp < auction.reveal_deadline(),
+        phase_time < auction.reveal_close_time(),
         BidError::RevealDeadlinePassed
     );
  
@@ -84,31 +144,85 @@ pub fn handler(ctx: Context<RevealBid>, params: RevealBidParams) -> Result<()> {
     };
  
     require!(
-        bid_reveal.verify_commitment(&bid.commitment_hash, &ctx.accounts.bidder.key()),
+        bid_reveal.verify_commitment(
+            &bid.commitment_hash,
+            &bidder,
+            &auction.key()
+        ),
         BidError::CommitmentMismatch
     );
  
-    // Verify ZK proof (in production, verify actual proof)
-    require!(!params.proof.is_empty(), BidError::InvalidProof);
+    // Verify ZK proof of the revealed amount against the commitment
+    verify_reveal_proof(
+        config,
+        &params.proof,
+        &[to_field(&bid.commitment_hash), u64_to_field(params.amount)],
+    )?;
+ 
+    // Zero-value reveals would pollute the winner and second-price tracking
+    require!(params.amount > 0, BidError::ZeroBidAmount);
+ 
+    // A bid above its declared ceiling is under-collateralized
+    require!(
+        auction.collateral_covers(params.amount, bid.max_bid),
+        AuctionError::CollateralTooLow
+    );
+ 
+    // Normalize the bid so bids in different mints compare on a common unit
+    let bid_value = if auction.is_multi_mint() {
+        let price_feed = price_feed.ok_or(OracleError::PriceFeedRequired)?;
+        price_feed.normalize(params.amount)?
+    } else {
+        params.amount
+    };
+ 
+    // The minimum bid and cap are denominated in the primary payment mint, so a bid in
+    // another mint is held to their oracle value instead
+    let bid_measure = if bid.payment_mint == auction.payment_mint {
+        params.amount
+    } else {
+        bid_value
+    };
+    if auction.min_bid > 0 {
+        let min_bid =
+            auction.bound_for_mint(auction.min_bid, &bid.payment_mint, primary_price_feed)?;
+        require!(bid_measure >= min_bid, BidError::BelowMinimumBid);
+    }
+    if let Some(cap) = auction.max_bid_cap {
+        let cap = auction.bound_for_mint(cap, &bid.payment_mint, primary_price_feed)?;
+        require!(bid_measure <= cap, BidError::AboveBidCap);
+    }
  
     // Update bid state
-    bid.reveal(params.amount);
+    bid.reveal(params.amount, bid_value);
+    bid.late_revealed = auction.is_late_reveal(phase_time);
+    bid.early_revealed = auction.is_early_reveal(phase_time, config.early_reveal_window_bps);
  
     // Update auction revealed count
     auction.revealed_count += 1;
  
     // Track highest and second-highest bids for second-price calculation
-    let current_highest = auction.winning_amount.unwrap_or(0);
-    let current_second = auction.second_price.unwrap_or(0);
- 
-    if params.amount > current_highest {
-        // New highest bid
-        auction.second_price = auction.winning_amount;
-        auction.winning_amount = Some(params.amount);
-        auction.winner = Some(ctx.accounts.bidder.key());
-    } else if params.amount > current_second {
-        // New second-highest bid
-        auction.second_price = Some(params.amount);
+    let previous_leader = auction.winner;
+    let takes_lead = auction.record_revealed_bid(
+        bidder,
+        params.amount,
+        bid.payment_mint,
+        bid_value,
+        bid.timestamp,
+    );
+ 
+    if takes_lead {
+        auction.record_new_leader(previous_leader.is_some(), clock.unix_timestamp);
+        emit!(LeaderChanged {
+            auction_id: auction.key(),
+            previous_leader,
+            new_leader: bidder,
+            highest_amount: params.amount,
+            payment_mint: bid.payment_mint,
+            second_price: auction.second_price.unwrap_or(0),
+            timestamp: clock.unix_timestamp,
+            event_seq: stats.next_event_seq(),
+        });
     }
  
     // Update compressed bid in Merkle tree
@@ -118,11 +232,13 @@ pub fn handler(ctx: Context<RevealBid>, params: RevealBidParams) -> Result<()> {
     emit!(BidRevealed {
         bid_id: bid.key(),
         auction_id: auction.key(),
-        bidder: ctx.accounts.bidder.key(),
+        bidder,
         amount: params.amount,
         current_highest: auction.winning_amount.unwrap_or(0),
         revealed_count: auction.revealed_count,
+        late: bid.late_revealed,
         timestamp: clock.unix_timestamp,
+        event_seq: stats.next_event_seq(),
     });
  
     msg!(
@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::instructions::reveal_bid::{apply_reveal, RevealBidParams};
+use crate::verifier::verify_ed25519_authorization;
+ 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct DelegatedReveal {
+    /// Bidder who pre-signed the reveal
+    pub bidder: Pubkey,
+    /// The reveal payload the bidder signed
+    pub reveal: RevealBidParams,
+}
+ 
+#[derive(Accounts)]
+pub struct RevealOnBehalf<'info> {
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.paused @ ConfigError::ProgramPaused
+    )]
+    pub config: Account<'info, ProgramConfig>,
+ 
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        mut,
+        seeds = [b"auction", auction.seller.as_ref(), &auction.listing_seed.to_le_bytes()],
+        bump = auction.bump
+    )]
+    pub auction: Account<'info, AuctionState>,
+ 
+    /// Anyone may submit pre-authorized reveals
+    pub keeper: Signer<'info>,
+ 
+    /// CHECK: Instructions sysvar, read for the bidders' Ed25519 signature checks
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: AccountInfo<'info>,
+ 
+    /// Price feed for the primary payment mint (required to check bids in other mints
+    /// against the minimum bid and bid cap)
+    #[account(
+        seeds = [b"price_feed", auction.payment_mint.as_ref()],
+        bump = primary_price_feed.bump
+    )]
+    pub primary_price_feed: Option<Account<'info, PriceFeed>>,
+ 
+    /// CHECK: Light Protocol state tree
+    #[account(mut, constraint = state_tree.key() == config.state_tree)]
+    pub state_tree: AccountInfo<'info>,
+    // remaining_accounts, per reveal in params order: the bidder's BidCommitment,
+    // followed by the price feed for the bid's mint on multi-mint auctions
+}
+ 
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RevealOnBehalf<'info>>,
+    reveals: Vec<DelegatedReveal>,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let stats = &mut ctx.accounts.stats;
+    let auction = &mut ctx.accounts.auction;
+    let primary_price_feed = ctx.accounts.primary_price_feed.as_deref();
+ 
+    require!(
+        !reveals.is_empty() && reveals.len() <= AuctionState::MAX_REVEAL_BATCH,
+        AuctionError::BatchTooLarge
+    );
+ 
+    let accounts_per_reveal = if auction.is_multi_mint() { 2 } else { 1 };
+    require!(
+        ctx.remaining_accounts.len() == reveals.len() * accounts_per_reveal,
+        AuctionError::InvalidBatchAccounts
+    );
+ 
+    let auction_key = auction.key();
+    for (item, accounts) in reveals
+        .iter()
+        .zip(ctx.remaining_accounts.chunks(accounts_per_reveal))
+    {
+        let mut bid = Account::<BidCommitment>::try_from(&accounts[0])?;
+        require!(
+            bid.auction_id == auction_key && bid.bidder == item.bidder,
+            AuctionError::InvalidBatchAccounts
+        );
+        require!(!bid.revealed, BidError::BidAlreadyRevealed);
+ 
+        let price_feed = match accounts.get(1) {
+            Some(info) => {
+                let price_feed = Account::<PriceFeed>::try_from(info)?;
+                require_keys_eq!(price_feed.mint, bid.payment_mint, OracleError::PriceFeedRequired);
+                Some(price_feed)
+            }
+            None => None,
+        };
+ 
+        // The bidder must have signed this exact reveal
+        let bid_reveal = BidReveal {
+            amount: item.reveal.amount,
+            salt: item.reveal.salt,
+            proof: vec![],
+        };
+        verify_ed25519_authorization(
+            &ctx.accounts.instructions,
+            &item.bidder,
+            &bid_reveal.authorization_message(&item.bidder, &auction_key),
+        )?;
+ 
+        apply_reveal(
+            config,
+            stats,
+            auction,
+            &mut bid,
+            &item.reveal,
+            price_feed.as_deref(),
+            primary_price_feed,
+        )?;
+        bid.exit(&crate::ID)?;
+    }
+ 
+    msg!(
+        "{} reveals processed for auction {} by {}",
+        reveals.len(),
+        auction_key,
+        ctx.accounts.keeper.key()
+    );
+ 
+    Ok(())
+}
\ No newline at end of file
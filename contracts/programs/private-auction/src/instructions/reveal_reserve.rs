@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::ReserveRevealed;
+ 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RevealReserveParams {
+    /// Reserve price in payment_mint base units (USD cents for a USD reserve)
+    pub reserve_price: u64,
+    /// The salt used in the reserve commitment
+    pub salt: [u8; 32],
+}
+ 
+#[derive(Accounts)]
+#[instruction(params: RevealReserveParams)]
+pub struct RevealReserve<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        mut,
+        seeds = [b"auction", auction.seller.as_ref(), &auction.listing_seed.to_le_bytes()],
+        bump = auction.bump,
+        constraint = auction.seller == seller.key() @ AuctionError::OnlySeller,
+        constraint = auction.status == AuctionStatus::Revealing @ AuctionError::NotInRevealPhase
+    )]
+    pub auction: Account<'info, AuctionState>,
+ 
+    /// Price feed for the payment mint (required on multi-mint auctions and USD reserves)
+    #[account(
+        seeds = [b"price_feed", auction.payment_mint.as_ref()],
+        bump = price_feed.bump
+    )]
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+ 
+    pub seller: Signer<'info>,
+}
+ 
+pub fn handler(ctx: Context<RevealReserve>, params: RevealReserveParams) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    let clock = Clock::get()?;
+ 
+    require!(
+        auction.reserve_value.is_none(),
+        AuctionError::ReserveAlreadyRevealed
+    );
+ 
+    let computed_hash = AuctionState::compute_reserve_hash(
+        params.reserve_price,
+        &params.salt,
+        &ctx.accounts.seller.key(),
+    );
+    require!(
+        computed_hash == auction.reserve_price_hash,
+        AuctionError::ReserveMismatch
+    );
+ 
+    // A USD reserve is kept in cents; bids are converted at the oracle price when checked
+    if auction.reserve_in_usd {
+        auction.reserve_usd_cents = Some(params.reserve_price);
+    } else {
+        // Normalize so the reserve compares against winning_value
+        let reserve_value = if auction.is_multi_mint() {
+            let price_feed = ctx
+                .accounts
+                .price_feed
+                .as_ref()
+                .ok_or(OracleError::PriceFeedRequired)?;
+            price_feed.normalize(params.reserve_price)?
+        } else {
+            params.reserve_price
+        };
+        auction.reserve_value = Some(reserve_value);
+    }
+ 
+    emit!(ReserveRevealed {
+        auction_id: auction.key(),
+        reserve_price: params.reserve_price,
+        reserve_met: auction.reserve_met_at(ctx.accounts.price_feed.as_deref())?,
+        timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
+    });
+ 
+    msg!(
+        "Reserve for auction {} revealed: {}",
+        auction.key(),
+        params.reserve_price
+    );
+ 
+    Ok(())
+}
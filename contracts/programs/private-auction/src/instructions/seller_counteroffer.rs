@@ -0,0 +1,203 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, transfer, Transfer, close_account, CloseAccount};
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::{AuctionFailed, CounterofferResolved, cancellation_reasons};
+ 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SellerCounterofferParams {
+    /// Accept the highest below-reserve bid (true) or expire the auction (false)
+    pub accept: bool,
+}
+ 
+#[derive(Accounts)]
+#[instruction(params: SellerCounterofferParams)]
+pub struct SellerCounteroffer<'info> {
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+ 
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        mut,
+        seeds = [b"auction", auction.seller.as_ref(), &auction.listing_seed.to_le_bytes()],
+        bump = auction.bump,
+        constraint = auction.seller == seller.key() @ AuctionError::OnlySeller,
+        constraint = auction.status == AuctionStatus::Revealing @ AuctionError::InvalidAuctionState
+    )]
+    pub auction: Account<'info, AuctionState>,
+ 
+    #[account(
+        mut,
+        seeds = [b"escrow", auction.key().as_ref()],
+        bump = escrow.bump
+    )]
+    pub escrow: Account<'info, EscrowAccount>,
+ 
+    /// NFT escrow (optional, for returning the NFT on decline)
+    #[account(
+        mut,
+        constraint = nft_escrow.owner == escrow.key(),
+        constraint = Some(nft_escrow.mint) == auction.nft_mint
+    )]
+    pub nft_escrow: Option<Account<'info, TokenAccount>>,
+ 
+    /// Seller's NFT token account (optional, for returning the NFT on decline)
+    #[account(
+        mut,
+        constraint = seller_nft_account.owner == auction.seller,
+        constraint = Some(seller_nft_account.mint) == auction.nft_mint
+    )]
+    pub seller_nft_account: Option<Account<'info, TokenAccount>>,
+ 
+    #[account(mut)]
+    pub seller: Signer<'info>,
+ 
+    /// CHECK: Seller's storefront aggregate at its seed-derived address. Always passed, and
+    /// updated whenever the seller has created one, so its listing count can't drift.
+    #[account(
+        mut,
+        seeds = [b"storefront", auction.seller.as_ref()],
+        bump
+    )]
+    pub storefront: UncheckedAccount<'info>,
+ 
+    /// Price feed for the payment mint (required to check a USD reserve on single-mint auctions)
+    #[account(
+        seeds = [b"price_feed", auction.payment_mint.as_ref()],
+        bump = price_feed.bump
+    )]
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+ 
+    pub token_program: Program<'info, Token>,
+}
+ 
+pub fn handler(ctx: Context<SellerCounteroffer>, params: SellerCounterofferParams) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    let escrow = &mut ctx.accounts.escrow;
+    let stats = &mut ctx.accounts.stats;
+    let clock = Clock::get()?;
+    let phase_time = ctx.accounts.config.phase_time(clock.unix_timestamp);
+ 
+    // Only between the end of the reveal phase and the counter-offer deadline
+    require!(
+        phase_time >= auction.reveal_close_time()
+            && phase_time < auction.counteroffer_deadline(),
+        AuctionError::CounterofferWindowClosed
+    );
+ 
+    // Only for a revealed highest bid that missed a revealed reserve
+    require!(auction.revealed_count > 0, AuctionError::NoBidsPlaced);
+    require!(
+        auction.revealed_count >= ctx.accounts.config.min_reveals_for_settlement,
+        AuctionError::InsufficientReveals
+    );
+    let reserve_met = auction.reserve_met_at(ctx.accounts.price_feed.as_deref())?;
+    require!(
+        auction.reserve_revealed() && !reserve_met,
+        AuctionError::InvalidAuctionState
+    );
+ 
+    let highest_amount = auction.winning_amount.ok_or(AuctionError::NoBidsPlaced)?;
+ 
+    if params.accept {
+        // Lower the reserve to the highest bid and settle at that bid
+        auction.reserve_value = auction.winning_value;
+        auction.reserve_usd_cents = None;
+        auction.second_price = Some(highest_amount);
+        auction.second_value = auction.winning_value;
+    } else {
+        // Return NFT to seller if NFT auction
+        let mut nft_returned = false;
+        if auction.product_type == ProductType::Nft {
+            let (Some(nft_escrow), Some(seller_nft)) = (
+                &ctx.accounts.nft_escrow,
+                &ctx.accounts.seller_nft_account,
+            ) else {
+                return err!(AuctionError::InvalidProductType);
+            };
+ 
+            let auction_key = auction.key();
+            let escrow_seeds = &[
+                b"escrow".as_ref(),
+                auction_key.as_ref(),
+                &[escrow.bump],
+            ];
+ 
+            transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: nft_escrow.to_account_info(),
+                        to: seller_nft.to_account_info(),
+                        authority: escrow.to_account_info(),
+                    },
+                    &[escrow_seeds],
+                ),
+                auction.token_amount,
+            )?;
+ 
+            // Close the NFT escrow account
+            close_account(CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                CloseAccount {
+                    account: nft_escrow.to_account_info(),
+                    destination: ctx.accounts.seller.to_account_info(),
+                    authority: escrow.to_account_info(),
+                },
+                &[escrow_seeds],
+            ))?;
+ 
+            nft_returned = true;
+        }
+ 
+        // No winner: every bidder reclaims collateral via claim_refund
+        auction.winner = None;
+        auction.status = AuctionStatus::Expired;
+        escrow.status = EscrowStatus::Cancelled;
+ 
+        stats.active_auctions = stats.active_auctions.saturating_sub(1);
+        stats.last_updated = clock.unix_timestamp;
+        SellerStorefront::update(&ctx.accounts.storefront, |storefront| {
+            storefront.listing_closed(clock.unix_timestamp);
+        })?;
+ 
+        emit!(AuctionFailed {
+            auction_id: auction.key(),
+            seller: auction.seller,
+            highest_value: auction.winning_value.unwrap_or(0),
+            reserve_value: auction.reserve_value.unwrap_or(0),
+            nft_returned,
+            reason: cancellation_reasons::COUNTEROFFER_DECLINED,
+            timestamp: clock.unix_timestamp,
+            event_seq: stats.next_event_seq(),
+        });
+    }
+ 
+    emit!(CounterofferResolved {
+        auction_id: auction.key(),
+        seller: auction.seller,
+        accepted: params.accept,
+        highest_amount,
+        timestamp: clock.unix_timestamp,
+        event_seq: stats.next_event_seq(),
+    });
+ 
+    msg!(
+        "Seller {} highest bid of {} for auction {}",
+        if params.accept { "accepted" } else { "declined" },
+        highest_amount,
+        auction.key()
+    );
+ 
+    Ok(())
+}
@@ -1,10 +1,18 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount, transfer, Transfer};
+use anchor_spl::token::{Mint, Token, TokenAccount, transfer, Transfer};
  
 use crate::state::*;
 use crate::errors::*;
 use crate::events::{AuctionSettled, EscrowFunded};
  
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SettleAuctionParams {
+    /// Winner's commitment to the settled amounts, required for a private settlement.
+    /// Computed off-chain as in `AuctionState::commit_settlement`, with a blinding factor
+    /// the winner keeps secret until confirming delivery.
+    pub amount_commitment: Option<[u8; 32]>,
+}
+ 
 #[derive(Accounts)]
 pub struct SettleAuction<'info> {
     #[account(
@@ -23,109 +31,433 @@ pub struct SettleAuction<'info> {
  
     #[account(
         mut,
-        seeds = [b"auction", auction.seller.as_ref(), &auction.start_time.to_le_bytes()],
+        seeds = [b"auction", auction.seller.as_ref(), &auction.listing_seed.to_le_bytes()],
         bump = auction.bump,
         constraint = auction.status == AuctionStatus::Revealing @ AuctionError::InvalidAuctionState
     )]
     pub auction: Account<'info, AuctionState>,
+
+    /// Product metadata (category selects the platform fee)
+    #[account(
+        seeds = [b"product", auction.key().as_ref()],
+        bump = product_metadata.bump
+    )]
+    pub product_metadata: Account<'info, ProductMetadata>,
  
     #[account(
         mut,
         seeds = [b"escrow", auction.key().as_ref()],
-        bump = escrow.bump
+        bump = escrow.bump,
+        constraint =
+            escrow.status == EscrowStatus::Created ||
+            escrow.status == EscrowStatus::Funding
+            @ EscrowError::InvalidEscrowState
     )]
     pub escrow: Account<'info, EscrowAccount>,
  
+    /// Escrow vault for the winner's mint (created here if the winner bid in an alternate mint)
     #[account(
-        mut,
-        seeds = [b"escrow_vault", auction.key().as_ref()],
+        init_if_needed,
+        payer = winner,
+        token::mint = winning_mint,
+        token::authority = escrow,
+        seeds = [b"escrow_vault", auction.key().as_ref(), winning_mint.key().as_ref()],
         bump
     )]
     pub escrow_vault: Account<'info, TokenAccount>,
  
     #[account(
+        constraint = Some(winning_mint.key()) == auction.winning_mint @ EscrowError::InvalidTokenMint
+    )]
+    pub winning_mint: Account<'info, Mint>,
+ 
+    #[account(
+        mut,
         seeds = [b"bid", auction.key().as_ref(), winner.key().as_ref()],
         bump = winner_bid.bump,
-        constraint = winner_bid.revealed @ BidError::BidNotFound
+        constraint = winner_bid.revealed @ BidError::BidNotFound,
+        // Cross-check the bid against the auction's recorded winner
+        constraint = Some(winner_bid.bidder) == auction.winner @ AuctionError::InvalidAuctionState,
+        constraint = winner_bid.revealed_amount == auction.winning_amount @ AuctionError::InvalidAuctionState
     )]
     pub winner_bid: Account<'info, BidCommitment>,
  
+    /// Pool holding the winner's bid collateral
+    #[account(
+        mut,
+        seeds = [b"collateral_pool", winner_bid.collateral_mint.as_ref()],
+        bump = collateral_pool.bump
+    )]
+    pub collateral_pool: Account<'info, CollateralPool>,
+ 
+    #[account(
+        mut,
+        constraint = collateral_pool_vault.key() == collateral_pool.token_account
+    )]
+    pub collateral_pool_vault: Account<'info, TokenAccount>,
+ 
     #[account(
         mut,
         constraint = winner_token_account.owner == winner.key(),
-        constraint = winner_token_account.mint == auction.payment_mint
+        constraint = winner_token_account.mint == winning_mint.key(),
+        // Catch a frozen account up front rather than mid-transfer
+        constraint = !winner_token_account.is_frozen() @ EscrowError::TokenAccountFrozen
     )]
     pub winner_token_account: Account<'info, TokenAccount>,
  
-    /// CHECK: Winner must match auction.winner
-    #[account(constraint = Some(winner.key()) == auction.winner @ AuctionError::InvalidAuctionState)]
-    pub winner: AccountInfo<'info>,
+    /// Winner's token account in the collateral mint (if it differs from the winning mint)
+    #[account(
+        mut,
+        constraint = winner_collateral_account.owner == winner.key(),
+        constraint = winner_collateral_account.mint == winner_bid.collateral_mint,
+        constraint = !winner_collateral_account.is_frozen() @ EscrowError::TokenAccountFrozen
+    )]
+    pub winner_collateral_account: Option<Account<'info, TokenAccount>>,
+ 
+    /// Winner must match auction.winner and sign the payment transfer
+    #[account(
+        mut,
+        constraint = Some(winner.key()) == auction.winner @ AuctionError::InvalidAuctionState
+    )]
+    pub winner: Signer<'info>,
+ 
+    /// Whoever submits the settlement (may be the winner)
+    pub caller: Signer<'info>,
+ 
+    /// Caller's token account in the winning mint, reimbursed when the seller covers
+    /// settlement costs (no reimbursement is paid without one)
+    #[account(
+        mut,
+        constraint = caller_token_account.owner == caller.key(),
+        constraint = caller_token_account.mint == winning_mint.key()
+    )]
+    pub caller_token_account: Option<Account<'info, TokenAccount>>,
+ 
+    /// Winner's profile (closes out a waived-collateral winning bid if provided)
+    #[account(
+        mut,
+        seeds = [b"user_profile", winner.key().as_ref()],
+        bump = winner_profile.bump
+    )]
+    pub winner_profile: Option<Account<'info, UserProfile>>,
  
     /// CHECK: Seller address
     #[account(constraint = seller.key() == auction.seller)]
     pub seller: AccountInfo<'info>,
  
+    /// Seller's profile (reputation discounts the platform fee)
+    #[account(
+        seeds = [b"user_profile", auction.seller.as_ref()],
+        bump = seller_profile.bump
+    )]
+    pub seller_profile: Account<'info, UserProfile>,
+ 
+    /// Seller's stake, re-checked against the tier the oracle-valued payment falls in
+    #[account(
+        seeds = [b"reputation_stake", auction.seller.as_ref()],
+        bump = seller_stake.bump
+    )]
+    pub seller_stake: Account<'info, ReputationStake>,
+ 
     #[account(
         mut,
-        constraint = fee_collector.key() == config.fee_collector
+        constraint = config.is_fee_collector(&fee_collector.key(), &fee_collector.owner),
+        constraint = fee_collector.mint == winning_mint.key()
     )]
     pub fee_collector: Account<'info, TokenAccount>,
  
-    /// NFT escrow (optional, for NFT auctions)
-    #[account(mut)]
+    /// NFT escrow (required for NFT auctions)
+    #[account(
+        mut,
+        constraint = nft_escrow.owner == escrow.key(),
+        constraint = Some(nft_escrow.mint) == auction.nft_mint
+    )]
     pub nft_escrow: Option<Account<'info, TokenAccount>>,
  
-    /// Winner's NFT account (optional, for NFT auctions)
-    #[account(mut)]
+    /// Winner's NFT account (required for NFT auctions)
+    #[account(
+        mut,
+        constraint = winner_nft_account.owner == winner.key(),
+        constraint = Some(winner_nft_account.mint) == auction.nft_mint
+    )]
     pub winner_nft_account: Option<Account<'info, TokenAccount>>,
  
+    /// Seller's token account (required for NFT auctions, which pay out at settlement)
+    #[account(
+        mut,
+        constraint = seller_token_account.owner == auction.seller,
+        constraint = seller_token_account.mint == winning_mint.key()
+    )]
+    pub seller_token_account: Option<Account<'info, TokenAccount>>,
+ 
+    /// CHECK: Price feed for the winner's mint at its seed-derived address (values the
+    /// escrow and checks a USD reserve). Always passed, and used whenever the mint has a
+    /// feed, so the payment can't be valued off the seller's estimate instead.
+    #[account(
+        seeds = [b"price_feed", winning_mint.key().as_ref()],
+        bump
+    )]
+    pub price_feed: UncheckedAccount<'info>,
+ 
+    /// CHECK: Seller's storefront aggregate at its seed-derived address. Always passed, and
+    /// updated whenever the seller has created one, so its listing count can't drift.
+    #[account(
+        mut,
+        seeds = [b"storefront", auction.seller.as_ref()],
+        bump
+    )]
+    pub storefront: UncheckedAccount<'info>,
+ 
+    /// Fulfillment record tracking delivery from here on
+    #[account(
+        init_if_needed,
+        payer = winner,
+        space = FulfillmentRecord::LEN,
+        seeds = [b"fulfillment", auction.key().as_ref()],
+        bump
+    )]
+    pub fulfillment: Account<'info, FulfillmentRecord>,
+ 
     pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 }
  
-pub fn handler(ctx: Context<SettleAuction>) -> Result<()> {
+pub fn handler(ctx: Context<SettleAuction>, params: SettleAuctionParams) -> Result<()> {
     let auction = &mut ctx.accounts.auction;
     let escrow = &mut ctx.accounts.escrow;
     let config = &ctx.accounts.config;
     let stats = &mut ctx.accounts.stats;
     let clock = Clock::get()?;
+    let phase_time = config.phase_time(clock.unix_timestamp);
  
     // Verify auction can be settled
     require!(
-        auction.can_settle(clock.unix_timestamp),
+        auction.can_settle(phase_time),
         AuctionError::CannotSettleYet
     );
  
+    // A chunked settlement must process every bid before funds move
+    require!(
+        !auction.settlement_in_progress(),
+        AuctionError::SettlementIncomplete
+    );
+ 
     // Verify there are revealed bids
     require!(auction.revealed_count > 0, AuctionError::NoBidsPlaced);
  
+    // A private settlement publishes only the winner's blinded commitment to the amounts
+    if auction.private_settlement {
+        auction.settlement_commitment = params
+            .amount_commitment
+            .ok_or(AuctionError::SettlementCommitmentRequired)?;
+    }
+ 
+    // Thin auctions are easy to shill, so require a minimum number of reveals
+    require!(
+        auction.revealed_count >= config.min_reveals_for_settlement,
+        AuctionError::InsufficientReveals
+    );
+ 
+    // Oracle conversions below must use a fresh, stable price
+    let price_feed = PriceFeed::load(&ctx.accounts.price_feed)?;
+    if let Some(price_feed) = &price_feed {
+        config.check_price_feed(price_feed, clock.unix_timestamp)?;
+    }
+ 
+    // Verify the leading bid meets the seller's reserve
+    // (a USD reserve converts the bid through the oracle at today's price)
+    require!(
+        auction.reserve_met_at(price_feed.as_ref())?,
+        AuctionError::ReserveNotMet
+    );
+ 
     // Calculate payment amounts
     let winning_amount = auction.winning_amount.ok_or(AuctionError::NoBidsPlaced)?;
  
     // Second-price auction: winner pays second-highest bid (or reserve if only one bidder)
-    let payment_amount = auction.second_price.unwrap_or(winning_amount);
+    let payment_amount = auction.payment_amount().ok_or(AuctionError::NoBidsPlaced)?;
+ 
+    // Funds already locked via lock_winner_funds count toward the payment
+    if escrow.status == EscrowStatus::Funding {
+        require!(
+            clock.unix_timestamp <= escrow.funding_deadline,
+            EscrowError::FundingWindowExpired
+        );
+    }
+    let mut remaining = payment_amount
+        .saturating_add(escrow.late_penalty)
+        .saturating_sub(escrow.amount);
  
     // Calculate platform fee
-    let platform_fee = config.calculate_fee(payment_amount);
-    let seller_receives = payment_amount - platform_fee;
- 
-    // Transfer payment from winner to escrow vault
-    transfer(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.winner_token_account.to_account_info(),
-                to: ctx.accounts.escrow_vault.to_account_info(),
-                authority: ctx.accounts.winner.to_account_info(),
-            },
-        ),
+    let platform_fee = config.calculate_fee(
         payment_amount,
-    )?;
+        &ctx.accounts.winning_mint.key(),
+        ctx.accounts.product_metadata.category,
+        ctx.accounts.seller_profile.reputation_score,
+    );
+    let seller_receives = payment_amount
+        .checked_sub(platform_fee)
+        .ok_or(ConfigError::ArithmeticOverflow)?;
+ 
+    // A seller who covers settlement costs reimburses the caller out of their proceeds
+    let settlement_reimbursement =
+        if auction.seller_pays_settlement && ctx.accounts.caller_token_account.is_some() {
+            config.settlement_reimbursement(price_feed.as_ref(), seller_receives)?
+        } else {
+            0
+        };
+    let seller_receives = seller_receives - settlement_reimbursement;
+ 
+    // Winner's collateral counts toward the payment; any excess goes back to the winner
+    let winner_bid = &mut ctx.accounts.winner_bid;
+    let mut collateral_credited = 0;
+    if config.credit_winner_collateral && !winner_bid.collateral_returned {
+        let collateral = winner_bid.collateral_deposited;
+        let pool_mint = winner_bid.collateral_mint;
+ 
+        // Collateral posted in another mint can't pay for the item and is returned in full
+        let same_mint = pool_mint == ctx.accounts.winning_mint.key();
+        collateral_credited = if same_mint { collateral.min(remaining) } else { 0 };
+ 
+        // A prompt reveal earns a rebate from penalties withheld in the pool
+        let reveal_rebate = if winner_bid.early_revealed {
+            ctx.accounts
+                .collateral_pool
+                .pay_rebate(config.reveal_rebate(collateral))
+        } else {
+            0
+        };
+        let collateral_excess = collateral - collateral_credited + reveal_rebate;
+        let excess_destination = if same_mint {
+            ctx.accounts.winner_token_account.to_account_info()
+        } else {
+            ctx.accounts
+                .winner_collateral_account
+                .as_ref()
+                .ok_or(EscrowError::InvalidTokenMint)?
+                .to_account_info()
+        };
+ 
+        let collateral_pool = &mut ctx.accounts.collateral_pool;
+        let pool_seeds = &[
+            b"collateral_pool".as_ref(),
+            pool_mint.as_ref(),
+            &[collateral_pool.bump],
+        ];
+ 
+        if collateral_credited > 0 {
+            transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.collateral_pool_vault.to_account_info(),
+                        to: ctx.accounts.escrow_vault.to_account_info(),
+                        authority: collateral_pool.to_account_info(),
+                    },
+                    &[pool_seeds],
+                ),
+                collateral_credited,
+            )?;
+        }
+        if collateral_excess > 0 {
+            transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.collateral_pool_vault.to_account_info(),
+                        to: excess_destination,
+                        authority: collateral_pool.to_account_info(),
+                    },
+                    &[pool_seeds],
+                ),
+                collateral_excess,
+            )?;
+        }
+ 
+        collateral_pool.credit_winner(collateral, collateral_credited)?;
+        winner_bid.collateral_returned = true;
+        remaining -= collateral_credited;
+    }
+ 
+    // Settling closes out a winning bid placed on waived collateral
+    if winner_bid.collateral_waived > 0 {
+        if let Some(winner_profile) = ctx.accounts.winner_profile.as_mut() {
+            winner_profile.close_waived_bid();
+        }
+    }
+ 
+    // Whatever is still missing past the funding grace period carries the late penalty
+    let late_penalty = if escrow.status == EscrowStatus::Funding {
+        escrow.late_penalty_on(remaining, clock.unix_timestamp)
+    } else {
+        0
+    };
+    escrow.late_penalty += late_penalty;
+    remaining += late_penalty;
+ 
+    // Transfer the rest of the payment from winner to escrow vault
+    if remaining > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.winner_token_account.to_account_info(),
+                    to: ctx.accounts.escrow_vault.to_account_info(),
+                    authority: ctx.accounts.winner.to_account_info(),
+                },
+            ),
+            remaining,
+        )?;
+    }
  
-    // Update escrow state
-    escrow.amount = payment_amount;
+    // Update escrow state (the escrow now holds the winner's mint)
+    escrow.token_mint = ctx.accounts.winning_mint.key();
+    escrow.token_account = ctx.accounts.escrow_vault.key();
+    escrow.vault_bump = ctx.bumps.escrow_vault;
+    // Late penalties stay in the escrow and go to the seller with the payment
+    escrow.amount = payment_amount + escrow.late_penalty;
+    escrow.required_amount = payment_amount;
+    escrow.settlement_reimbursement = settlement_reimbursement;
     escrow.payer = Some(ctx.accounts.winner.key());
     escrow.status = EscrowStatus::Funded;
-    escrow.security_level = EscrowAccount::determine_security_level(payment_amount);
+    // Value the payment in USD (oracle if available, else the seller's estimate)
+    escrow.estimated_value_usd_cents = match &price_feed {
+        Some(price_feed) => price_feed.to_usd_cents(payment_amount)?,
+        None => auction.estimated_value_usd_cents,
+    };
+    escrow.security_level =
+        EscrowAccount::determine_security_level(escrow.estimated_value_usd_cents);
+    // The listing's stake tier came from the seller's own estimate. An understated listing
+    // holds the seller's payout until their stake is topped up to the tier of the sale.
+    escrow.required_seller_stake = config.required_seller_stake(escrow.estimated_value_usd_cents);
+ 
+    // Reimburse the settlement caller; the escrow keeps the rest of the payment
+    if let Some(caller_token_account) = ctx
+        .accounts
+        .caller_token_account
+        .as_ref()
+        .filter(|_| settlement_reimbursement > 0)
+    {
+        let auction_key = auction.key();
+        let escrow_seeds = &[
+            b"escrow".as_ref(),
+            auction_key.as_ref(),
+            &[escrow.bump],
+        ];
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.escrow_vault.to_account_info(),
+                    to: caller_token_account.to_account_info(),
+                    authority: escrow.to_account_info(),
+                },
+                &[escrow_seeds],
+            ),
+            settlement_reimbursement,
+        )?;
+        escrow.amount -= settlement_reimbursement;
+    }
  
     // Set release conditions based on product type
     match auction.product_type {
@@ -138,78 +470,118 @@ pub fn handler(ctx: Context<SettleAuction>) -> Result<()> {
         ProductType::Physical => {
             // Physical: Requires delivery confirmation
             escrow.release_conditions.requires_delivery_confirmation = true;
-            escrow.release_conditions.time_lock_duration = 30 * 24 * 60 * 60; // 30 days
+            escrow.release_conditions.time_lock_duration = config.physical_timelock;
             escrow.release_conditions.release_deadline =
                 clock.unix_timestamp + escrow.release_conditions.time_lock_duration;
         }
         ProductType::Digital => {
             // Digital: Short time-lock for download verification
             escrow.release_conditions.requires_delivery_confirmation = false;
-            escrow.release_conditions.time_lock_duration = 24 * 60 * 60; // 24 hours
+            escrow.release_conditions.time_lock_duration = config.digital_timelock;
             escrow.release_conditions.release_deadline =
                 clock.unix_timestamp + escrow.release_conditions.time_lock_duration;
         }
         ProductType::Service => {
             // Service: Confirmation after service delivery
             escrow.release_conditions.requires_delivery_confirmation = true;
-            escrow.release_conditions.time_lock_duration = 14 * 24 * 60 * 60; // 14 days
+            escrow.release_conditions.time_lock_duration = config.service_timelock;
             escrow.release_conditions.release_deadline =
                 clock.unix_timestamp + escrow.release_conditions.time_lock_duration;
         }
     }
  
-    // Handle NFT transfer immediately if NFT auction
+    // NFT auctions deliver and pay out here, so both NFT accounts are required; leaving
+    // them out would strand the payment and the NFT in escrow
     if auction.product_type == ProductType::Nft {
-        if let (Some(nft_escrow), Some(winner_nft)) = (
+        let (Some(nft_escrow), Some(winner_nft)) = (
             &ctx.accounts.nft_escrow,
             &ctx.accounts.winner_nft_account,
-        ) {
-            // Create escrow signer seeds for CPI
-            let auction_key = auction.key();
-            let escrow_seeds = &[
-                b"escrow".as_ref(),
-                auction_key.as_ref(),
-                &[escrow.bump],
-            ];
- 
-            transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    Transfer {
-                        from: nft_escrow.to_account_info(),
-                        to: winner_nft.to_account_info(),
-                        authority: escrow.to_account_info(),
-                    },
-                    &[escrow_seeds],
-                ),
-                1,
-            )?;
- 
-            // Also release funds to seller immediately for NFT
-            let vault_seeds = &[
-                b"escrow_vault".as_ref(),
-                auction_key.as_ref(),
-                &[ctx.bumps.escrow_vault],
-            ];
- 
-            // Transfer fee to collector
-            transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    Transfer {
-                        from: ctx.accounts.escrow_vault.to_account_info(),
-                        to: ctx.accounts.fee_collector.to_account_info(),
-                        authority: ctx.accounts.escrow_vault.to_account_info(),
-                    },
-                    &[vault_seeds],
-                ),
-                platform_fee,
-            )?;
+        ) else {
+            return err!(AuctionError::InvalidProductType);
+        };
+ 
+        // Create escrow signer seeds for CPI
+        let auction_key = auction.key();
+        let escrow_seeds = &[
+            b"escrow".as_ref(),
+            auction_key.as_ref(),
+            &[escrow.bump],
+        ];
+ 
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: nft_escrow.to_account_info(),
+                    to: winner_nft.to_account_info(),
+                    authority: escrow.to_account_info(),
+                },
+                &[escrow_seeds],
+            ),
+            auction.token_amount,
+        )?;
+ 
+        // Also release funds to seller immediately for NFT, so the stake tier of the
+        // sale must already be covered
+        require!(
+            ctx.accounts.seller_stake.amount >= escrow.required_seller_stake,
+            ProfileError::InsufficientStake
+        );
+        let seller_token_account = ctx
+            .accounts
+            .seller_token_account
+            .as_ref()
+            .ok_or(EscrowError::InvalidTokenMint)?;
+ 
+        // Transfer fee to collector
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.escrow_vault.to_account_info(),
+                    to: ctx.accounts.fee_collector.to_account_info(),
+                    authority: escrow.to_account_info(),
+                },
+                &[escrow_seeds],
+            ),
+            platform_fee,
+        )?;
+ 
+        // Pay the seller the rest of the escrow, late-funding penalties included
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.escrow_vault.to_account_info(),
+                    to: seller_token_account.to_account_info(),
+                    authority: escrow.to_account_info(),
+                },
+                &[escrow_seeds],
+            ),
+            seller_receives + escrow.late_penalty,
+        )?;
+ 
+        // Mark escrow as released for NFT
+        escrow.status = EscrowStatus::Released;
+        escrow.released_at = Some(clock.unix_timestamp);
+    }
  
-            // Mark escrow as released for NFT
-            escrow.status = EscrowStatus::Released;
-            escrow.released_at = Some(clock.unix_timestamp);
-        }
+    // Open the fulfillment record; NFTs are delivered by the transfer above
+    let fulfillment = &mut ctx.accounts.fulfillment;
+    if fulfillment.is_new() {
+        fulfillment.auction_id = auction.key();
+        fulfillment.buyer = ctx.accounts.winner.key();
+        fulfillment.seller = auction.seller;
+        fulfillment.downloads_remaining = ctx
+            .accounts
+            .product_metadata
+            .digital_delivery
+            .as_ref()
+            .map_or(0, |digital| digital.max_downloads);
+        fulfillment.bump = ctx.bumps.fulfillment;
+    }
+    if escrow.status == EscrowStatus::Released {
+        fulfillment.delivery_confirmed_at = Some(clock.unix_timestamp);
     }
  
     // Update auction state
@@ -217,38 +589,71 @@ pub fn handler(ctx: Context<SettleAuction>) -> Result<()> {
     auction.second_price = Some(payment_amount);
  
     // Update stats
-    stats.auction_completed(payment_amount, platform_fee);
+    stats.auction_completed(
+        escrow.estimated_value_usd_cents,
+        platform_fee,
+        clock.unix_timestamp,
+    );
+    SellerStorefront::update(&ctx.accounts.storefront, |storefront| {
+        storefront.sale_completed(escrow.estimated_value_usd_cents, clock.unix_timestamp);
+    })?;
  
     // Emit events
+    // Private settlements keep every amount out of the events until delivery is confirmed
+    let private = auction.private_settlement;
+    let public = |amount: u64| if private { 0 } else { amount };
+ 
     emit!(EscrowFunded {
         escrow_id: escrow.key(),
         auction_id: auction.key(),
         payer: ctx.accounts.winner.key(),
-        amount: payment_amount,
-        token_mint: auction.payment_mint,
+        amount: public(payment_amount),
+        late_penalty: public(escrow.late_penalty),
+        token_mint: escrow.token_mint,
         security_level: escrow.security_level as u8,
         timestamp: clock.unix_timestamp,
+        event_seq: stats.next_event_seq(),
     });
  
     emit!(AuctionSettled {
         auction_id: auction.key(),
         winner: ctx.accounts.winner.key(),
-        winning_amount,
-        second_price: payment_amount,
-        platform_fee,
-        seller_receives,
+        winning_amount: public(winning_amount),
+        second_price: public(payment_amount),
+        platform_fee: public(platform_fee),
+        seller_receives: public(seller_receives),
         total_bids: auction.bid_count,
         revealed_bids: auction.revealed_count,
+        winner_revealed_at: auction.winner_revealed_at,
+        leader_changes: auction.leader_changes,
+        top_bids: if private { vec![] } else { auction.top_bid_amounts() },
+        collateral_credited: public(collateral_credited),
+        settlement_reimbursement: public(settlement_reimbursement),
+        amount_commitment: private.then_some(auction.settlement_commitment),
         timestamp: clock.unix_timestamp,
+        event_seq: stats.next_event_seq(),
     });
  
-    msg!(
-        "Auction {} settled. Winner: {}, Payment: {} (winning bid: {})",
-        auction.key(),
-        ctx.accounts.winner.key(),
-        payment_amount,
-        winning_amount
-    );
+    if private {
+        msg!("Auction {} settled privately. Winner: {}", auction.key(), ctx.accounts.winner.key());
+    } else {
+        msg!(
+            "Auction {} settled. Winner: {}, Payment: {} (winning bid: {})",
+            auction.key(),
+            ctx.accounts.winner.key(),
+            payment_amount,
+            winning_amount
+        );
+    }
  
     Ok(())
+}
+ 
+/// Finalize a chunked settlement once begin_settlement has processed every bid
+pub fn finalize_handler(ctx: Context<SettleAuction>, params: SettleAuctionParams) -> Result<()> {
+    require!(
+        ctx.accounts.auction.settlement_complete(),
+        AuctionError::SettlementIncomplete
+    );
+    handler(ctx, params)
 }
\ No newline at end of file
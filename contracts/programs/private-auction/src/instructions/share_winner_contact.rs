@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::WinnerContactShared;
+ 
+#[derive(Accounts)]
+pub struct ShareWinnerContact<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        seeds = [b"auction", auction.seller.as_ref(), &auction.listing_seed.to_le_bytes()],
+        bump = auction.bump,
+        constraint = auction.status == AuctionStatus::Settled @ AuctionError::InvalidAuctionState
+    )]
+    pub auction: Account<'info, AuctionState>,
+ 
+    /// Only the winning bid's contact is ever surfaced
+    #[account(
+        seeds = [b"bid", auction.key().as_ref(), winner_bid.bidder.as_ref()],
+        bump = winner_bid.bump,
+        constraint = Some(winner_bid.bidder) == auction.winner @ AuctionError::InvalidAuctionState,
+        constraint = !winner_bid.encrypted_contact.is_empty() @ BidError::NoContactProvided
+    )]
+    pub winner_bid: Account<'info, BidCommitment>,
+ 
+    #[account(
+        constraint = auction.seller == seller.key() @ AuctionError::OnlySeller
+    )]
+    pub seller: Signer<'info>,
+}
+ 
+pub fn handler(ctx: Context<ShareWinnerContact>) -> Result<()> {
+    let auction = &ctx.accounts.auction;
+    let winner_bid = &ctx.accounts.winner_bid;
+    let clock = Clock::get()?;
+ 
+    emit!(WinnerContactShared {
+        auction_id: auction.key(),
+        winner: winner_bid.bidder,
+        seller: auction.seller,
+        encrypted_contact: winner_bid.encrypted_contact.clone(),
+        timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
+    });
+ 
+    msg!("Winner contact shared for auction {}", auction.key());
+ 
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, transfer, Transfer};
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::StakeSlashed;
+ 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SlashStakeParams {
+    /// Percentage of the offender's stake to slash (1-100)
+    pub percentage: u8,
+}
+ 
+#[derive(Accounts)]
+#[instruction(params: SlashStakeParams)]
+pub struct SlashStake<'info> {
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+ 
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        mut,
+        seeds = [b"dispute", dispute.auction_id.as_ref()],
+        bump = dispute.bump,
+        constraint = !dispute.stake_slashed @ DisputeError::AlreadySlashed
+    )]
+    pub dispute: Account<'info, Dispute>,
+ 
+    /// CHECK: Party found at fault in the dispute
+    #[account(
+        constraint = dispute.fault_parties().map(|(at_fault, _)| at_fault)
+            == Some(offender.key()) @ DisputeError::InvalidDisputeState
+    )]
+    pub offender: AccountInfo<'info>,
+ 
+    #[account(
+        mut,
+        seeds = [b"reputation_stake", offender.key().as_ref()],
+        bump = offender_stake.bump
+    )]
+    pub offender_stake: Account<'info, ReputationStake>,
+ 
+    #[account(
+        mut,
+        seeds = [b"stake_vault", offender.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+ 
+    #[account(
+        mut,
+        seeds = [b"user_profile", offender.key().as_ref()],
+        bump = offender_profile.bump
+    )]
+    pub offender_profile: Account<'info, UserProfile>,
+ 
+    /// Wronged party's token account for their share of the slash
+    #[account(
+        mut,
+        constraint = dispute.fault_parties().map(|(_, wronged)| wronged)
+            == Some(wronged_token_account.owner) @ DisputeError::NotAParty,
+        constraint = wronged_token_account.mint == stake_vault.mint
+    )]
+    pub wronged_token_account: Account<'info, TokenAccount>,
+ 
+    #[account(
+        mut,
+        constraint = config.is_fee_collector(&fee_collector.key(), &fee_collector.owner),
+        constraint = fee_collector.mint == stake_vault.mint
+    )]
+    pub fee_collector: Account<'info, TokenAccount>,
+ 
+    #[account(constraint = config.is_arbitrator(&arbitrator.key()) @ DisputeError::OnlyArbitrator)]
+    pub arbitrator: Signer<'info>,
+ 
+    pub token_program: Program<'info, Token>,
+}
+ 
+pub fn handler(ctx: Context<SlashStake>, params: SlashStakeParams) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let dispute = &mut ctx.accounts.dispute;
+    let offender_stake = &mut ctx.accounts.offender_stake;
+    let clock = Clock::get()?;
+ 
+    require!(
+        params.percentage > 0 && params.percentage <= 100,
+        ConfigError::InvalidParameter
+    );
+ 
+    let slash_amount = offender_stake.slash(params.percentage);
+    let (to_counterparty, to_platform) = config.split_slash(slash_amount);
+ 
+    let offender_key = ctx.accounts.offender.key();
+    let stake_seeds = &[
+        b"reputation_stake".as_ref(),
+        offender_key.as_ref(),
+        &[offender_stake.bump],
+    ];
+ 
+    // Compensate the wronged party
+    if to_counterparty > 0 {
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.stake_vault.to_account_info(),
+                    to: ctx.accounts.wronged_token_account.to_account_info(),
+                    authority: offender_stake.to_account_info(),
+                },
+                &[stake_seeds],
+            ),
+            to_counterparty,
+        )?;
+    }
+ 
+    // Remainder goes to the platform
+    if to_platform > 0 {
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.stake_vault.to_account_info(),
+                    to: ctx.accounts.fee_collector.to_account_info(),
+                    authority: offender_stake.to_account_info(),
+                },
+                &[stake_seeds],
+            ),
+            to_platform,
+        )?;
+    }
+ 
+    dispute.stake_slashed = true;
+    ctx.accounts.offender_profile.staked_amount = offender_stake.amount;
+ 
+    emit!(StakeSlashed {
+        user: offender_key,
+        dispute_id: dispute.key(),
+        amount: slash_amount,
+        to_counterparty,
+        to_platform,
+        remaining_stake: offender_stake.amount,
+        timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
+    });
+ 
+    msg!(
+        "Slashed {} from {} ({} to counterparty, {} to platform)",
+        slash_amount,
+        offender_key,
+        to_counterparty,
+        to_platform
+    );
+ 
+    Ok(())
+}
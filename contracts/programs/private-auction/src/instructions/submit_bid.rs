@@ -4,15 +4,22 @@ use anchor_spl::token::{Token, TokenAccount, transfer, Transfer};
 use crate::state::*;
 use crate::errors::*;
 use crate::events::BidSubmitted;
+use crate::verifier::{to_field, u64_to_field, verify_bid_proof, verify_range_proof};
  
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct SubmitBidParams {
-    /// Commitment hash: poseidon(bid_amount || salt || bidder)
+    /// Commitment hash: poseidon(bid_amount || salt || bidder || auction)
     pub commitment_hash: [u8; 32],
     /// ZK proof of valid bid (bid >= reserve)
     pub proof: Vec<u8>,
     /// Hash of the ZK proof for on-chain storage
     pub proof_hash: [u8; 32],
+    /// Ceiling on the sealed bid, attested by the proof (sizes percentage collateral)
+    pub max_bid: u64,
+    /// Range proof that the committed bid is within the auction's cap (required if capped)
+    pub range_proof: Option<Vec<u8>>,
+    /// Contact details encrypted to the seller's key, shared with the seller if this bid wins
+    pub encrypted_contact: Option<Vec<u8>>,
 }
  
 #[derive(Accounts)]
@@ -34,7 +41,7 @@ pub struct SubmitBid<'info> {
  
     #[account(
         mut,
-        seeds = [b"auction", auction.seller.as_ref(), &auction.start_time.to_le_bytes()],
+        seeds = [b"auction", auction.seller.as_ref(), &auction.listing_seed.to_le_bytes()],
         bump = auction.bump,
         constraint = auction.is_active() @ AuctionError::AuctionNotActive
     )]
@@ -49,6 +56,15 @@ pub struct SubmitBid<'info> {
     )]
     pub bid: Account<'info, BidCommitment>,
  
+    #[account(
+        init,
+        payer = bidder,
+        space = BidIndexEntry::LEN,
+        seeds = [b"bid_index", auction.key().as_ref(), &auction.next_bid_index.to_le_bytes()],
+        bump
+    )]
+    pub bid_index_entry: Account<'info, BidIndexEntry>,
+ 
     #[account(
         mut,
         seeds = [b"escrow", auction.key().as_ref()],
@@ -58,40 +74,65 @@ pub struct SubmitBid<'info> {
  
     #[account(
         mut,
-        seeds = [b"escrow_vault", auction.key().as_ref()],
+        seeds = [b"escrow_vault", auction.key().as_ref(), escrow.token_mint.as_ref()],
         bump
     )]
     pub escrow_vault: Account<'info, TokenAccount>,
  
+    /// Pool for the auction's collateral mint
     #[account(
         mut,
-        seeds = [b"collateral_pool", auction.payment_mint.as_ref()],
-        bump
+        seeds = [b"collateral_pool", collateral_pool.token_mint.as_ref()],
+        bump = collateral_pool.bump,
+        constraint =
+            collateral_pool.token_mint == auction.collateral_mint_for(&bidder_token_account.mint)
+            @ EscrowError::InvalidTokenMint
     )]
     pub collateral_pool: Account<'info, CollateralPool>,
  
     #[account(
         mut,
-        constraint = collateral_pool_vault.mint == auction.payment_mint
+        constraint = collateral_pool_vault.key() == collateral_pool.token_account
     )]
     pub collateral_pool_vault: Account<'info, TokenAccount>,
  
+    /// Bidder's token account in any of the auction's accepted mints
     #[account(
         mut,
         constraint = bidder_token_account.owner == bidder.key(),
-        constraint = bidder_token_account.mint == auction.payment_mint
+        constraint = auction.accepts_mint(&bidder_token_account.mint) @ ConfigError::UnsupportedMint
     )]
     pub bidder_token_account: Account<'info, TokenAccount>,
  
+    /// Bidder's token account in the collateral mint (required when it differs from the bid mint)
     #[account(
+        mut,
+        constraint = bidder_collateral_account.owner == bidder.key(),
+        constraint = bidder_collateral_account.mint == collateral_pool.token_mint @ EscrowError::InvalidTokenMint
+    )]
+    pub bidder_collateral_account: Option<Account<'info, TokenAccount>>,
+ 
+    #[account(
+        mut,
         seeds = [b"user_profile", bidder.key().as_ref()],
-        bump = bidder_profile.bump
+        bump = bidder_profile.bump,
+        constraint = bidder_profile.meets_reputation_threshold(
+            auction.min_bidder_reputation.unwrap_or(0)
+        ) @ ProfileError::InsufficientReputation
     )]
     pub bidder_profile: Account<'info, UserProfile>,
  
     #[account(mut)]
     pub bidder: Signer<'info>,
  
+    /// CHECK: Bidder's wallet link PDA, possibly uninitialized (read by WalletLink::identity_of)
+    #[account(seeds = [b"wallet_link", bidder.key().as_ref()], bump)]
+    pub bidder_link: AccountInfo<'info>,
+ 
+    /// CHECK: Seller's wallet link PDA, possibly uninitialized (read by WalletLink::identity_of)
+    #[account(seeds = [b"wallet_link", auction.seller.as_ref()], bump)]
+    pub seller_link: AccountInfo<'info>,
+ 
     /// CHECK: Light Protocol state tree for compressed bid storage
     #[account(mut, constraint = state_tree.key() == config.state_tree)]
     pub state_tree: AccountInfo<'info>,
@@ -109,25 +150,87 @@ pub fn handler(ctx: Context<SubmitBid>, params: SubmitBidParams) -> Result<()> {
  
     // Verify auction can accept bids
     require!(
-        auction.can_accept_bids(clock.unix_timestamp),
+        auction.can_accept_bids(ctx.accounts.config.phase_time(clock.unix_timestamp)),
         AuctionError::BiddingEnded
     );
  
-    // Verify ZK proof (in production, this would verify the actual proof)
-    // For now, we just verify the proof is non-empty
-    require!(!params.proof.is_empty(), BidError::InvalidProof);
+    // Shill protection: neither the seller nor any wallet sharing their identity may bid
+    let bidder_identity =
+        WalletLink::identity_of(ctx.accounts.bidder.key(), &ctx.accounts.bidder_link)?;
+    let seller_identity = WalletLink::identity_of(auction.seller, &ctx.accounts.seller_link)?;
+    require!(bidder_identity != seller_identity, BidError::SellerCannotBid);
+ 
+    // Verify the ZK proof binds the commitment to the reserve and declared ceiling
+    verify_bid_proof(
+        &ctx.accounts.config,
+        &params.proof,
+        &[
+            to_field(&params.commitment_hash),
+            to_field(&auction.reserve_price_hash),
+            u64_to_field(params.max_bid),
+        ],
+    )?;
+ 
+    // Capped auctions require a range proof that the sealed bid is within the cap
+    if let Some(cap) = auction.max_bid_cap {
+        let range_proof = params.range_proof.as_ref().ok_or(BidError::InvalidProof)?;
+        verify_range_proof(
+            &ctx.accounts.config,
+            range_proof,
+            &[to_field(&params.commitment_hash), u64_to_field(cap)],
+        )?;
+    }
+ 
+    let encrypted_contact = params.encrypted_contact.unwrap_or_default();
+    require!(
+        encrypted_contact.len() <= BidCommitment::MAX_ENCRYPTED_CONTACT_LEN,
+        BidError::InvalidContact
+    );
+ 
+    // Collateral must back the declared ceiling in percentage mode
+    if matches!(auction.collateral_mode, CollateralMode::Percentage { .. }) {
+        require!(params.max_bid > 0, AuctionError::CollateralTooLow);
+    }
+    let required_collateral = auction.required_collateral(params.max_bid);
+ 
+    // Trusted bidders post less; their reputation backs the waived share until the bid
+    // is refunded or settled
+    let bidder_profile = &mut ctx.accounts.bidder_profile;
+    let collateral_waived = if bidder_profile.can_open_waived_bid() {
+        ctx.accounts
+            .config
+            .collateral_waiver(required_collateral, bidder_profile.reputation_score)
+    } else {
+        0
+    };
+    if collateral_waived > 0 {
+        bidder_profile.open_waived_bids += 1;
+    }
+    let collateral = required_collateral - collateral_waived;
+ 
+    // Collateral comes from the bid's own token account unless posted in another mint
+    let collateral_mint = collateral_pool.token_mint;
+    let collateral_source = if collateral_mint == ctx.accounts.bidder_token_account.mint {
+        ctx.accounts.bidder_token_account.to_account_info()
+    } else {
+        ctx.accounts
+            .bidder_collateral_account
+            .as_ref()
+            .ok_or(EscrowError::InvalidTokenMint)?
+            .to_account_info()
+    };
  
     // Transfer collateral from bidder to pool
     transfer(
         CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
             Transfer {
-                from: ctx.accounts.bidder_token_account.to_account_info(),
+                from: collateral_source,
                 to: ctx.accounts.collateral_pool_vault.to_account_info(),
                 authority: ctx.accounts.bidder.to_account_info(),
             },
         ),
-        auction.bid_collateral,
+        collateral,
     )?;
  
     // Initialize bid commitment
@@ -135,19 +238,37 @@ pub fn handler(ctx: Context<SubmitBid>, params: SubmitBidParams) -> Result<()> {
     bid.auction_id = auction.key();
     bid.bidder = ctx.accounts.bidder.key();
     bid.commitment_hash = params.commitment_hash;
+    bid.payment_mint = ctx.accounts.bidder_token_account.mint;
+    bid.collateral_mint = collateral_mint;
+    bid.bid_index = auction.next_bid_index;
     bid.timestamp = clock.unix_timestamp;
     bid.revealed = false;
     bid.revealed_amount = None;
+    bid.revealed_value = None;
     bid.proof_hash = params.proof_hash;
-    bid.collateral_deposited = auction.bid_collateral;
+    bid.collateral_deposited = collateral;
     bid.collateral_returned = false;
+    bid.collateral_waived = collateral_waived;
+    bid.max_bid = params.max_bid;
+    bid.late_revealed = false;
+    bid.early_revealed = false;
+    bid.encrypted_contact = encrypted_contact;
     bid.bump = ctx.bumps.bid;
  
     // Update collateral pool
-    collateral_pool.deposit(auction.bid_collateral);
+    collateral_pool.deposit(collateral)?;
+ 
+    // Record the bid in the auction's index
+    let bid_index_entry = &mut ctx.accounts.bid_index_entry;
+    bid_index_entry.auction_id = auction.key();
+    bid_index_entry.index = auction.next_bid_index;
+    bid_index_entry.bid = bid.key();
+    bid_index_entry.bidder = bid.bidder;
+    bid_index_entry.bump = ctx.bumps.bid_index_entry;
  
     // Update auction bid count
     auction.bid_count += 1;
+    auction.next_bid_index += 1;
  
     // Update Merkle root (in production, this would compute actual Merkle tree update)
     // For Light Protocol integration, this would use the state tree
@@ -166,7 +287,7 @@ pub fn handler(ctx: Context<SubmitBid>, params: SubmitBidParams) -> Result<()> {
     auction.bid_merkle_root = compute_merkle_root(&compressed_bid);
  
     // Update stats
-    stats.bid_placed();
+    stats.bid_placed(clock.unix_timestamp);
  
     // Emit event
     emit!(BidSubmitted {
@@ -175,8 +296,10 @@ pub fn handler(ctx: Context<SubmitBid>, params: SubmitBidParams) -> Result<()> {
         commitment_hash: params.commitment_hash,
         proof_hash: params.proof_hash,
         bid_count: auction.bid_count,
-        collateral: auction.bid_collateral,
+        bid_index: bid.bid_index,
+        collateral,
         timestamp: clock.unix_timestamp,
+        event_seq: stats.next_event_seq(),
     });
  
     msg!(
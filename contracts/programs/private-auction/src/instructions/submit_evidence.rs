@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::EvidenceSubmitted;
+ 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SubmitEvidenceParams {
+    /// Evidence type
+    pub evidence_type: EvidenceType,
+    /// Encrypted evidence data (IPFS hash)
+    pub encrypted_data: [u8; 64],
+}
+ 
+#[derive(Accounts)]
+#[instruction(params: SubmitEvidenceParams)]
+pub struct SubmitEvidence<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        mut,
+        seeds = [b"dispute", dispute.auction_id.as_ref()],
+        bump = dispute.bump,
+        constraint =
+            submitter.key() == dispute.buyer ||
+            submitter.key() == dispute.seller
+            @ DisputeError::NotAParty
+    )]
+    pub dispute: Account<'info, Dispute>,
+ 
+    pub submitter: Signer<'info>,
+}
+ 
+pub fn handler(ctx: Context<SubmitEvidence>, params: SubmitEvidenceParams) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let clock = Clock::get()?;
+ 
+    require!(
+        dispute.can_submit_evidence(clock.unix_timestamp),
+        DisputeError::CannotSubmitEvidence
+    );
+ 
+    let submitter = ctx.accounts.submitter.key();
+    let is_buyer = submitter == dispute.buyer;
+    dispute.add_evidence(
+        Evidence {
+            submitter,
+            evidence_type: params.evidence_type,
+            encrypted_data: params.encrypted_data,
+            submitted_at: clock.unix_timestamp,
+            reviewed: false,
+        },
+        is_buyer,
+    )?;
+ 
+    emit!(EvidenceSubmitted {
+        dispute_id: dispute.key(),
+        submitter,
+        evidence_type: params.evidence_type as u8,
+        timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
+    });
+ 
+    msg!("Evidence submitted to dispute {} by {}", dispute.key(), submitter);
+ 
+    Ok(())
+}
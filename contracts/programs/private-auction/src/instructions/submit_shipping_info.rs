@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::ShippingInfoSubmitted;
+ 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SubmitShippingInfoParams {
+    /// Shipping address encrypted to the seller's key
+    pub encrypted_address: Vec<u8>,
+}
+ 
+#[derive(Accounts)]
+#[instruction(params: SubmitShippingInfoParams)]
+pub struct SubmitShippingInfo<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        seeds = [b"auction", auction.seller.as_ref(), &auction.listing_seed.to_le_bytes()],
+        bump = auction.bump,
+        constraint = auction.status == AuctionStatus::Settled @ AuctionError::InvalidAuctionState,
+        constraint = auction.product_type == ProductType::Physical @ AuctionError::InvalidProductType
+    )]
+    pub auction: Account<'info, AuctionState>,
+ 
+    #[account(
+        mut,
+        seeds = [b"fulfillment", auction.key().as_ref()],
+        bump = fulfillment.bump
+    )]
+    pub fulfillment: Account<'info, FulfillmentRecord>,
+ 
+    /// Buyer must match auction winner
+    #[account(
+        constraint = Some(buyer.key()) == auction.winner @ FulfillmentError::OnlyBuyer
+    )]
+    pub buyer: Signer<'info>,
+}
+ 
+pub fn handler(ctx: Context<SubmitShippingInfo>, params: SubmitShippingInfoParams) -> Result<()> {
+    let auction = &ctx.accounts.auction;
+    let fulfillment = &mut ctx.accounts.fulfillment;
+    let clock = Clock::get()?;
+ 
+    require!(
+        !params.encrypted_address.is_empty()
+            && params.encrypted_address.len() <= FulfillmentRecord::MAX_ENCRYPTED_ADDRESS_LEN,
+        FulfillmentError::InvalidShippingInfo
+    );
+ 
+    fulfillment.encrypted_shipping_address = params.encrypted_address;
+    fulfillment.shipping_submitted_at = Some(clock.unix_timestamp);
+ 
+    emit!(ShippingInfoSubmitted {
+        auction_id: auction.key(),
+        buyer: fulfillment.buyer,
+        seller: fulfillment.seller,
+        timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
+    });
+ 
+    msg!("Shipping info submitted for auction {}", auction.key());
+ 
+    Ok(())
+}
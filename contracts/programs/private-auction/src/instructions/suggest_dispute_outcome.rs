@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+ 
+use crate::state::*;
+ 
+/// Advisory default outcome for a dispute
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct OutcomeSuggestion {
+    /// Reason the dispute was raised
+    pub reason: DisputeReason,
+    /// Outcome the reason and evidence point toward (not binding)
+    pub outcome: DisputeOutcome,
+    /// Whether the seller has submitted tracking evidence
+    pub seller_tracking: bool,
+}
+ 
+#[derive(Accounts)]
+pub struct SuggestDisputeOutcome<'info> {
+    #[account(
+        seeds = [b"dispute", dispute.auction_id.as_ref()],
+        bump = dispute.bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+}
+ 
+/// Read-only: report the advisory default outcome for this dispute's reason and evidence
+pub fn handler(ctx: Context<SuggestDisputeOutcome>) -> Result<OutcomeSuggestion> {
+    let dispute = &ctx.accounts.dispute;
+ 
+    Ok(OutcomeSuggestion {
+        reason: dispute.reason,
+        outcome: dispute.suggested_outcome(),
+        seller_tracking: dispute.has_evidence(EvidenceType::Tracking, false),
+    })
+}
\ No newline at end of file
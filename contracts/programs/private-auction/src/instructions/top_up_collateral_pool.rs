@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, transfer, Transfer};
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::CollateralPoolToppedUp;
+ 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct TopUpCollateralPoolParams {
+    /// Extra amount set aside for prompt revealers' rebates, on top of any shortfall
+    pub rebate_funding: u64,
+}
+ 
+#[derive(Accounts)]
+pub struct TopUpCollateralPool<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        mut,
+        seeds = [b"collateral_pool", collateral_pool.token_mint.as_ref()],
+        bump = collateral_pool.bump
+    )]
+    pub collateral_pool: Account<'info, CollateralPool>,
+ 
+    #[account(
+        mut,
+        constraint = collateral_pool_vault.key() == collateral_pool.token_account
+            @ EscrowError::InvalidEscrowState
+    )]
+    pub collateral_pool_vault: Account<'info, TokenAccount>,
+ 
+    #[account(
+        mut,
+        constraint = funder_token_account.owner == funder.key(),
+        constraint = funder_token_account.mint == collateral_pool.token_mint
+            @ EscrowError::InvalidTokenMint
+    )]
+    pub funder_token_account: Account<'info, TokenAccount>,
+ 
+    /// Anyone may restore a pool's solvency or fund its rebates
+    pub funder: Signer<'info>,
+ 
+    pub token_program: Program<'info, Token>,
+}
+ 
+pub fn handler(ctx: Context<TopUpCollateralPool>, params: TopUpCollateralPoolParams) -> Result<()> {
+    let collateral_pool = &mut ctx.accounts.collateral_pool;
+    let clock = Clock::get()?;
+ 
+    // Only the exact shortfall is accepted, plus what is earmarked for rebates; anything
+    // more would be untracked surplus
+    let shortfall = collateral_pool.shortfall(ctx.accounts.collateral_pool_vault.amount);
+    require!(
+        shortfall > 0 || params.rebate_funding > 0,
+        EscrowError::NoCollateralShortfall
+    );
+    let amount = shortfall
+        .checked_add(params.rebate_funding)
+        .ok_or(ConfigError::ArithmeticOverflow)?;
+    collateral_pool.fund_rebates(params.rebate_funding)?;
+ 
+    transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.funder_token_account.to_account_info(),
+                to: ctx.accounts.collateral_pool_vault.to_account_info(),
+                authority: ctx.accounts.funder.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+ 
+    emit!(CollateralPoolToppedUp {
+        pool: collateral_pool.key(),
+        funder: ctx.accounts.funder.key(),
+        amount,
+        total_collateral: collateral_pool.total_collateral,
+        penalty_reserve: collateral_pool.penalty_reserve,
+        vault_balance: ctx.accounts.collateral_pool_vault.amount + amount,
+        timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
+    });
+ 
+    msg!(
+        "Topped up collateral pool {} by {} ({} toward rebates)",
+        collateral_pool.key(),
+        amount,
+        params.rebate_funding
+    );
+ 
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, transfer, Transfer};
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::EscrowToppedUp;
+ 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct TopUpEscrowParams {
+    /// Amount to add (at most the remaining shortfall); any late penalty is charged on top
+    pub amount: u64,
+}
+ 
+#[derive(Accounts)]
+#[instruction(params: TopUpEscrowParams)]
+pub struct TopUpEscrow<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        seeds = [b"auction", auction.seller.as_ref(), &auction.listing_seed.to_le_bytes()],
+        bump = auction.bump,
+        constraint = auction.status == AuctionStatus::Revealing @ AuctionError::InvalidAuctionState
+    )]
+    pub auction: Account<'info, AuctionState>,
+ 
+    #[account(
+        mut,
+        seeds = [b"escrow", auction.key().as_ref()],
+        bump = escrow.bump,
+        constraint = escrow.status == EscrowStatus::Funding @ EscrowError::InvalidEscrowState
+    )]
+    pub escrow: Account<'info, EscrowAccount>,
+ 
+    #[account(
+        mut,
+        seeds = [b"escrow_vault", auction.key().as_ref(), escrow.token_mint.as_ref()],
+        bump
+    )]
+    pub escrow_vault: Account<'info, TokenAccount>,
+ 
+    #[account(
+        mut,
+        constraint = winner_token_account.owner == winner.key(),
+        constraint = winner_token_account.mint == escrow.token_mint,
+        // Catch a frozen account up front rather than mid-transfer
+        constraint = !winner_token_account.is_frozen() @ EscrowError::TokenAccountFrozen
+    )]
+    pub winner_token_account: Account<'info, TokenAccount>,
+ 
+    #[account(
+        constraint = Some(winner.key()) == auction.winner @ AuctionError::InvalidAuctionState
+    )]
+    pub winner: Signer<'info>,
+ 
+    pub token_program: Program<'info, Token>,
+}
+ 
+pub fn handler(ctx: Context<TopUpEscrow>, params: TopUpEscrowParams) -> Result<()> {
+    let escrow = &mut ctx.accounts.escrow;
+    let clock = Clock::get()?;
+ 
+    require!(
+        clock.unix_timestamp <= escrow.funding_deadline,
+        EscrowError::FundingWindowExpired
+    );
+    require!(
+        params.amount > 0 && params.amount <= escrow.shortfall(),
+        EscrowError::AmountMismatch
+    );
+ 
+    // Past the grace period the winner pays an escalating penalty on top of what they add
+    let late_penalty = escrow.late_penalty_on(params.amount, clock.unix_timestamp);
+    let total = params
+        .amount
+        .checked_add(late_penalty)
+        .ok_or(ConfigError::ArithmeticOverflow)?;
+ 
+    transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.winner_token_account.to_account_info(),
+                to: ctx.accounts.escrow_vault.to_account_info(),
+                authority: ctx.accounts.winner.to_account_info(),
+            },
+        ),
+        total,
+    )?;
+ 
+    escrow.amount = escrow
+        .amount
+        .checked_add(total)
+        .ok_or(ConfigError::ArithmeticOverflow)?;
+    escrow.late_penalty += late_penalty;
+ 
+    emit!(EscrowToppedUp {
+        escrow_id: escrow.key(),
+        auction_id: ctx.accounts.auction.key(),
+        amount: params.amount,
+        total_locked: escrow.amount,
+        required_amount: escrow.required_amount,
+        late_penalty,
+        funding_deadline: escrow.funding_deadline,
+        timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
+    });
+ 
+    msg!(
+        "Escrow topped up by {} (shortfall {})",
+        params.amount,
+        escrow.shortfall()
+    );
+ 
+    Ok(())
+}
@@ -0,0 +1,299 @@
+use anchor_lang::prelude::*;
+ 
+use crate::state::{
+    CategoryFeeOverride, Groth16VerifyingKey, MintDisputeBond, MintFeeBounds, ProgramConfig,
+    ProgramStats,
+};
+use crate::errors::ConfigError;
+use crate::events::ConfigUpdated;
+use crate::instructions::initialize_program::validate_supported_mints;
+ 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct UpdateConfigParams {
+    /// New escrow time-lock for physical products (seconds)
+    pub physical_timelock: Option<i64>,
+    /// New escrow time-lock for digital products (seconds)
+    pub digital_timelock: Option<i64>,
+    /// New escrow time-lock for services (seconds)
+    pub service_timelock: Option<i64>,
+    /// New share of slashed stake paid to the wronged party (basis points)
+    pub slash_split_bps: Option<u16>,
+    /// New evidence deadline extension for info requests (seconds)
+    pub info_request_extension: Option<i64>,
+    /// New evidence submission window for new disputes (seconds)
+    pub evidence_period: Option<i64>,
+    /// New resolution window for new disputes (seconds)
+    pub resolution_period: Option<i64>,
+    /// New window for winners to fund the escrow (seconds)
+    pub claim_window: Option<i64>,
+    /// New late-reveal grace period (seconds, 0 disables)
+    pub late_reveal_grace: Option<i64>,
+    /// Whether winner collateral is credited toward settlement payments
+    pub credit_winner_collateral: Option<bool>,
+    /// New cooldown between a user's dispute filings (seconds, 0 disables)
+    pub dispute_cooldown: Option<i64>,
+    /// New minimum reputation stake for voting arbitrators
+    pub min_arbitrator_stake: Option<u64>,
+    /// New keeper reward for permissionless cranks (lamports)
+    pub keeper_reward: Option<u64>,
+    /// New number of revealed bids required to settle (at least 1)
+    pub min_reveals_for_settlement: Option<u32>,
+    /// New seller reputation threshold for fee discounts
+    pub fee_discount_reputation: Option<u16>,
+    /// New fee discount at a perfect reputation score (bps)
+    pub max_fee_discount_bps: Option<u16>,
+    /// New phase-boundary clock skew tolerance (seconds, 0-60)
+    pub clock_skew_tolerance: Option<i64>,
+    /// New bidder reputation that earns a collateral waiver (0 disables it)
+    pub collateral_waiver_reputation: Option<u16>,
+    /// New share of collateral waived for trusted bidders (bps)
+    pub collateral_waiver_bps: Option<u16>,
+    /// New oldest price feed update settlement accepts (seconds)
+    pub max_oracle_age: Option<i64>,
+    /// New largest price move since the previous update settlement accepts (bps)
+    pub max_price_deviation_bps: Option<u16>,
+    /// New per-mint platform fee floors and ceilings (token base units)
+    pub mint_fee_bounds: Option<Vec<MintFeeBounds>>,
+    /// New per-mint bonds a party posts to raise a dispute (token base units)
+    pub dispute_bonds: Option<Vec<MintDisputeBond>>,
+    /// New wait before a seller can relist a cancelled NFT (seconds, 0 disables)
+    pub relist_cooldown: Option<i64>,
+    /// New amount paid to the settler when the seller covers settlement costs
+    /// (USD cents, 0 disables)
+    pub settlement_reimbursement_usd_cents: Option<u64>,
+    /// New number of auctions a seller may have open at once (0 = unlimited)
+    pub max_active_auctions_per_seller: Option<u32>,
+    /// New longest total reveal window a seller can extend an auction to (seconds)
+    pub max_reveal_duration: Option<i64>,
+    /// New window for returning an item on a return-for-refund outcome (seconds)
+    pub return_window: Option<i64>,
+    /// New leading share of the reveal window that counts as a prompt reveal (bps)
+    pub early_reveal_window_bps: Option<u16>,
+    /// New collateral rebate for prompt revealers (bps, 0 disables)
+    pub reveal_rebate_bps: Option<u16>,
+    /// New penalty per started day a winner funds past the grace period (bps, 0 disables)
+    pub late_funding_penalty_bps_per_day: Option<u16>,
+    /// New cap on the late-funding penalty (bps)
+    pub max_late_funding_penalty_bps: Option<u16>,
+    /// Pause or resume the program
+    pub paused: Option<bool>,
+    /// Replacement set of supported payment mints (each passed in remaining_accounts, in order)
+    pub supported_mints: Option<Vec<Pubkey>>,
+    /// Replacement set of per-category fee overrides
+    pub category_fee_overrides: Option<Vec<CategoryFeeOverride>>,
+    /// Rotated verifying key for bid validity proofs
+    pub bid_verifying_key: Option<Groth16VerifyingKey>,
+    /// Rotated verifying key for delivery proofs
+    pub delivery_verifying_key: Option<Groth16VerifyingKey>,
+    /// Rotated verifying key for reveal proofs
+    pub reveal_verifying_key: Option<Groth16VerifyingKey>,
+    /// Rotated verifying key for bid cap range proofs
+    pub range_verifying_key: Option<Groth16VerifyingKey>,
+}
+ 
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ ConfigError::InvalidAuthority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+ 
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    pub authority: Signer<'info>,
+}
+ 
+// remaining_accounts: one Mint account per supported_mints entry, in order (if replaced)
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UpdateConfig<'info>>,
+    params: UpdateConfigParams,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+ 
+    if let Some(timelock) = params.physical_timelock {
+        ProgramConfig::validate_timelock(timelock)?;
+        config.physical_timelock = timelock;
+    }
+    if let Some(timelock) = params.digital_timelock {
+        ProgramConfig::validate_timelock(timelock)?;
+        config.digital_timelock = timelock;
+    }
+    if let Some(timelock) = params.service_timelock {
+        ProgramConfig::validate_timelock(timelock)?;
+        config.service_timelock = timelock;
+    }
+    if let Some(split_bps) = params.slash_split_bps {
+        require!(split_bps <= 10_000, ConfigError::InvalidParameter);
+        config.slash_split_bps = split_bps;
+    }
+    if let Some(extension) = params.info_request_extension {
+        ProgramConfig::validate_period(extension)?;
+        config.info_request_extension = extension;
+    }
+    if params.evidence_period.is_some() || params.resolution_period.is_some() {
+        let evidence_period = params.evidence_period.unwrap_or(config.evidence_period);
+        let resolution_period = params.resolution_period.unwrap_or(config.resolution_period);
+        ProgramConfig::validate_dispute_periods(evidence_period, resolution_period)?;
+        config.evidence_period = evidence_period;
+        config.resolution_period = resolution_period;
+    }
+    if let Some(claim_window) = params.claim_window {
+        ProgramConfig::validate_period(claim_window)?;
+        config.claim_window = claim_window;
+    }
+    if let Some(grace) = params.late_reveal_grace {
+        ProgramConfig::validate_timelock(grace)?;
+        config.late_reveal_grace = grace;
+    }
+    if let Some(credit) = params.credit_winner_collateral {
+        config.credit_winner_collateral = credit;
+    }
+    if let Some(cooldown) = params.dispute_cooldown {
+        ProgramConfig::validate_timelock(cooldown)?;
+        config.dispute_cooldown = cooldown;
+    }
+    if let Some(min_stake) = params.min_arbitrator_stake {
+        config.min_arbitrator_stake = min_stake;
+    }
+    if let Some(reward) = params.keeper_reward {
+        config.keeper_reward = reward;
+    }
+    if let Some(min_reveals) = params.min_reveals_for_settlement {
+        require!(min_reveals > 0, ConfigError::InvalidParameter);
+        config.min_reveals_for_settlement = min_reveals;
+    }
+    if params.fee_discount_reputation.is_some() || params.max_fee_discount_bps.is_some() {
+        let threshold = params
+            .fee_discount_reputation
+            .unwrap_or(config.fee_discount_reputation);
+        let max_discount_bps = params.max_fee_discount_bps.unwrap_or(config.max_fee_discount_bps);
+        ProgramConfig::validate_fee_discount(threshold, max_discount_bps)?;
+        config.fee_discount_reputation = threshold;
+        config.max_fee_discount_bps = max_discount_bps;
+    }
+    if let Some(tolerance) = params.clock_skew_tolerance {
+        ProgramConfig::validate_clock_skew_tolerance(tolerance)?;
+        config.clock_skew_tolerance = tolerance;
+    }
+    if params.collateral_waiver_reputation.is_some() || params.collateral_waiver_bps.is_some() {
+        let threshold = params
+            .collateral_waiver_reputation
+            .unwrap_or(config.collateral_waiver_reputation);
+        let waiver_bps = params.collateral_waiver_bps.unwrap_or(config.collateral_waiver_bps);
+        ProgramConfig::validate_collateral_waiver(threshold, waiver_bps)?;
+        config.collateral_waiver_reputation = threshold;
+        config.collateral_waiver_bps = waiver_bps;
+    }
+    if params.max_oracle_age.is_some() || params.max_price_deviation_bps.is_some() {
+        let max_age = params.max_oracle_age.unwrap_or(config.max_oracle_age);
+        let max_deviation_bps = params
+            .max_price_deviation_bps
+            .unwrap_or(config.max_price_deviation_bps);
+        ProgramConfig::validate_oracle_bounds(max_age, max_deviation_bps)?;
+        config.max_oracle_age = max_age;
+        config.max_price_deviation_bps = max_deviation_bps;
+    }
+    if let Some(mint_fee_bounds) = params.mint_fee_bounds {
+        ProgramConfig::validate_fee_bounds(&mint_fee_bounds)?;
+        config.mint_fee_bounds = mint_fee_bounds;
+    }
+    if let Some(dispute_bonds) = params.dispute_bonds {
+        ProgramConfig::validate_dispute_bonds(&dispute_bonds)?;
+        config.dispute_bonds = dispute_bonds;
+    }
+    if let Some(cooldown) = params.relist_cooldown {
+        ProgramConfig::validate_timelock(cooldown)?;
+        config.relist_cooldown = cooldown;
+    }
+    if let Some(reimbursement) = params.settlement_reimbursement_usd_cents {
+        config.settlement_reimbursement_usd_cents = reimbursement;
+    }
+    if let Some(max_active) = params.max_active_auctions_per_seller {
+        config.max_active_auctions_per_seller = max_active;
+    }
+    if let Some(max_duration) = params.max_reveal_duration {
+        ProgramConfig::validate_period(max_duration)?;
+        config.max_reveal_duration = max_duration;
+    }
+    if let Some(return_window) = params.return_window {
+        ProgramConfig::validate_period(return_window)?;
+        config.return_window = return_window;
+    }
+    if params.early_reveal_window_bps.is_some() || params.reveal_rebate_bps.is_some() {
+        let window_bps = params
+            .early_reveal_window_bps
+            .unwrap_or(config.early_reveal_window_bps);
+        let rebate_bps = params.reveal_rebate_bps.unwrap_or(config.reveal_rebate_bps);
+        ProgramConfig::validate_reveal_rebate(window_bps, rebate_bps)?;
+        config.early_reveal_window_bps = window_bps;
+        config.reveal_rebate_bps = rebate_bps;
+    }
+    if params.late_funding_penalty_bps_per_day.is_some()
+        || params.max_late_funding_penalty_bps.is_some()
+    {
+        let bps_per_day = params
+            .late_funding_penalty_bps_per_day
+            .unwrap_or(config.late_funding_penalty_bps_per_day);
+        let max_bps = params
+            .max_late_funding_penalty_bps
+            .unwrap_or(config.max_late_funding_penalty_bps);
+        ProgramConfig::validate_late_funding_penalty(bps_per_day, max_bps)?;
+        config.late_funding_penalty_bps_per_day = bps_per_day;
+        config.max_late_funding_penalty_bps = max_bps;
+    }
+    if let Some(paused) = params.paused {
+        config.set_paused(paused, clock.unix_timestamp);
+    }
+    if let Some(mints) = params.supported_mints {
+        validate_supported_mints(&mints, ctx.remaining_accounts)?;
+        config.supported_mints = mints;
+    }
+    if let Some(overrides) = params.category_fee_overrides {
+        require!(
+            overrides.len() <= ProgramConfig::MAX_CATEGORY_FEE_OVERRIDES,
+            ConfigError::InvalidParameter
+        );
+        require!(
+            overrides
+                .iter()
+                .all(|fee_override| fee_override.fee_bps <= ProgramConfig::MAX_CATEGORY_FEE_BPS),
+            ConfigError::InvalidParameter
+        );
+        config.category_fee_overrides = overrides;
+    }
+    if let Some(vk) = params.bid_verifying_key {
+        vk.validate(Groth16VerifyingKey::BID_PROOF_INPUTS)?;
+        config.bid_verifying_key = Some(vk);
+    }
+    if let Some(vk) = params.delivery_verifying_key {
+        vk.validate(Groth16VerifyingKey::DELIVERY_PROOF_INPUTS)?;
+        config.delivery_verifying_key = Some(vk);
+    }
+    if let Some(vk) = params.reveal_verifying_key {
+        vk.validate(Groth16VerifyingKey::REVEAL_PROOF_INPUTS)?;
+        config.reveal_verifying_key = Some(vk);
+    }
+    if let Some(vk) = params.range_verifying_key {
+        vk.validate(Groth16VerifyingKey::RANGE_PROOF_INPUTS)?;
+        config.range_verifying_key = Some(vk);
+    }
+ 
+    emit!(ConfigUpdated {
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
+    });
+ 
+    msg!("Program config updated by {}", ctx.accounts.authority.key());
+ 
+    Ok(())
+}
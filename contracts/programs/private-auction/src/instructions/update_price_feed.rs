@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::PriceFeedUpdated;
+ 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct UpdatePriceFeedParams {
+    /// Price of one whole token in micro-USD (1_000_000 = $1.00)
+    pub price: u64,
+}
+ 
+#[derive(Accounts)]
+#[instruction(params: UpdatePriceFeedParams)]
+pub struct UpdatePriceFeed<'info> {
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = config.authority == authority.key() @ ConfigError::InvalidAuthority,
+        constraint = config.is_mint_supported(&mint.key()) @ ConfigError::UnsupportedMint
+    )]
+    pub config: Account<'info, ProgramConfig>,
+ 
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = PriceFeed::LEN,
+        seeds = [b"price_feed", mint.key().as_ref()],
+        bump
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+ 
+    pub mint: Account<'info, Mint>,
+ 
+    #[account(mut)]
+    pub authority: Signer<'info>,
+ 
+    pub system_program: Program<'info, System>,
+}
+ 
+pub fn handler(ctx: Context<UpdatePriceFeed>, params: UpdatePriceFeedParams) -> Result<()> {
+    let price_feed = &mut ctx.accounts.price_feed;
+    let clock = Clock::get()?;
+ 
+    require!(params.price > 0, ConfigError::InvalidParameter);
+ 
+    price_feed.mint = ctx.accounts.mint.key();
+    price_feed.previous_price = price_feed.price;
+    price_feed.price = params.price;
+    price_feed.decimals = ctx.accounts.mint.decimals;
+    price_feed.updated_at = clock.unix_timestamp;
+    price_feed.bump = ctx.bumps.price_feed;
+ 
+    emit!(PriceFeedUpdated {
+        mint: price_feed.mint,
+        price: params.price,
+        timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
+    });
+ 
+    msg!("Price feed for {} set to {}", price_feed.mint, params.price);
+ 
+    Ok(())
+}
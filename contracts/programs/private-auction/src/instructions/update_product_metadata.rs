@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::ProductMetadataUpdated;
+ 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct UpdateProductMetadataParams {
+    /// New product title
+    pub title: Option<String>,
+    /// New product description
+    pub description: Option<String>,
+    /// Replacement image URLs
+    pub images: Option<Vec<String>>,
+    /// New IPFS hash of product metadata
+    pub ipfs_hash: Option<String>,
+}
+ 
+#[derive(Accounts)]
+pub struct UpdateProductMetadata<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        seeds = [b"auction", auction.seller.as_ref(), &auction.listing_seed.to_le_bytes()],
+        bump = auction.bump,
+        constraint = auction.seller == seller.key() @ AuctionError::OnlySeller,
+        constraint = auction.status == AuctionStatus::Active @ AuctionError::InvalidAuctionState
+    )]
+    pub auction: Account<'info, AuctionState>,
+ 
+    #[account(
+        mut,
+        seeds = [b"product", auction.key().as_ref()],
+        bump = product_metadata.bump
+    )]
+    pub product_metadata: Account<'info, ProductMetadata>,
+ 
+    pub seller: Signer<'info>,
+}
+ 
+pub fn handler(
+    ctx: Context<UpdateProductMetadata>,
+    params: UpdateProductMetadataParams,
+) -> Result<()> {
+    let auction = &ctx.accounts.auction;
+    let product = &mut ctx.accounts.product_metadata;
+    let clock = Clock::get()?;
+ 
+    // Listings are immutable once bidders have committed against them
+    require!(auction.bid_count == 0, AuctionError::CannotEditWithBids);
+ 
+    if let Some(title) = params.title {
+        product.title = title;
+    }
+    if let Some(description) = params.description {
+        product.description = description;
+    }
+    if let Some(images) = params.images {
+        product.images = images;
+    }
+    if let Some(ipfs_hash) = params.ipfs_hash {
+        product.ipfs_hash = ipfs_hash;
+    }
+    product.validate()?;
+    product.updated_at = clock.unix_timestamp;
+ 
+    emit!(ProductMetadataUpdated {
+        auction_id: auction.key(),
+        product_id: product.key(),
+        seller: ctx.accounts.seller.key(),
+        ipfs_hash: product.ipfs_hash.clone(),
+        timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
+    });
+ 
+    msg!("Listing for auction {} updated", auction.key());
+ 
+    Ok(())
+}
\ No newline at end of file
@@ -96,14 +96,18 @@ pub fn handler(ctx: Context<UpdateProfile>, params: UpdateProfileParams) -> Resu
         profile.disputes_against = 0;
         profile.disputes_raised = 0;
         profile.disputes_won = 0;
+        profile.frivolous_disputes = 0;
+        profile.cancellations_with_bids = 0;
         profile.total_volume = 0;
         profile.average_rating = 25; // 2.5 stars default
         profile.rating_count = 0;
         profile.kyc_level = KycLevel::None;
         profile.created_at = clock.unix_timestamp;
         profile.last_active = clock.unix_timestamp;
+        profile.last_dispute_at = 0;
         profile.platform_verified = false;
         profile.staked_amount = 0;
+        profile.linked_wallets = 0;
         profile.bump = ctx.bumps.profile;
  
         // Initialize stake account
@@ -115,13 +119,14 @@ pub fn handler(ctx: Context<UpdateProfile>, params: UpdateProfileParams) -> Resu
         stake_account.locked_for_dispute = false;
         stake_account.bump = ctx.bumps.stake_account;
  
-        stats.user_registered();
+        stats.user_registered(clock.unix_timestamp);
  
         emit!(ProfileCreated {
             user: ctx.accounts.user.key(),
             reputation_score: profile.reputation_score,
             kyc_level: profile.kyc_level as u8,
             timestamp: clock.unix_timestamp,
+            event_seq: stats.next_event_seq(),
         });
  
         msg!("Profile created for {}", ctx.accounts.user.key());
@@ -151,7 +156,10 @@ pub fn handler(ctx: Context<UpdateProfile>, params: UpdateProfileParams) -> Resu
             params.stake_amount,
         )?;
  
-        stake_account.amount += params.stake_amount;
+        stake_account.amount = stake_account
+            .amount
+            .checked_add(params.stake_amount)
+            .ok_or(ConfigError::ArithmeticOverflow)?;
         // Lock for 30 days minimum
         stake_account.lock_until = clock.unix_timestamp + (30 * 24 * 60 * 60);
         profile.staked_amount = stake_account.amount;
@@ -162,6 +170,7 @@ pub fn handler(ctx: Context<UpdateProfile>, params: UpdateProfileParams) -> Resu
             total_stake: stake_account.amount,
             lock_until: stake_account.lock_until,
             timestamp: clock.unix_timestamp,
+            event_seq: stats.next_event_seq(),
         });
  
         msg!(
@@ -208,6 +217,7 @@ pub fn handler(ctx: Context<UpdateProfile>, params: UpdateProfileParams) -> Resu
             amount: withdraw_amount,
             remaining_stake: 0,
             timestamp: clock.unix_timestamp,
+            event_seq: stats.next_event_seq(),
         });
  
         msg!("Stake withdrawn: {}", withdraw_amount);
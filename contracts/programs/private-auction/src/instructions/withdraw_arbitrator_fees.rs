@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, transfer, Transfer};
+ 
+use crate::state::*;
+use crate::errors::*;
+use crate::events::ArbitratorFeesWithdrawn;
+ 
+#[derive(Accounts)]
+pub struct WithdrawArbitratorFees<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_stats"],
+        bump = stats.bump
+    )]
+    pub stats: Account<'info, ProgramStats>,
+ 
+    #[account(
+        mut,
+        seeds = [b"arbitrator", arbitrator.key().as_ref()],
+        bump = arbitrator_record.bump
+    )]
+    pub arbitrator_record: Account<'info, ArbitratorRecord>,
+ 
+    #[account(
+        mut,
+        seeds = [b"arbitrator_fee_pool"],
+        bump = arbitrator_fee_pool.bump
+    )]
+    pub arbitrator_fee_pool: Account<'info, ArbitratorFeePool>,
+ 
+    #[account(
+        mut,
+        constraint = arbitrator_fee_vault.key() == arbitrator_fee_pool.token_account
+    )]
+    pub arbitrator_fee_vault: Account<'info, TokenAccount>,
+ 
+    #[account(
+        mut,
+        constraint = arbitrator_token_account.owner == arbitrator.key(),
+        constraint = arbitrator_token_account.mint == arbitrator_fee_pool.token_mint @ EscrowError::InvalidTokenMint
+    )]
+    pub arbitrator_token_account: Account<'info, TokenAccount>,
+ 
+    pub arbitrator: Signer<'info>,
+ 
+    pub token_program: Program<'info, Token>,
+}
+ 
+pub fn handler(ctx: Context<WithdrawArbitratorFees>) -> Result<()> {
+    let arbitrator_record = &mut ctx.accounts.arbitrator_record;
+    let fee_pool = &mut ctx.accounts.arbitrator_fee_pool;
+    let clock = Clock::get()?;
+ 
+    // Reset before transferring so a repeat call finds nothing to withdraw
+    let amount = fee_pool.withdraw(arbitrator_record)?;
+ 
+    let pool_seeds = &[b"arbitrator_fee_pool".as_ref(), &[fee_pool.bump]];
+ 
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.arbitrator_fee_vault.to_account_info(),
+                to: ctx.accounts.arbitrator_token_account.to_account_info(),
+                authority: fee_pool.to_account_info(),
+            },
+            &[pool_seeds],
+        ),
+        amount,
+    )?;
+ 
+    arbitrator_record.last_activity = clock.unix_timestamp;
+ 
+    emit!(ArbitratorFeesWithdrawn {
+        arbitrator: ctx.accounts.arbitrator.key(),
+        mint: fee_pool.token_mint,
+        amount,
+        total_earned: arbitrator_record.fees_earned,
+        timestamp: clock.unix_timestamp,
+        event_seq: ctx.accounts.stats.next_event_seq(),
+    });
+ 
+    msg!(
+        "Arbitrator {} withdrew {} in fees",
+        ctx.accounts.arbitrator.key(),
+        amount
+    );
+ 
+    Ok(())
+}
\ No newline at end of file
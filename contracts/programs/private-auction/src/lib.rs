@@ -4,6 +4,7 @@ pub mod errors;
 pub mod events;
 pub mod instructions;
 pub mod state;
+pub mod verifier;
  
 use instructions::*;
  
@@ -14,8 +15,8 @@ pub mod private_auction {
     use super::*;
  
     /// Initialize the auction program with global configuration
-    pub fn initialize_program(
-        ctx: Context<InitializeProgram>,
+    pub fn initialize_program<'info>(
+        ctx: Context<'_, '_, 'info, 'info, InitializeProgram<'info>>,
         params: InitializeProgramParams,
     ) -> Result<()> {
         instructions::initialize_program::handler(ctx, params)
@@ -40,8 +41,8 @@ pub mod private_auction {
     }
  
     /// Settle the auction and determine winner
-    pub fn settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
-        instructions::settle_auction::handler(ctx)
+    pub fn settle_auction(ctx: Context<SettleAuction>, params: SettleAuctionParams) -> Result<()> {
+        instructions::settle_auction::handler(ctx, params)
     }
  
     /// Cancel an auction (seller only, before any bids)
@@ -82,4 +83,283 @@ pub mod private_auction {
     pub fn claim_refund(ctx: Context<ClaimRefund>) -> Result<()> {
         instructions::claim_refund::handler(ctx)
     }
-}
\ No newline at end of file
+ 
+    /// Set the USD price for a supported mint (authority only)
+    pub fn update_price_feed(
+        ctx: Context<UpdatePriceFeed>,
+        params: UpdatePriceFeedParams,
+    ) -> Result<()> {
+        instructions::update_price_feed::handler(ctx, params)
+    }
+ 
+    /// Expire an auction that ended without bids (permissionless)
+    pub fn expire_empty_auction(ctx: Context<ExpireEmptyAuction>) -> Result<()> {
+        instructions::expire_empty_auction::handler(ctx)
+    }
+ 
+    /// Update program configuration (authority only)
+    pub fn update_config<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateConfig<'info>>,
+        params: UpdateConfigParams,
+    ) -> Result<()> {
+        instructions::update_config::handler(ctx, params)
+    }
+ 
+    /// Withdraw a dispute before arbitration begins (raiser only)
+    pub fn cancel_dispute(ctx: Context<CancelDispute>) -> Result<()> {
+        instructions::cancel_dispute::handler(ctx)
+    }
+ 
+    /// Hand off an encrypted shipping address to the seller (winner only)
+    pub fn submit_shipping_info(
+        ctx: Context<SubmitShippingInfo>,
+        params: SubmitShippingInfoParams,
+    ) -> Result<()> {
+        instructions::submit_shipping_info::handler(ctx, params)
+    }
+ 
+    /// Record a shipment tracking number (seller only)
+    pub fn record_tracking(
+        ctx: Context<RecordTracking>,
+        params: RecordTrackingParams,
+    ) -> Result<()> {
+        instructions::record_tracking::handler(ctx, params)
+    }
+ 
+    /// Claim the encrypted download link for a digital win (winner only)
+    pub fn claim_digital_delivery(ctx: Context<ClaimDigitalDelivery>) -> Result<Vec<u8>> {
+        instructions::claim_digital_delivery::handler(ctx)
+    }
+ 
+    /// Redeem a won service before its deadline (winner only)
+    pub fn redeem_service(ctx: Context<RedeemService>) -> Result<()> {
+        instructions::redeem_service::handler(ctx)
+    }
+ 
+    /// Reveal the committed reserve price (seller only)
+    pub fn reveal_reserve(ctx: Context<RevealReserve>, params: RevealReserveParams) -> Result<()> {
+        instructions::reveal_reserve::handler(ctx, params)
+    }
+ 
+    /// Close an auction that missed its reserve and return the NFT (permissionless)
+    pub fn close_failed_auction(ctx: Context<CloseFailedAuction>) -> Result<()> {
+        instructions::close_failed_auction::handler(ctx)
+    }
+ 
+    /// Slash the at-fault party's stake after a dispute (arbitrator only)
+    pub fn slash_stake(ctx: Context<SlashStake>, params: SlashStakeParams) -> Result<()> {
+        instructions::slash_stake::handler(ctx, params)
+    }
+ 
+    /// Lock the winner's available funds, opening a top-up window for any shortfall
+    pub fn lock_winner_funds(ctx: Context<LockWinnerFunds>) -> Result<()> {
+        instructions::lock_winner_funds::handler(ctx)
+    }
+ 
+    /// Top up a partially funded escrow before the funding deadline
+    pub fn top_up_escrow(ctx: Context<TopUpEscrow>, params: TopUpEscrowParams) -> Result<()> {
+        instructions::top_up_escrow::handler(ctx, params)
+    }
+ 
+    /// Forfeit a winner who failed to fully fund the escrow in time
+    pub fn forfeit_winner(ctx: Context<ForfeitWinner>) -> Result<()> {
+        instructions::forfeit_winner::handler(ctx)
+    }
+ 
+    /// Recompute a profile's reputation score from its counters
+    pub fn recompute_reputation(ctx: Context<RecomputeReputation>) -> Result<()> {
+        instructions::recompute_reputation::handler(ctx)
+    }
+ 
+    /// Cancel an auction with bids, refunding all bidders and penalizing the seller
+    pub fn cancel_with_refunds(ctx: Context<CancelWithRefunds>) -> Result<()> {
+        instructions::cancel_with_refunds::handler(ctx)
+    }
+ 
+    /// Request more information from the parties, reopening evidence submission
+    pub fn request_more_info(ctx: Context<RequestMoreInfo>) -> Result<()> {
+        instructions::request_more_info::handler(ctx)
+    }
+ 
+    /// Submit evidence to an open dispute
+    pub fn submit_evidence(
+        ctx: Context<SubmitEvidence>,
+        params: SubmitEvidenceParams,
+    ) -> Result<()> {
+        instructions::submit_evidence::handler(ctx, params)
+    }
+ 
+    /// Accept or decline the highest below-reserve bid after the reveal phase
+    pub fn seller_counteroffer(
+        ctx: Context<SellerCounteroffer>,
+        params: SellerCounterofferParams,
+    ) -> Result<()> {
+        instructions::seller_counteroffer::handler(ctx, params)
+    }
+ 
+    /// Process a batch of revealed bids toward a chunked settlement
+    pub fn begin_settlement(ctx: Context<BeginSettlement>) -> Result<()> {
+        instructions::begin_settlement::handler(ctx)
+    }
+ 
+    /// Transfer funds for a chunked settlement once every bid has been processed
+    pub fn finalize_settlement(
+        ctx: Context<SettleAuction>,
+        params: SettleAuctionParams,
+    ) -> Result<()> {
+        instructions::settle_auction::finalize_handler(ctx, params)
+    }
+ 
+    /// Create the collateral pool and vault for a supported payment mint
+    pub fn init_collateral_pool(ctx: Context<InitCollateralPool>) -> Result<()> {
+        instructions::init_collateral_pool::handler(ctx)
+    }
+ 
+    /// Promote the runner-up when the winner fails to fund within the claim window
+    pub fn promote_runner_up(ctx: Context<PromoteRunnerUp>) -> Result<()> {
+        instructions::promote_runner_up::handler(ctx)
+    }
+ 
+    /// Propose a new program authority (current authority only)
+    pub fn propose_authority(
+        ctx: Context<ProposeAuthority>,
+        params: ProposeAuthorityParams,
+    ) -> Result<()> {
+        instructions::propose_authority::handler(ctx, params)
+    }
+ 
+    /// Accept a proposed authority transfer (pending authority only)
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::accept_authority::handler(ctx)
+    }
+ 
+    /// Migrate an auction account created under an older schema (authority only)
+    pub fn migrate_auction(ctx: Context<MigrateAuction>) -> Result<()> {
+        instructions::migrate_auction::handler(ctx)
+    }
+ 
+    /// Migrate any other account created under the original layout (authority only)
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler(ctx)
+    }
+ 
+    /// Create the seller's storefront aggregate
+    pub fn init_storefront(ctx: Context<InitStorefront>) -> Result<()> {
+        instructions::init_storefront::handler(ctx)
+    }
+ 
+    /// Preview a bidder's refund eligibility and amount without claiming
+    pub fn preview_refund(ctx: Context<PreviewRefund>) -> Result<RefundPreview> {
+        instructions::preview_refund::handler(ctx)
+    }
+ 
+    /// Edit a listing's metadata before any bids (seller only)
+    pub fn update_product_metadata(
+        ctx: Context<UpdateProductMetadata>,
+        params: UpdateProductMetadataParams,
+    ) -> Result<()> {
+        instructions::update_product_metadata::handler(ctx, params)
+    }
+ 
+    /// Create the keeper reward pool (authority only)
+    pub fn init_keeper_pool(ctx: Context<InitKeeperPool>) -> Result<()> {
+        instructions::init_keeper_pool::handler(ctx)
+    }
+ 
+    /// Deposit lamports into the keeper reward pool
+    pub fn fund_keeper_pool(
+        ctx: Context<FundKeeperPool>,
+        params: FundKeeperPoolParams,
+    ) -> Result<()> {
+        instructions::fund_keeper_pool::handler(ctx, params)
+    }
+ 
+    /// Create several auctions in one transaction (all-or-nothing)
+    pub fn create_auctions_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateAuctionsBatch<'info>>,
+        params: Vec<CreateAuctionParams>,
+    ) -> Result<()> {
+        instructions::create_auctions_batch::handler(ctx, params)
+    }
+ 
+    /// Process reveals pre-signed by bidders (permissionless)
+    pub fn reveal_on_behalf<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RevealOnBehalf<'info>>,
+        reveals: Vec<DelegatedReveal>,
+    ) -> Result<()> {
+        instructions::reveal_on_behalf::handler(ctx, reveals)
+    }
+ 
+    /// Mark a party's evidence item as reviewed (assigned arbitrator only)
+    pub fn mark_evidence_reviewed(
+        ctx: Context<MarkEvidenceReviewed>,
+        params: MarkEvidenceReviewedParams,
+    ) -> Result<()> {
+        instructions::mark_evidence_reviewed::handler(ctx, params)
+    }
+ 
+    /// Recover untracked tokens from an escrow or collateral vault (authority only)
+    pub fn rescue_tokens(ctx: Context<RescueTokens>, params: RescueTokensParams) -> Result<()> {
+        instructions::rescue_tokens::handler(ctx, params)
+    }
+ 
+    /// Create the pooled arbitrator fee vault (authority only)
+    pub fn init_arbitrator_fee_pool(ctx: Context<InitArbitratorFeePool>) -> Result<()> {
+        instructions::init_arbitrator_fee_pool::handler(ctx)
+    }
+ 
+    /// Withdraw an arbitrator's pooled fees (arbitrator only)
+    pub fn withdraw_arbitrator_fees(ctx: Context<WithdrawArbitratorFees>) -> Result<()> {
+        instructions::withdraw_arbitrator_fees::handler(ctx)
+    }
+ 
+    /// Compare a collateral pool's tracked collateral with its vault balance
+    pub fn pool_health(ctx: Context<PoolHealthCheck>) -> Result<PoolHealth> {
+        instructions::pool_health::handler(ctx)
+    }
+ 
+    /// Cover a collateral pool's vault shortfall or fund its reveal rebates (anyone may fund)
+    pub fn top_up_collateral_pool(
+        ctx: Context<TopUpCollateralPool>,
+        params: TopUpCollateralPoolParams,
+    ) -> Result<()> {
+        instructions::top_up_collateral_pool::handler(ctx, params)
+    }
+ 
+    /// Suggest a default outcome for a dispute from its reason and evidence (advisory)
+    pub fn suggest_dispute_outcome(
+        ctx: Context<SuggestDisputeOutcome>,
+    ) -> Result<OutcomeSuggestion> {
+        instructions::suggest_dispute_outcome::handler(ctx)
+    }
+ 
+    /// Surface the winning bidder's encrypted contact to the seller after settlement
+    pub fn share_winner_contact(ctx: Context<ShareWinnerContact>) -> Result<()> {
+        instructions::share_winner_contact::handler(ctx)
+    }
+ 
+    /// Unwind an auction while the program is paused past the emergency timelock (authority only)
+    pub fn emergency_withdraw(ctx: Context<EmergencyWithdraw>) -> Result<()> {
+        instructions::emergency_withdraw::handler(ctx)
+    }
+ 
+    /// Link a secondary wallet to the caller's profile (both wallets sign)
+    pub fn link_wallet(ctx: Context<LinkWallet>) -> Result<()> {
+        instructions::link_wallet::handler(ctx)
+    }
+ 
+    /// Extend an auction's reveal window before it closes (seller only)
+    pub fn extend_reveal(ctx: Context<ExtendReveal>, params: ExtendRevealParams) -> Result<()> {
+        instructions::extend_reveal::handler(ctx, params)
+    }
+ 
+    /// Refund a return-for-refund once the seller confirms receipt or the return window lapses
+    pub fn confirm_return(ctx: Context<ConfirmReturn>) -> Result<()> {
+        instructions::confirm_return::handler(ctx)
+    }
+ 
+    /// Report the metrics behind a profile's reputation score
+    pub fn get_reputation_report(ctx: Context<GetReputationReport>) -> Result<ReputationReport> {
+        instructions::get_reputation_report::handler(ctx)
+    }
+}
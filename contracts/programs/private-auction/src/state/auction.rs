@@ -1,4 +1,8 @@
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+ 
+use crate::errors::{AuctionError, OracleError};
+use crate::state::{read_field, zeroed, BidCommitment, PriceFeed};
  
 /// Auction status enum
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
@@ -42,6 +46,120 @@ impl Default for ProductType {
     }
 }
  
+/// How bid collateral is sized
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum CollateralMode {
+    /// Every bid deposits the flat bid_collateral
+    #[default]
+    Flat,
+    /// Bids deposit a share of their declared maximum (at least bid_collateral)
+    Percentage { bps: u16 },
+}
+ 
+/// Leader, runner-up and top-bid ladder over a set of revealed bids
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct BidRanking {
+    /// Leading bidder
+    pub winner: Option<Pubkey>,
+ 
+    /// Leading bid in its own mint
+    pub winning_amount: Option<u64>,
+ 
+    /// Mint the leader bid in
+    pub winning_mint: Option<Pubkey>,
+ 
+    /// Leading bid, normalized like AuctionState::winning_value
+    pub winning_value: Option<u64>,
+ 
+    /// Commitment time of the leading bid (first tie-breaker)
+    pub winner_committed_at: Option<i64>,
+ 
+    /// Bidder behind the second-highest bid
+    pub runner_up: Option<Pubkey>,
+ 
+    /// Runner-up's bid amount in their own mint
+    pub runner_up_amount: Option<u64>,
+ 
+    /// Mint the runner-up bid in
+    pub runner_up_mint: Option<Pubkey>,
+ 
+    /// Second-highest bid, normalized
+    pub second_value: Option<u64>,
+ 
+    /// Commitment time of the runner-up bid
+    pub runner_up_committed_at: Option<i64>,
+ 
+    /// Highest bid values, descending (0 = empty)
+    pub top_bid_values: [u64; AuctionState::TOP_BIDS],
+}
+ 
+impl BidRanking {
+    pub const LEN: usize = 33 + // winner
+        9 + // winning_amount
+        33 + // winning_mint
+        9 + // winning_value
+        9 + // winner_committed_at
+        33 + // runner_up
+        9 + // runner_up_amount
+        33 + // runner_up_mint
+        9 + // second_value
+        9 + // runner_up_committed_at
+        (8 * AuctionState::TOP_BIDS); // top_bid_values
+ 
+    /// Record a revealed bid; returns whether it took the lead
+    pub fn record(
+        &mut self,
+        bidder: Pubkey,
+        amount: u64,
+        mint: Pubkey,
+        value: u64,
+        committed_at: i64,
+    ) -> bool {
+        let rank = (value, committed_at, bidder);
+        let leader = match (self.winning_value, self.winner_committed_at, self.winner) {
+            (Some(value), Some(committed_at), Some(bidder)) => Some((value, committed_at, bidder)),
+            _ => None,
+        };
+        let runner_up = match (self.second_value, self.runner_up_committed_at, self.runner_up) {
+            (Some(value), Some(committed_at), Some(bidder)) => Some((value, committed_at, bidder)),
+            _ => None,
+        };
+ 
+        let takes_lead = AuctionState::outranks(rank, leader);
+        if takes_lead {
+            // Previous leader becomes the runner-up
+            self.second_value = self.winning_value;
+            self.runner_up = self.winner;
+            self.runner_up_amount = self.winning_amount;
+            self.runner_up_mint = self.winning_mint;
+            self.runner_up_committed_at = self.winner_committed_at;
+ 
+            self.winning_value = Some(value);
+            self.winning_amount = Some(amount);
+            self.winning_mint = Some(mint);
+            self.winner = Some(bidder);
+            self.winner_committed_at = Some(committed_at);
+        } else if AuctionState::outranks(rank, runner_up) {
+            self.second_value = Some(value);
+            self.runner_up = Some(bidder);
+            self.runner_up_amount = Some(amount);
+            self.runner_up_mint = Some(mint);
+            self.runner_up_committed_at = Some(committed_at);
+        }
+ 
+        self.record_top_bid(value);
+        takes_lead
+    }
+ 
+    /// Insert a revealed value into the top-bid ladder, dropping the lowest once full
+    pub fn record_top_bid(&mut self, value: u64) {
+        if let Some(slot) = self.top_bid_values.iter().position(|&top| value > top) {
+            self.top_bid_values.copy_within(slot..AuctionState::TOP_BIDS - 1, slot + 1);
+            self.top_bid_values[slot] = value;
+        }
+    }
+}
+ 
 /// Main auction state account (COMPRESSED via Light Protocol)
 /// This struct represents the core auction data stored on-chain
 #[account]
@@ -116,10 +234,138 @@ pub struct AuctionState {
  
     /// Reserved space for future upgrades
     pub _reserved: [u8; 64],
+ 
+    // Fields below were added after the original release. New fields are only ever
+    // appended, so accounts created under an older layout can be migrated in place.
+    /// Account layout version (absent from accounts created under the original layout)
+    pub schema_version: u8,
+ 
+    /// Additional mints bidders may pay in (normalized via price feeds)
+    pub accepted_mints: Vec<Pubkey>,
+ 
+    /// Mint the current leader bid in
+    pub winning_mint: Option<Pubkey>,
+ 
+    /// Leading bid normalized to micro-USD (equals the raw amount on single-mint auctions)
+    pub winning_value: Option<u64>,
+ 
+    /// Second-highest bid normalized to micro-USD
+    pub second_value: Option<u64>,
+ 
+    /// Reserve revealed by the seller, normalized like winning_value
+    pub reserve_value: Option<u64>,
+ 
+    /// Seller's estimate of the item's value in USD cents
+    /// (used for tiering when no price feed is available)
+    pub estimated_value_usd_cents: u64,
+ 
+    /// Next bid index to assign (monotonic, never reused)
+    pub next_bid_index: u32,
+ 
+    /// Next bid index to process in a chunked settlement
+    pub settlement_cursor: u32,
+ 
+    /// Bidder behind the second-highest bid (promoted if the winner defaults)
+    pub runner_up: Option<Pubkey>,
+ 
+    /// Runner-up's bid amount in their own mint
+    pub runner_up_amount: Option<u64>,
+ 
+    /// Mint the runner-up bid in
+    pub runner_up_mint: Option<Pubkey>,
+ 
+    /// Deadline for the current winner to fund the escrow
+    pub claim_deadline: i64,
+ 
+    /// How bid collateral is sized
+    pub collateral_mode: CollateralMode,
+ 
+    /// Grace after the reveal deadline during which late reveals are accepted with a penalty
+    pub late_reveal_grace: i64,
+ 
+    /// When the current leader's bid was revealed
+    pub winner_revealed_at: Option<i64>,
+ 
+    /// Number of times a reveal displaced the previous leader
+    pub leader_changes: u8,
+ 
+    /// Minimum acceptable bid in the primary payment mint (in token base units; bids in
+    /// other mints must clear its oracle value)
+    pub min_bid: u64,
+ 
+    /// Quantity of the auctioned token held in escrow (1 for a unique NFT)
+    pub token_amount: u64,
+ 
+    /// Commitment time of the leading bid (first tie-breaker)
+    pub winner_committed_at: Option<i64>,
+ 
+    /// Commitment time of the runner-up bid
+    pub runner_up_committed_at: Option<i64>,
+ 
+    /// Optional cap on any single bid in the primary payment mint (range-proven at submit,
+    /// checked again at reveal against the oracle value for bids in other mints)
+    pub max_bid_cap: Option<u64>,
+ 
+    /// Whether a keeper has already been rewarded for cranking this auction
+    pub keeper_rewarded: bool,
+ 
+    /// Whether the reserve commitment is denominated in USD cents rather than token units
+    pub reserve_in_usd: bool,
+ 
+    /// USD reserve revealed by the seller (in cents), checked against the oracle-converted bid
+    pub reserve_usd_cents: Option<u64>,
+ 
+    /// Mint bid collateral is posted in (None = each bid's payment mint)
+    pub collateral_mint: Option<Pubkey>,
+ 
+    /// Minimum reputation a bidder needs to bid (None = open to all)
+    pub min_bidder_reputation: Option<u16>,
+ 
+    /// Seller reimburses the settlement caller out of the sale proceeds
+    pub seller_pays_settlement: bool,
+ 
+    /// Highest revealed bid values, descending (normalized like winning_value, 0 = empty)
+    pub top_bid_values: [u64; AuctionState::TOP_BIDS],
+ 
+    /// Settlement events carry only a commitment to the price until delivery is confirmed.
+    /// This only redacts events: reveal events and the auction and bid accounts still show
+    /// the amounts to anyone reading chain state.
+    pub private_settlement: bool,
+ 
+    /// Ranking built up by a chunked settlement, copied onto the auction once complete
+    pub provisional_ranking: BidRanking,
+ 
+    /// Winner's blinded commitment to the settled amounts, published by a private settlement
+    pub settlement_commitment: [u8; 32],
+ 
+    /// Seed the auction's address is derived from: its start time, or a batch listing seed
+    pub listing_seed: i64,
 }
  
 impl AuctionState {
-    pub const LEN: usize = 8 + // discriminator
+    pub const MAX_ACCEPTED_MINTS: usize = 4;
+ 
+    /// Window after the reveal phase for the seller to accept a below-reserve bid
+    pub const COUNTEROFFER_WINDOW: i64 = 24 * 60 * 60; // 24 hours
+ 
+    /// Maximum bids processed per begin_settlement call
+    pub const MAX_SETTLEMENT_BATCH: usize = 16;
+ 
+    /// Maximum auctions created per create_auctions_batch call
+    pub const MAX_CREATE_BATCH: usize = 5;
+ 
+    /// Maximum delegated reveals processed per reveal_on_behalf call
+    pub const MAX_REVEAL_BATCH: usize = 8;
+ 
+    /// Revealed bid values kept on the top-bid ladder for analytics
+    pub const TOP_BIDS: usize = 5;
+ 
+    /// Current account layout version. Accounts created under the original layout predate
+    /// the field and decode as version 0; bump it when a released layout grows again.
+    pub const SCHEMA_VERSION: u8 = 1;
+ 
+    /// Size of accounts created under the original layout
+    pub const LEGACY_LEN: usize = 8 + // discriminator
         32 + // auction_id
         32 + // seller
         1 + // product_type
@@ -144,6 +390,47 @@ impl AuctionState {
         1 + // bump
         64; // _reserved
  
+    pub const LEN: usize = Self::LEGACY_LEN +
+        1 + // schema_version
+        (4 + Self::MAX_ACCEPTED_MINTS * 32) + // accepted_mints
+        33 + // winning_mint (Option<Pubkey>)
+        9 + // winning_value (Option<u64>)
+        9 + // second_value (Option<u64>)
+        9 + // reserve_value (Option<u64>)
+        8 + // estimated_value_usd_cents
+        4 + // next_bid_index
+        4 + // settlement_cursor
+        33 + // runner_up (Option<Pubkey>)
+        9 + // runner_up_amount (Option<u64>)
+        33 + // runner_up_mint (Option<Pubkey>)
+        8 + // claim_deadline
+        3 + // collateral_mode
+        8 + // late_reveal_grace
+        9 + // winner_revealed_at (Option<i64>)
+        1 + // leader_changes
+        8 + // min_bid
+        8 + // token_amount
+        9 + // winner_committed_at (Option<i64>)
+        9 + // runner_up_committed_at (Option<i64>)
+        9 + // max_bid_cap (Option<u64>)
+        1 + // keeper_rewarded
+        1 + // reserve_in_usd
+        9 + // reserve_usd_cents (Option<u64>)
+        33 + // collateral_mint (Option<Pubkey>)
+        3 + // min_bidder_reputation (Option<u16>)
+        1 + // seller_pays_settlement
+        (8 * Self::TOP_BIDS) + // top_bid_values
+        1 + // private_settlement
+        BidRanking::LEN + // provisional_ranking
+        32 + // settlement_commitment
+        8; // listing_seed
+ 
+    /// Address seed for the `index`th listing of a batch created at `start_time`. Batch
+    /// seeds are negative, so they never collide with a single listing's start time.
+    pub fn batch_listing_seed(start_time: i64, index: usize) -> i64 {
+        -(start_time.saturating_mul(Self::MAX_CREATE_BATCH as i64) + index as i64 + 1)
+    }
+ 
     /// Check if auction is in bidding phase
     pub fn is_active(&self) -> bool {
         self.status == AuctionStatus::Active
@@ -163,18 +450,424 @@ impl AuctionState {
     pub fn can_reveal_bids(&self, current_time: i64) -> bool {
         self.is_revealing()
             && current_time >= self.end_time
-            && current_time < self.end_time + self.reveal_duration
+            && current_time < self.reveal_close_time()
     }
  
     /// Check if auction can be settled
     pub fn can_settle(&self, current_time: i64) -> bool {
-        self.is_revealing() && current_time >= self.end_time + self.reveal_duration
+        self.is_revealing() && current_time >= self.reveal_close_time()
     }
  
     /// Get reveal deadline
     pub fn reveal_deadline(&self) -> i64 {
         self.end_time + self.reveal_duration
     }
+ 
+    /// Get the time after which no reveal is accepted, late or not
+    pub fn reveal_close_time(&self) -> i64 {
+        self.reveal_deadline() + self.late_reveal_grace
+    }
+ 
+    /// Check if a reveal at this time falls in the leading share of the reveal window
+    pub fn is_early_reveal(&self, current_time: i64, window_bps: u16) -> bool {
+        let window = (self.reveal_duration as i128 * window_bps as i128 / 10_000) as i64;
+        current_time < self.end_time.saturating_add(window)
+    }
+ 
+    /// Check if a reveal at this time is late (past the deadline, within grace)
+    pub fn is_late_reveal(&self, current_time: i64) -> bool {
+        current_time >= self.reveal_deadline()
+    }
+ 
+    /// Whether a bid outranks another: higher value, then earlier commitment, then lower pubkey
+    /// (independent of reveal order, so the outcome can't be gamed by timing reveals)
+    pub fn outranks(
+        (value, committed_at, bidder): (u64, i64, Pubkey),
+        other: Option<(u64, i64, Pubkey)>,
+    ) -> bool {
+        match other {
+            None => true,
+            Some((other_value, other_committed_at, other_bidder)) => value
+                .cmp(&other_value)
+                .then(other_committed_at.cmp(&committed_at))
+                .then(other_bidder.cmp(&bidder))
+                .is_gt(),
+        }
+    }
+ 
+    /// Record a revealed bid, tracking the leader and runner-up;
+    /// returns whether the bid took the lead. On a tie for the top the
+    /// second price equals the tied value, so the winner pays in full.
+    pub fn record_revealed_bid(
+        &mut self,
+        bidder: Pubkey,
+        amount: u64,
+        mint: Pubkey,
+        value: u64,
+        committed_at: i64,
+    ) -> bool {
+        let mut ranking = self.ranking();
+        let takes_lead = ranking.record(bidder, amount, mint, value, committed_at);
+        self.set_ranking(ranking);
+        takes_lead
+    }
+ 
+    /// Leader, runner-up and top-bid ladder as recorded on the auction
+    pub fn ranking(&self) -> BidRanking {
+        BidRanking {
+            winner: self.winner,
+            winning_amount: self.winning_amount,
+            winning_mint: self.winning_mint,
+            winning_value: self.winning_value,
+            winner_committed_at: self.winner_committed_at,
+            runner_up: self.runner_up,
+            runner_up_amount: self.runner_up_amount,
+            runner_up_mint: self.runner_up_mint,
+            second_value: self.second_value,
+            runner_up_committed_at: self.runner_up_committed_at,
+            top_bid_values: self.top_bid_values,
+        }
+    }
+ 
+    /// Replace the auction's leader, runner-up and top-bid ladder
+    pub fn set_ranking(&mut self, ranking: BidRanking) {
+        self.winner = ranking.winner;
+        self.winning_amount = ranking.winning_amount;
+        self.winning_mint = ranking.winning_mint;
+        self.winning_value = ranking.winning_value;
+        self.winner_committed_at = ranking.winner_committed_at;
+        self.runner_up = ranking.runner_up;
+        self.runner_up_amount = ranking.runner_up_amount;
+        self.runner_up_mint = ranking.runner_up_mint;
+        self.second_value = ranking.second_value;
+        self.runner_up_committed_at = ranking.runner_up_committed_at;
+        self.top_bid_values = ranking.top_bid_values;
+        // Second price is paid in the winner's mint
+        self.second_price = self.second_value.map(|value| self.price_in_winning_mint(value));
+    }
+ 
+    /// Fold the next bid of a chunked settlement into the provisional ranking. The auction's
+    /// own leader is replaced only once every bid has been processed, so an unfinished
+    /// settlement never leaves it without one.
+    pub fn process_settlement_bid(
+        &mut self,
+        auction_key: Pubkey,
+        bid: &BidCommitment,
+    ) -> Result<()> {
+        require!(
+            self.settlement_cursor < self.next_bid_index,
+            AuctionError::InvalidSettlementBid
+        );
+        require!(
+            bid.auction_id == auction_key && bid.bid_index == self.settlement_cursor,
+            AuctionError::InvalidSettlementBid
+        );
+ 
+        // First batch ranks from scratch
+        if self.settlement_cursor == 0 {
+            self.provisional_ranking = BidRanking::default();
+        }
+        // Same ranking as reveal_bid, so the result doesn't depend on processing order
+        if let (Some(amount), Some(value)) = (bid.revealed_amount, bid.revealed_value) {
+            self.provisional_ranking
+                .record(bid.bidder, amount, bid.payment_mint, value, bid.timestamp);
+        }
+        self.settlement_cursor += 1;
+ 
+        if self.settlement_complete() {
+            self.set_ranking(self.provisional_ranking.clone());
+        }
+        Ok(())
+    }
+ 
+    /// Top-bid ladder priced in the winner's mint, without bidder identities
+    pub fn top_bid_amounts(&self) -> Vec<u64> {
+        self.top_bid_values
+            .iter()
+            .take_while(|&&value| value > 0)
+            .map(|&value| self.price_in_winning_mint(value))
+            .collect()
+    }
+ 
+    /// Record reveal provenance for a bid that just took the lead
+    pub fn record_new_leader(&mut self, had_leader: bool, revealed_at: i64) {
+        if had_leader {
+            self.leader_changes = self.leader_changes.saturating_add(1);
+        }
+        self.winner_revealed_at = Some(revealed_at);
+    }
+ 
+    /// Promote the runner-up to winner at their own bid price
+    pub fn promote_runner_up(&mut self, claim_deadline: i64) {
+        self.winner = self.runner_up.take();
+        self.winning_amount = self.runner_up_amount.take();
+        self.winning_mint = self.runner_up_mint.take();
+        self.winner_committed_at = self.runner_up_committed_at.take();
+        self.winning_value = self.second_value;
+        self.second_price = self.winning_amount;
+        self.claim_deadline = claim_deadline;
+        // Reveal time of the runner-up bid is not tracked
+        self.winner_revealed_at = None;
+    }
+ 
+    /// Collateral a bid must deposit given its declared maximum
+    pub fn required_collateral(&self, max_bid: u64) -> u64 {
+        match self.collateral_mode {
+            CollateralMode::Flat => self.bid_collateral,
+            CollateralMode::Percentage { bps } => {
+                let share = (max_bid as u128 * bps as u128 / 10_000) as u64;
+                share.max(self.bid_collateral)
+            }
+        }
+    }
+ 
+    /// Mint a bid paying in `bid_mint` must post its collateral in
+    pub fn collateral_mint_for(&self, bid_mint: &Pubkey) -> Pubkey {
+        self.collateral_mint.unwrap_or(*bid_mint)
+    }
+ 
+    /// Check if a revealed amount is covered by the bid's collateral
+    pub fn collateral_covers(&self, amount: u64, max_bid: u64) -> bool {
+        match self.collateral_mode {
+            CollateralMode::Flat => true,
+            CollateralMode::Percentage { .. } => amount <= max_bid,
+        }
+    }
+ 
+    /// Check if a chunked settlement has started but not yet processed every bid
+    pub fn settlement_in_progress(&self) -> bool {
+        self.settlement_cursor > 0 && self.settlement_cursor < self.next_bid_index
+    }
+ 
+    /// Check if a chunked settlement has processed every bid
+    pub fn settlement_complete(&self) -> bool {
+        self.settlement_cursor > 0 && self.settlement_cursor == self.next_bid_index
+    }
+ 
+    /// Get the deadline for the seller's counter-offer on a below-reserve bid
+    pub fn counteroffer_deadline(&self) -> i64 {
+        self.reveal_close_time() + Self::COUNTEROFFER_WINDOW
+    }
+ 
+    /// Express a bound denominated in the primary payment mint (minimum bid, bid cap) in the
+    /// unit a bid in `mint` is measured in: token units for the primary mint, the normalized
+    /// value via the primary mint's price feed for any other accepted mint
+    pub fn bound_for_mint(
+        &self,
+        bound: u64,
+        mint: &Pubkey,
+        primary_price_feed: Option<&PriceFeed>,
+    ) -> Result<u64> {
+        if *mint == self.payment_mint {
+            return Ok(bound);
+        }
+        primary_price_feed
+            .ok_or(OracleError::PriceFeedRequired)?
+            .normalize(bound)
+    }
+ 
+    /// Check if bids may be paid in more than one mint
+    pub fn is_multi_mint(&self) -> bool {
+        !self.accepted_mints.is_empty()
+    }
+ 
+    /// Check if bidders may pay in the given mint
+    pub fn accepts_mint(&self, mint: &Pubkey) -> bool {
+        self.payment_mint == *mint || self.accepted_mints.contains(mint)
+    }
+ 
+    /// Compute the reserve commitment: hash(reserve_price || salt || seller_pubkey)
+    /// (the auction key isn't known when the seller commits, so it isn't bound here)
+    pub fn compute_reserve_hash(reserve_price: u64, salt: &[u8; 32], seller: &Pubkey) -> [u8; 32] {
+        use solana_program::keccak;
+ 
+        let mut data = Vec::new();
+        data.extend_from_slice(&reserve_price.to_le_bytes());
+        data.extend_from_slice(salt);
+        data.extend_from_slice(&seller.to_bytes());
+ 
+        keccak::hash(&data).to_bytes()
+    }
+ 
+    /// Commitment to the settled amounts under the winner's secret blinding factor, published
+    /// in place of them by a private settlement:
+    /// hash(winning_amount || payment_amount || auction || winner || blinding)
+    pub fn commit_settlement(&self, blinding: &[u8; 32]) -> [u8; 32] {
+        use solana_program::keccak;
+ 
+        let mut data = Vec::new();
+        data.extend_from_slice(&self.winning_amount.unwrap_or(0).to_le_bytes());
+        data.extend_from_slice(&self.payment_amount().unwrap_or(0).to_le_bytes());
+        data.extend_from_slice(&self.auction_id.to_bytes());
+        data.extend_from_slice(&self.winner.unwrap_or_default().to_bytes());
+        data.extend_from_slice(blinding);
+ 
+        keccak::hash(&data).to_bytes()
+    }
+ 
+    /// Check a revealed blinding factor against the published settlement commitment
+    pub fn opens_settlement_commitment(&self, blinding: &[u8; 32]) -> bool {
+        self.commit_settlement(blinding) == self.settlement_commitment
+    }
+ 
+    /// Amount the winner pays: the second price, or their own bid if there was none
+    pub fn payment_amount(&self) -> Option<u64> {
+        self.second_price.or(self.winning_amount)
+    }
+ 
+    /// Check if the leading bid meets the revealed reserve
+    /// (an unrevealed reserve is treated as no reserve)
+    pub fn reserve_met(&self) -> bool {
+        self.winning_value.unwrap_or(0) >= self.reserve_value.unwrap_or(0)
+    }
+ 
+    /// Check if the seller has revealed the reserve, in either denomination
+    pub fn reserve_revealed(&self) -> bool {
+        self.reserve_value.is_some() || self.reserve_usd_cents.is_some()
+    }
+ 
+    /// Check if a normalized bid value clears the reserve. A USD reserve converts
+    /// the value through the oracle (multi-mint values are already in micro-USD).
+    pub fn value_meets_reserve(&self, value: u64, price_feed: Option<&PriceFeed>) -> Result<bool> {
+        let Some(reserve_usd_cents) = self.reserve_usd_cents else {
+            return Ok(value >= self.reserve_value.unwrap_or(0));
+        };
+ 
+        let value_usd_cents = if self.is_multi_mint() {
+            value / PriceFeed::MICRO_USD_PER_CENT
+        } else {
+            price_feed
+                .ok_or(OracleError::PriceFeedRequired)?
+                .to_usd_cents(value)?
+        };
+        Ok(value_usd_cents >= reserve_usd_cents)
+    }
+ 
+    /// Check if the leading bid meets the reserve at the oracle's current price
+    pub fn reserve_met_at(&self, price_feed: Option<&PriceFeed>) -> Result<bool> {
+        self.value_meets_reserve(self.winning_value.unwrap_or(0), price_feed)
+    }
+ 
+    /// Convert a normalized value into units of the winner's mint,
+    /// using the rate implied by the winner's own bid
+    pub fn price_in_winning_mint(&self, value: u64) -> u64 {
+        match (self.winning_amount, self.winning_value) {
+            (Some(amount), Some(winning_value)) if winning_value > 0 => {
+                ((value as u128 * amount as u128) / winning_value as u128) as u64
+            }
+            _ => value,
+        }
+    }
+ 
+    /// Decode auction data written under the original layout or the current one, upgrading
+    /// the former. Accounts from the original layout have no schema_version and are recognized
+    /// by their size. Returns the version the data was written under alongside the upgraded state.
+    pub fn decode_versioned(data: &[u8], claim_window: i64) -> Result<(u8, Self)> {
+        require!(
+            data.len() >= 8 && data[..8] == *Self::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        if data.len() > Self::LEGACY_LEN {
+            let auction = Self::try_deserialize(&mut &data[..])?;
+            require!(
+                auction.schema_version == Self::SCHEMA_VERSION,
+                ErrorCode::AccountDidNotDeserialize
+            );
+            return Ok((auction.schema_version, auction));
+        }
+        let data = &mut &data[8..];
+ 
+        let mut auction: Self = zeroed(Self::LEN)?;
+        auction.auction_id = read_field(data)?;
+        auction.seller = read_field(data)?;
+        auction.product_type = read_field(data)?;
+        auction.reserve_price_hash = read_field(data)?;
+        auction.start_time = read_field(data)?;
+        auction.end_time = read_field(data)?;
+        auction.reveal_duration = read_field(data)?;
+        auction.status = read_field(data)?;
+        auction.bid_count = read_field(data)?;
+        auction.revealed_count = read_field(data)?;
+        auction.bid_merkle_root = read_field(data)?;
+        auction.product_metadata = read_field(data)?;
+        auction.escrow_account = read_field(data)?;
+        auction.winner = read_field(data)?;
+        auction.winning_amount = read_field(data)?;
+        auction.second_price = read_field(data)?;
+        auction.nft_mint = read_field(data)?;
+        auction.payment_mint = read_field(data)?;
+        auction.min_bid_increment = read_field(data)?;
+        auction.bid_collateral = read_field(data)?;
+        auction.per_session_id = read_field(data)?;
+        auction.bump = read_field(data)?;
+        auction._reserved = read_field(data)?;
+ 
+        // Fill in what the fields added since imply. Bids from the original layout carry no
+        // index, so next_bid_index starts at zero and counts them as they are migrated.
+        auction.winning_mint = auction.winning_amount.map(|_| auction.payment_mint);
+        auction.winning_value = auction.winning_amount;
+        auction.second_value = auction.second_price;
+        auction.claim_deadline = auction.reveal_deadline() + claim_window;
+        auction.listing_seed = auction.start_time;
+        if auction.product_type == ProductType::Nft {
+            auction.token_amount = 1;
+        }
+        auction.schema_version = Self::SCHEMA_VERSION;
+ 
+        Ok((0, auction))
+    }
+}
+ 
+/// Last cancellation of a seller's NFT listing, used to throttle cancel/relist cycles
+#[account]
+#[derive(Default, Debug)]
+pub struct RelistGuard {
+    /// Seller who cancelled
+    pub seller: Pubkey,
+ 
+    /// NFT mint of the cancelled listing
+    pub nft_mint: Pubkey,
+ 
+    /// When the listing was last cancelled
+    pub last_cancelled_at: i64,
+ 
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+ 
+impl RelistGuard {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // seller
+        32 + // nft_mint
+        8 + // last_cancelled_at
+        1; // bump
+ 
+    /// Start the relist cooldown for a seller's NFT
+    pub fn record_cancellation(&mut self, seller: Pubkey, nft_mint: Pubkey, now: i64, bump: u8) {
+        self.seller = seller;
+        self.nft_mint = nft_mint;
+        self.last_cancelled_at = now;
+        self.bump = bump;
+    }
+ 
+    /// Check if the NFT may be listed again
+    pub fn can_relist(&self, cooldown: i64, current_time: i64) -> bool {
+        current_time >= self.last_cancelled_at.saturating_add(cooldown)
+    }
+ 
+    /// Enforce the relist cooldown against a guard PDA that may not exist yet
+    pub fn check(guard_info: &AccountInfo, cooldown: i64, current_time: i64) -> Result<()> {
+        if guard_info.data_is_empty() {
+            return Ok(());
+        }
+        require_keys_eq!(*guard_info.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+        let guard = RelistGuard::try_deserialize(&mut &guard_info.try_borrow_data()?[..])?;
+        require!(
+            guard.can_relist(cooldown, current_time),
+            crate::errors::AuctionError::RelistCooldownActive
+        );
+        Ok(())
+    }
 }
  
 /// Compressed auction state for Light Protocol
@@ -209,4 +902,460 @@ impl CompressedAuctionState {
         }
     }
 }
- 
\ No newline at end of file
+ 
+ 
+#[cfg(test)]
+mod tests {
+    use super::*;
+ 
+    fn sample_auction() -> AuctionState {
+        let mut auction: AuctionState = zeroed(AuctionState::LEN).unwrap();
+        auction.product_type = ProductType::Nft;
+        auction.end_time = 1_000;
+        auction.reveal_duration = 500;
+        auction.bid_count = 3;
+        auction.winner = Some(Pubkey::new_unique());
+        auction.winning_amount = Some(900);
+        auction.second_price = Some(700);
+        auction.nft_mint = Some(Pubkey::new_unique());
+        auction.payment_mint = Pubkey::new_unique();
+        auction.schema_version = AuctionState::SCHEMA_VERSION;
+        auction.min_bid = 50;
+        auction.token_amount = 7;
+        auction.private_settlement = true;
+        auction
+    }
+ 
+    fn encode(auction: &AuctionState) -> Vec<u8> {
+        let mut data = Vec::new();
+        auction.try_serialize(&mut data).unwrap();
+        data
+    }
+ 
+    #[test]
+    fn decodes_original_layout() {
+        // With every Option set, the original fields fill the original allocation exactly
+        let auction = sample_auction();
+        let data = encode(&auction);
+ 
+        let (version, migrated) =
+            AuctionState::decode_versioned(&data[..AuctionState::LEGACY_LEN], 100).unwrap();
+ 
+        assert_eq!(version, 0);
+        assert_eq!(migrated.schema_version, AuctionState::SCHEMA_VERSION);
+        assert_eq!(migrated.winner, auction.winner);
+        assert_eq!(migrated.winning_mint, Some(auction.payment_mint));
+        assert_eq!(migrated.winning_value, Some(900));
+        assert_eq!(migrated.second_value, Some(700));
+        assert_eq!(migrated.claim_deadline, 1_600);
+        assert_eq!(migrated.token_amount, 1);
+        assert_eq!(migrated.next_bid_index, 0);
+        assert_eq!(migrated.min_bid, 0);
+        assert!(!migrated.private_settlement);
+    }
+ 
+    #[test]
+    fn rejects_an_unknown_schema_version() {
+        let mut auction = sample_auction();
+        auction.schema_version = AuctionState::SCHEMA_VERSION + 1;
+ 
+        assert!(AuctionState::decode_versioned(&encode(&auction), 100).is_err());
+    }
+ 
+    #[test]
+    fn current_layout_round_trips() {
+        let data = encode(&sample_auction());
+ 
+        let (version, migrated) = AuctionState::decode_versioned(&data, 100).unwrap();
+ 
+        assert_eq!(version, AuctionState::SCHEMA_VERSION);
+        assert_eq!(encode(&migrated), data);
+    }
+ 
+    #[test]
+    fn rejects_other_account_types() {
+        let data = vec![0u8; AuctionState::LEN];
+        assert!(AuctionState::decode_versioned(&data, 100).is_err());
+    }
+ 
+    fn price_feed(mint: Pubkey, price: u64, decimals: u8) -> PriceFeed {
+        PriceFeed {
+            mint,
+            price,
+            decimals,
+            updated_at: 0,
+            previous_price: 0,
+            bump: 0,
+        }
+    }
+ 
+    #[test]
+    fn bound_applies_in_token_units_for_the_primary_mint() {
+        let auction = sample_auction();
+        assert_eq!(auction.bound_for_mint(1_000, &auction.payment_mint, None).unwrap(), 1_000);
+    }
+ 
+    #[test]
+    fn bound_is_held_at_oracle_value_for_other_mints() {
+        // A 1,000 USDC cap at $1.00 is worth $1,000
+        let auction = sample_auction();
+        let usdc = price_feed(auction.payment_mint, 1_000_000, 6);
+        let sol = price_feed(Pubkey::new_unique(), 150_000_000, 9);
+        let cap = auction.bound_for_mint(1_000_000_000, &sol.mint, Some(&usdc)).unwrap();
+        assert_eq!(cap, 1_000_000_000);
+ 
+        // 10 SOL at $150 is over the cap, 5 SOL is under it
+        assert!(sol.normalize(10_000_000_000).unwrap() > cap);
+        assert!(sol.normalize(5_000_000_000).unwrap() <= cap);
+    }
+ 
+    #[test]
+    fn min_bid_floor_is_held_at_oracle_value_for_other_mints() {
+        // A 150 USDC floor is exactly 1 SOL at $150
+        let auction = sample_auction();
+        let usdc = price_feed(auction.payment_mint, 1_000_000, 6);
+        let sol = price_feed(Pubkey::new_unique(), 150_000_000, 9);
+        let floor = auction.bound_for_mint(150_000_000, &sol.mint, Some(&usdc)).unwrap();
+ 
+        assert!(sol.normalize(1_000_000_000).unwrap() >= floor);
+        assert!(sol.normalize(999_999_999).unwrap() < floor);
+    }
+ 
+    #[test]
+    fn bound_for_other_mints_needs_the_primary_price_feed() {
+        let auction = sample_auction();
+        assert!(auction.bound_for_mint(1_000, &Pubkey::new_unique(), None).is_err());
+    }
+ 
+    /// Revealed bids on `auction_key` (one unrevealed), indexed in commitment order
+    fn settlement_bids(auction_key: Pubkey, mint: Pubkey) -> Vec<BidCommitment> {
+        [(400, 5), (900, 3), (0, 1), (900, 2), (650, 4), (120, 6), (880, 7)]
+            .iter()
+            .enumerate()
+            .map(|(index, &(value, committed_at))| {
+                let mut bid: BidCommitment = zeroed(BidCommitment::LEN).unwrap();
+                bid.auction_id = auction_key;
+                bid.bidder = Pubkey::new_unique();
+                bid.timestamp = committed_at;
+                bid.payment_mint = mint;
+                bid.bid_index = index as u32;
+                if value > 0 {
+                    bid.revealed_amount = Some(value);
+                    bid.revealed_value = Some(value);
+                }
+                bid
+            })
+            .collect()
+    }
+ 
+    fn settling_auction(bid_count: usize) -> AuctionState {
+        let mut auction = sample_auction();
+        auction.winner = None;
+        auction.winning_amount = None;
+        auction.second_price = None;
+        auction.next_bid_index = bid_count as u32;
+        auction
+    }
+ 
+    #[test]
+    fn chunked_settlement_matches_a_single_pass() {
+        let auction_key = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let bids = settlement_bids(auction_key, mint);
+ 
+        // Reference: every reveal recorded in one pass, in reverse order for good measure
+        let mut reference = settling_auction(bids.len());
+        for bid in bids.iter().rev() {
+            if let (Some(amount), Some(value)) = (bid.revealed_amount, bid.revealed_value) {
+                reference.record_revealed_bid(bid.bidder, amount, mint, value, bid.timestamp);
+            }
+        }
+ 
+        let mut single = settling_auction(bids.len());
+        for bid in &bids {
+            single.process_settlement_bid(auction_key, bid).unwrap();
+        }
+ 
+        let mut chunked = settling_auction(bids.len());
+        for batch in bids.chunks(3) {
+            for bid in batch {
+                chunked.process_settlement_bid(auction_key, bid).unwrap();
+            }
+        }
+ 
+        // The earlier commitment wins the tie at 900 and pays the tied amount in full
+        assert_eq!(single.winner, Some(bids[3].bidder));
+        assert_eq!(single.runner_up, Some(bids[1].bidder));
+        assert_eq!(single.second_price, Some(900));
+        assert_eq!(single.ranking(), reference.ranking());
+        assert_eq!(chunked.ranking(), reference.ranking());
+        assert_eq!(chunked.second_price, reference.second_price);
+    }
+ 
+    #[test]
+    fn unfinished_settlement_keeps_the_recorded_leader() {
+        let auction_key = Pubkey::new_unique();
+        let bids = settlement_bids(auction_key, Pubkey::new_unique());
+        let mut auction = settling_auction(bids.len());
+        auction.record_revealed_bid(bids[1].bidder, 900, bids[1].payment_mint, 900, 3);
+        let recorded = auction.ranking();
+ 
+        for bid in &bids[..3] {
+            auction.process_settlement_bid(auction_key, bid).unwrap();
+        }
+        assert!(auction.settlement_in_progress());
+        assert_eq!(auction.ranking(), recorded);
+        assert_eq!(auction.winning_value, Some(900));
+    }
+ 
+    #[test]
+    fn settlement_bids_must_arrive_in_index_order() {
+        let auction_key = Pubkey::new_unique();
+        let bids = settlement_bids(auction_key, Pubkey::new_unique());
+        let mut auction = settling_auction(bids.len());
+ 
+        assert!(auction.process_settlement_bid(auction_key, &bids[1]).is_err());
+        assert!(auction.process_settlement_bid(Pubkey::new_unique(), &bids[0]).is_err());
+ 
+        for bid in &bids {
+            auction.process_settlement_bid(auction_key, bid).unwrap();
+        }
+        assert!(auction.settlement_complete());
+        assert!(auction.process_settlement_bid(auction_key, &bids[0]).is_err());
+    }
+ 
+    #[test]
+    fn confirmation_reveal_opens_the_settlement_commitment() {
+        let mut auction = sample_auction();
+        auction.auction_id = Pubkey::new_unique();
+        let blinding = [7u8; 32];
+        // A lone bidder pays their own bid
+        auction.second_price = None;
+ 
+        // The winner commits off-chain and the settlement publishes only the commitment
+        auction.settlement_commitment = auction.commit_settlement(&blinding);
+        assert!(auction.opens_settlement_commitment(&blinding));
+        assert_ne!(auction.settlement_commitment, auction.commit_settlement(&[0u8; 32]));
+        assert!(!auction.opens_settlement_commitment(&[8u8; 32]));
+ 
+        // Settlement records the payment as the second price; the commitment still opens
+        auction.second_price = auction.payment_amount();
+        assert!(auction.opens_settlement_commitment(&blinding));
+ 
+        // Revealed amounts that differ from the committed ones don't
+        auction.winning_amount = Some(901);
+        assert!(!auction.opens_settlement_commitment(&blinding));
+    }
+ 
+    #[test]
+    fn cancelled_auction_refunds_every_bidder_in_full() {
+        let mut auction = sample_auction();
+        auction.status = AuctionStatus::Cancelled;
+        let mut bid: BidCommitment = zeroed(BidCommitment::LEN).unwrap();
+        bid.collateral_deposited = 1_000;
+ 
+        // Bids were never revealed, and aren't penalized for it
+        let refund = crate::instructions::compute_refund(&auction, &bid).unwrap();
+        assert!(refund.eligible);
+        assert_eq!(refund.amount, 1_000);
+        assert!(!refund.penalized);
+        assert_eq!(refund.reason, crate::events::refund_reasons::SELLER_ABORTED);
+    }
+ 
+    #[test]
+    fn batch_listings_get_their_own_addresses_and_settle_independently() {
+        let seller = Pubkey::new_unique();
+        let start_time = 1_000;
+        let address = |seed: i64| {
+            Pubkey::find_program_address(
+                &[b"auction", seller.as_ref(), &seed.to_le_bytes()],
+                &crate::ID,
+            )
+            .0
+        };
+ 
+        let mut listings: Vec<AuctionState> = (0..3)
+            .map(|i| {
+                let mut auction = settling_auction(0);
+                auction.seller = seller;
+                auction.start_time = start_time;
+                auction.end_time = start_time + 100;
+                auction.listing_seed = AuctionState::batch_listing_seed(start_time, i);
+                auction.status = AuctionStatus::Revealing;
+                auction
+            })
+            .collect();
+ 
+        // Distinct addresses, none of which a single listing created that second could take
+        let mut addresses: Vec<Pubkey> =
+            listings.iter().map(|auction| address(auction.listing_seed)).collect();
+        addresses.push(address(start_time));
+        addresses.sort();
+        addresses.dedup();
+        assert_eq!(addresses.len(), 4);
+ 
+        // Each listing ranks only its own bids and settles once its own reveal closes
+        let mint = Pubkey::new_unique();
+        for (i, auction) in listings.iter_mut().enumerate() {
+            let amount = 100 * (i as u64 + 1);
+            auction.record_revealed_bid(Pubkey::new_unique(), amount, mint, amount, 1);
+        }
+        for (i, auction) in listings.iter().enumerate() {
+            let close = auction.reveal_close_time();
+            assert_eq!(auction.start_time, start_time);
+            assert_eq!(auction.winning_amount, Some(100 * (i as u64 + 1)));
+            assert!(!auction.can_settle(close - 1));
+            assert!(auction.can_settle(close));
+        }
+    }
+ 
+    #[test]
+    fn clear_leader_cannot_settle_before_the_reveal_window_closes() {
+        let mut auction = settling_auction(1);
+        auction.status = AuctionStatus::Revealing;
+ 
+        // The first reveal lands right as bidding ends and leads unopposed
+        assert!(auction.can_reveal_bids(auction.end_time));
+        auction.record_revealed_bid(Pubkey::new_unique(), 900, auction.payment_mint, 900, 1);
+        assert!(!auction.can_settle(auction.end_time));
+        assert!(!auction.can_settle(auction.reveal_close_time() - 1));
+        assert!(auction.can_settle(auction.reveal_close_time()));
+ 
+        // Status alone never opens settlement early
+        auction.status = AuctionStatus::Active;
+        assert!(!auction.can_settle(auction.reveal_close_time()));
+    }
+ 
+    #[test]
+    fn only_reveals_that_take_the_lead_change_the_leader() {
+        let mut auction = settling_auction(0);
+        let mint = auction.payment_mint;
+        let bidders: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+ 
+        // (amount, committed_at, takes the lead)
+        let reveals = [
+            (500, 1, true),
+            (300, 2, false),
+            (800, 3, true),
+            (800, 4, false),
+            (700, 5, false),
+        ];
+        for (bidder, (amount, committed_at, leads)) in bidders.iter().zip(reveals) {
+            assert_eq!(
+                auction.record_revealed_bid(*bidder, amount, mint, amount, committed_at),
+                leads
+            );
+        }
+ 
+        // The later tie at 800 lost on commitment time but sets the second price
+        assert_eq!(auction.winner, Some(bidders[2]));
+        assert_eq!(auction.second_price, Some(800));
+    }
+ 
+    #[test]
+    fn ascending_reveals_count_every_change_of_leader() {
+        let mut auction = settling_auction(0);
+        let mint = auction.payment_mint;
+ 
+        // Each reveal outbids the last, as reveal_bid records it; the first isn't a change
+        for (i, amount) in [100, 200, 300, 400].into_iter().enumerate() {
+            let previous_leader = auction.winner;
+            let revealed_at = 1_000 + i as i64;
+            if auction.record_revealed_bid(Pubkey::new_unique(), amount, mint, amount, 1) {
+                auction.record_new_leader(previous_leader.is_some(), revealed_at);
+            }
+        }
+        assert_eq!(auction.leader_changes, 3);
+        assert_eq!(auction.winner_revealed_at, Some(1_003));
+ 
+        // A lower reveal afterwards changes neither
+        assert!(!auction.record_revealed_bid(Pubkey::new_unique(), 50, mint, 50, 1));
+        assert_eq!(auction.leader_changes, 3);
+        assert_eq!(auction.winner_revealed_at, Some(1_003));
+    }
+ 
+    #[test]
+    fn refund_preview_matches_the_claimed_refund() {
+        use crate::events::refund_reasons;
+        use crate::instructions::{compute_refund, refund_preview};
+ 
+        let bid_with = |revealed: bool| {
+            let mut bid: BidCommitment = zeroed(BidCommitment::LEN).unwrap();
+            bid.bidder = Pubkey::new_unique();
+            bid.collateral_deposited = 1_000;
+            bid.revealed = revealed;
+            bid
+        };
+        let mut settled = sample_auction();
+        settled.status = AuctionStatus::Settled;
+        let mut cancelled = sample_auction();
+        cancelled.status = AuctionStatus::Cancelled;
+ 
+        // Outbid, cancelled and failed-to-reveal bids
+        let cases = [
+            (&settled, bid_with(true), 1_000, refund_reasons::LOSING_BID, false),
+            (&cancelled, bid_with(false), 1_000, refund_reasons::SELLER_ABORTED, false),
+            (&settled, bid_with(false), 500, refund_reasons::FAILED_TO_REVEAL, true),
+        ];
+        for (auction, bid, amount, reason, penalized) in cases {
+            let preview = refund_preview(auction, &bid);
+            let claimed = compute_refund(auction, &bid).unwrap();
+            assert!(preview.eligible);
+            assert_eq!(preview.amount, claimed.amount);
+            assert_eq!(preview.amount, amount);
+            assert_eq!(preview.reason, reason);
+            assert_eq!(preview.penalized, penalized);
+        }
+ 
+        // The winner and auctions still running have nothing to claim
+        let mut winner = bid_with(true);
+        winner.bidder = settled.winner.unwrap();
+        assert!(!refund_preview(&settled, &winner).eligible);
+        assert!(!refund_preview(&sample_auction(), &bid_with(true)).eligible);
+    }
+ 
+    #[test]
+    fn tie_goes_to_the_earliest_commitment_then_the_lowest_key() {
+        let mint = Pubkey::new_unique();
+        let low = Pubkey::new_from_array([1; 32]);
+        let high = Pubkey::new_from_array([2; 32]);
+ 
+        // (bidder, committed_at) in reveal order -> expected winner
+        let cases = [
+            ([(high, 5), (low, 5)], low),
+            ([(low, 5), (high, 5)], low),
+            ([(low, 6), (high, 5)], high),
+            ([(high, 5), (low, 6)], high),
+        ];
+        for (reveals, winner) in cases {
+            let mut auction = settling_auction(reveals.len());
+            for (bidder, committed_at) in reveals {
+                auction.record_revealed_bid(bidder, 800, mint, 800, committed_at);
+            }
+ 
+            // The tied winner pays the full tied amount
+            assert_eq!(auction.winner, Some(winner));
+            assert_eq!(auction.second_price, Some(800));
+            assert_eq!(auction.payment_amount(), Some(800));
+        }
+    }
+ 
+    #[test]
+    fn top_bid_ladder_keeps_the_five_highest_reveals() {
+        let mut auction = settling_auction(0);
+        let mint = auction.payment_mint;
+ 
+        // Fewer reveals than slots leave the ladder short
+        for (committed_at, value) in [(1, 300), (2, 700)] {
+            auction.record_revealed_bid(Pubkey::new_unique(), value, mint, value, committed_at);
+        }
+        assert_eq!(auction.top_bid_amounts(), [700, 300]);
+ 
+        // Out-of-order reveals, including a tie and bids that fall off the bottom
+        for (committed_at, value) in [(3, 100), (4, 900), (5, 500), (6, 700), (7, 200), (8, 800)] {
+            auction.record_revealed_bid(Pubkey::new_unique(), value, mint, value, committed_at);
+        }
+        assert_eq!(auction.top_bid_values, [900, 800, 700, 700, 500]);
+        assert_eq!(auction.top_bid_amounts(), [900, 800, 700, 700, 500]);
+    }
+}
\ No newline at end of file
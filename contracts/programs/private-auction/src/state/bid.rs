@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
  
+use super::{read_field, zeroed, AuctionState, LegacyLayout};
+use crate::errors::AuctionError;
+ 
 /// Bid commitment state (COMPRESSED via Light Protocol)
 /// Stored in Merkle tree for privacy
 #[account]
@@ -14,7 +17,7 @@ pub struct BidCommitment {
     /// Bidder's wallet (may be PDA for additional privacy)
     pub bidder: Pubkey,
  
-    /// Commitment hash: poseidon(bid_amount || salt || bidder_pubkey)
+    /// Commitment hash: poseidon(bid_amount || salt || bidder_pubkey || auction_id)
     pub commitment_hash: [u8; 32],
  
     /// Timestamp when bid was submitted
@@ -37,10 +40,50 @@ pub struct BidCommitment {
  
     /// Bump seed for PDA
     pub bump: u8,
+ 
+    // Fields below were added after the original release. New fields are only ever
+    // appended, so accounts created under an older layout can be migrated in place.
+    /// Mint the bidder pays in
+    pub payment_mint: Pubkey,
+ 
+    /// Position of this bid in the auction's bid index
+    pub bid_index: u32,
+ 
+    /// Revealed amount normalized like AuctionState::winning_value
+    pub revealed_value: Option<u64>,
+ 
+    /// Declared ceiling on the sealed bid (backs percentage collateral)
+    pub max_bid: u64,
+ 
+    /// Whether the bid was revealed after the deadline, within the grace period
+    pub late_revealed: bool,
+ 
+    /// Mint the bidder posted collateral in
+    pub collateral_mint: Pubkey,
+ 
+    /// Notional collateral waived for a trusted bidder (never deposited or refunded)
+    pub collateral_waived: u64,
+ 
+    /// Contact details encrypted to the seller's key (empty if none; wiped on refund,
+    /// surfaced to the seller only if this bid wins)
+    pub encrypted_contact: Vec<u8>,
+ 
+    /// Whether the bid was revealed early in the reveal window (earns a collateral rebate)
+    pub early_revealed: bool,
 }
  
 impl BidCommitment {
-    pub const LEN: usize = 8 + // discriminator
+    /// Collateral penalty for a late reveal (basis points)
+    pub const LATE_REVEAL_PENALTY_BPS: u64 = 1000; // 10%
+ 
+    /// Reputation lost for failing to reveal a bid whose collateral was waived
+    pub const WAIVED_REVEAL_PENALTY: u16 = 150;
+ 
+    /// Maximum size of the encrypted contact ciphertext
+    pub const MAX_ENCRYPTED_CONTACT_LEN: usize = 128;
+ 
+    /// Size of accounts created under the original layout
+    pub const LEGACY_LEN: usize = 8 + // discriminator
         32 + // bid_id
         32 + // auction_id
         32 + // bidder
@@ -53,18 +96,90 @@ impl BidCommitment {
         1 + // collateral_returned
         1; // bump
  
+    pub const LEN: usize = Self::LEGACY_LEN +
+        32 + // payment_mint
+        4 + // bid_index
+        9 + // revealed_value (Option<u64>)
+        8 + // max_bid
+        1 + // late_revealed
+        32 + // collateral_mint
+        8 + // collateral_waived
+        (4 + Self::MAX_ENCRYPTED_CONTACT_LEN) + // encrypted_contact
+        1; // early_revealed
+ 
     /// Check if bid can be revealed
     pub fn can_reveal(&self) -> bool {
         !self.revealed
     }
  
-    /// Mark bid as revealed with amount
-    pub fn reveal(&mut self, amount: u64) {
+    /// Mark bid as revealed with amount and its normalized value
+    pub fn reveal(&mut self, amount: u64, value: u64) {
         self.revealed = true;
         self.revealed_amount = Some(amount);
+        self.revealed_value = Some(value);
     }
 }
  
+impl LegacyLayout for BidCommitment {
+    const SPACE: usize = Self::LEN;
+    const LEGACY_SPACE: usize = Self::LEGACY_LEN;
+ 
+    fn decode_legacy(data: &mut &[u8], auction: Option<&mut AuctionState>) -> Result<Self> {
+        let mut bid: Self = zeroed(Self::LEN)?;
+        bid.bid_id = read_field(data)?;
+        bid.auction_id = read_field(data)?;
+        bid.bidder = read_field(data)?;
+        bid.commitment_hash = read_field(data)?;
+        bid.timestamp = read_field(data)?;
+        bid.revealed = read_field(data)?;
+        bid.revealed_amount = read_field(data)?;
+        bid.proof_hash = read_field(data)?;
+        bid.collateral_deposited = read_field(data)?;
+        bid.collateral_returned = read_field(data)?;
+        bid.bump = read_field(data)?;
+        // Bids were only ever placed (and collateralized) in the auction's primary mint.
+        // They had no index, so they are numbered in the order they are migrated.
+        let auction = auction.ok_or(error!(ErrorCode::AccountNotEnoughKeys))?;
+        require_keys_eq!(bid.auction_id, auction.auction_id, AuctionError::InvalidAuctionState);
+        bid.payment_mint = auction.payment_mint;
+        bid.collateral_mint = auction.payment_mint;
+        bid.revealed_value = bid.revealed_amount;
+        bid.bid_index = auction.next_bid_index;
+        auction.next_bid_index += 1;
+        Ok(bid)
+    }
+}
+ 
+/// Index entry mapping [auction, index] to a bid, so keepers can
+/// enumerate an auction's bids without scanning
+#[account]
+#[derive(Default, Debug)]
+pub struct BidIndexEntry {
+    /// Associated auction
+    pub auction_id: Pubkey,
+ 
+    /// Position in the auction's bid sequence
+    pub index: u32,
+ 
+    /// Bid commitment account
+    pub bid: Pubkey,
+ 
+    /// Bidder's wallet
+    pub bidder: Pubkey,
+ 
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+ 
+impl BidIndexEntry {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_id
+        4 + // index
+        32 + // bid
+        32 + // bidder
+        1; // bump
+}
+ 
 /// Compressed bid commitment for Light Protocol Merkle tree
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct CompressedBidCommitment {
@@ -117,16 +232,30 @@ pub struct BidReveal {
 }
  
 impl BidReveal {
+    /// Domain separator for delegated reveal authorizations
+    pub const AUTHORIZATION_DOMAIN: &'static [u8] = b"private-auction:reveal";
+ 
     /// Verify the reveal matches the commitment
-    pub fn verify_commitment(&self, commitment_hash: &[u8; 32], bidder: &Pubkey) -> bool {
-        // Compute: poseidon(amount || salt || bidder)
+    pub fn verify_commitment(
+        &self,
+        commitment_hash: &[u8; 32],
+        bidder: &Pubkey,
+        auction: &Pubkey,
+    ) -> bool {
+        // Compute: poseidon(amount || salt || bidder || auction)
         // This would use the actual Poseidon hash implementation
-        let computed_hash = Self::compute_commitment(self.amount, &self.salt, bidder);
+        let computed_hash = Self::compute_commitment(self.amount, &self.salt, bidder, auction);
         computed_hash == *commitment_hash
     }
  
     /// Compute commitment hash from reveal data
-    pub fn compute_commitment(amount: u64, salt: &[u8; 32], bidder: &Pubkey) -> [u8; 32] {
+    /// (bound to the auction so a commitment can't be replayed elsewhere)
+    pub fn compute_commitment(
+        amount: u64,
+        salt: &[u8; 32],
+        bidder: &Pubkey,
+        auction: &Pubkey,
+    ) -> [u8; 32] {
         use light_hasher::Poseidon;
         use solana_program::keccak;
  
@@ -137,6 +266,21 @@ impl BidReveal {
         data.extend_from_slice(&amount.to_le_bytes());
         data.extend_from_slice(salt);
         data.extend_from_slice(&bidder.to_bytes());
+        data.extend_from_slice(&auction.to_bytes());
+ 
+        keccak::hash(&data).to_bytes()
+    }
+ 
+    /// Message a bidder signs to let a keeper reveal this bid on their behalf
+    pub fn authorization_message(&self, bidder: &Pubkey, auction: &Pubkey) -> [u8; 32] {
+        use solana_program::keccak;
+ 
+        let mut data = Vec::new();
+        data.extend_from_slice(Self::AUTHORIZATION_DOMAIN);
+        data.extend_from_slice(&auction.to_bytes());
+        data.extend_from_slice(&bidder.to_bytes());
+        data.extend_from_slice(&self.amount.to_le_bytes());
+        data.extend_from_slice(&self.salt);
  
         keccak::hash(&data).to_bytes()
     }
@@ -163,4 +307,27 @@ impl Default for BidStatus {
     fn default() -> Self {
         Self::Committed
     }
+}
+ 
+#[cfg(test)]
+mod tests {
+    use super::*;
+ 
+    #[test]
+    fn commitment_for_one_auction_fails_against_another() {
+        let bidder = Pubkey::new_unique();
+        let auction = Pubkey::new_unique();
+        let reveal = BidReveal {
+            amount: 1_500,
+            salt: [9u8; 32],
+            proof: vec![],
+        };
+        let commitment =
+            BidReveal::compute_commitment(reveal.amount, &reveal.salt, &bidder, &auction);
+ 
+        assert!(reveal.verify_commitment(&commitment, &bidder, &auction));
+        // Same amount and salt, replayed against another auction or by another bidder
+        assert!(!reveal.verify_commitment(&commitment, &bidder, &Pubkey::new_unique()));
+        assert!(!reveal.verify_commitment(&commitment, &Pubkey::new_unique(), &auction));
+    }
 }
\ No newline at end of file
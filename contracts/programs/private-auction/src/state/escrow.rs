@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
  
+use super::{read_field, zeroed, AuctionState, LegacyLayout};
+ 
 /// Escrow security level based on auction value
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum EscrowSecurityLevel {
@@ -97,10 +99,43 @@ pub struct EscrowAccount {
  
     /// Reserved for future use
     pub _reserved: [u8; 32],
+ 
+    // Fields below were added after the original release. New fields are only ever
+    // appended, so accounts created under an older layout can be migrated in place.
+    /// Estimated USD value of the escrowed payment (in cents)
+    pub estimated_value_usd_cents: u64,
+ 
+    /// Amount the winner must lock before settlement can finalize
+    pub required_amount: u64,
+ 
+    /// Deadline for the winner to fully fund the escrow, after which they forfeit
+    pub funding_deadline: i64,
+ 
+    /// Bump of the escrow vault PDA, stored when the vault is created
+    pub vault_bump: u8,
+ 
+    /// Paid out of the payment to the settlement caller, on the seller's account
+    pub settlement_reimbursement: u64,
+ 
+    /// End of the penalty-free funding grace period
+    pub grace_deadline: i64,
+ 
+    /// Late-funding penalty per started day past the grace period (bps, fixed at lock time)
+    pub late_penalty_bps_per_day: u16,
+ 
+    /// Cap on the late-funding penalty (bps, fixed at lock time)
+    pub max_late_penalty_bps: u16,
+ 
+    /// Late-funding penalties paid into the escrow on top of the payment
+    pub late_penalty: u64,
+ 
+    /// Stake the seller must hold before being paid, for the tier of the oracle-valued sale
+    pub required_seller_stake: u64,
 }
  
 impl EscrowAccount {
-    pub const LEN: usize = 8 + // discriminator
+    /// Size of accounts created under the original layout
+    pub const LEGACY_LEN: usize = 8 + // discriminator
         32 + // escrow_id
         32 + // auction_id
         8 + // amount
@@ -116,10 +151,74 @@ impl EscrowAccount {
         1 + // bump
         32; // _reserved
  
-    /// Determine security level based on amount
-    pub fn determine_security_level(amount_usd: u64) -> EscrowSecurityLevel {
-        // Assuming amount is in USD cents
-        match amount_usd {
+    pub const LEN: usize = Self::LEGACY_LEN +
+        8 + // estimated_value_usd_cents
+        8 + // required_amount
+        8 + // funding_deadline
+        1 + // vault_bump
+        8 + // settlement_reimbursement
+        8 + // grace_deadline
+        2 + // late_penalty_bps_per_day
+        2 + // max_late_penalty_bps
+        8 + // late_penalty
+        8; // required_seller_stake
+ 
+    /// Grace window for the winner to top up a short escrow: 48 hours
+    pub const FUNDING_GRACE_PERIOD: i64 = 48 * 60 * 60;
+ 
+    /// Full sale price, including any settlement reimbursement already paid out
+    pub fn gross_amount(&self) -> u64 {
+        self.amount.saturating_add(self.settlement_reimbursement)
+    }
+ 
+    /// Amount still missing from the escrow, counting penalties charged so far
+    pub fn shortfall(&self) -> u64 {
+        self.required_amount
+            .saturating_add(self.late_penalty)
+            .saturating_sub(self.amount)
+    }
+ 
+    /// Vault balance beyond the tracked escrow amount (payment plus any late penalties)
+    pub fn surplus(&self, vault_balance: u64) -> u64 {
+        vault_balance.saturating_sub(self.amount)
+    }
+ 
+    /// Open the funding window: penalty-free through the grace period, then an escalating
+    /// penalty until `late_window` has passed and the winner can be forfeited
+    pub fn open_funding_window(
+        &mut self,
+        current_time: i64,
+        late_window: i64,
+        penalty_bps_per_day: u16,
+        max_penalty_bps: u16,
+    ) {
+        self.grace_deadline = current_time + Self::FUNDING_GRACE_PERIOD;
+        self.funding_deadline = self.grace_deadline + late_window;
+        self.late_penalty_bps_per_day = penalty_bps_per_day;
+        self.max_late_penalty_bps = max_penalty_bps;
+        self.late_penalty = 0;
+    }
+ 
+    /// Penalty rate for funding at `current_time`: nothing within the grace period, then
+    /// one step per started day late, capped
+    pub fn late_penalty_bps(&self, current_time: i64) -> u16 {
+        if current_time <= self.grace_deadline {
+            return 0;
+        }
+        let days_late = ((current_time - self.grace_deadline) as u64).div_ceil(24 * 60 * 60);
+        days_late
+            .saturating_mul(self.late_penalty_bps_per_day as u64)
+            .min(self.max_late_penalty_bps as u64) as u16
+    }
+ 
+    /// Penalty owed on top of `amount` funded at `current_time`
+    pub fn late_penalty_on(&self, amount: u64, current_time: i64) -> u64 {
+        (amount as u128 * self.late_penalty_bps(current_time) as u128 / 10_000) as u64
+    }
+ 
+    /// Determine security level based on USD value (in cents)
+    pub fn determine_security_level(value_usd_cents: u64) -> EscrowSecurityLevel {
+        match value_usd_cents {
             0..=100_000 => EscrowSecurityLevel::Standard,           // $0 - $1,000
             100_001..=1_000_000 => EscrowSecurityLevel::Enhanced,   // $1,000 - $10,000
             _ => EscrowSecurityLevel::Maximum,                      // $10,000+
@@ -149,6 +248,19 @@ impl EscrowAccount {
         true
     }
  
+    /// Check the escrow can still be disputed, explaining why a released one can't
+    pub fn ensure_disputable(&self) -> Result<()> {
+        require!(
+            self.status != EscrowStatus::Released,
+            crate::errors::DisputeError::DeliveryAlreadyConfirmed
+        );
+        require!(
+            self.status == EscrowStatus::Funded,
+            crate::errors::EscrowError::InvalidEscrowState
+        );
+        Ok(())
+    }
+ 
     /// Add a signature for multi-sig release
     pub fn add_signature(&mut self, signer: &Pubkey) -> Result<()> {
         if !self.release_conditions.signers.contains(signer) {
@@ -159,6 +271,38 @@ impl EscrowAccount {
     }
 }
  
+impl LegacyLayout for EscrowAccount {
+    const SPACE: usize = Self::LEN;
+    const LEGACY_SPACE: usize = Self::LEGACY_LEN;
+ 
+    fn decode_legacy(data: &mut &[u8], _auction: Option<&mut AuctionState>) -> Result<Self> {
+        let mut escrow: Self = zeroed(Self::LEN)?;
+        escrow.escrow_id = read_field(data)?;
+        escrow.auction_id = read_field(data)?;
+        escrow.amount = read_field(data)?;
+        escrow.token_mint = read_field(data)?;
+        escrow.token_account = read_field(data)?;
+        escrow.beneficiary = read_field(data)?;
+        escrow.payer = read_field(data)?;
+        escrow.security_level = read_field(data)?;
+        escrow.release_conditions = read_field(data)?;
+        escrow.status = read_field(data)?;
+        escrow.created_at = read_field(data)?;
+        escrow.released_at = read_field(data)?;
+        escrow.bump = read_field(data)?;
+        escrow._reserved = read_field(data)?;
+        escrow.required_amount = escrow.amount;
+        let (vault, vault_bump) = Pubkey::find_program_address(
+            &[b"escrow_vault", escrow.auction_id.as_ref(), escrow.token_mint.as_ref()],
+            &crate::ID,
+        );
+        if vault == escrow.token_account {
+            escrow.vault_bump = vault_bump;
+        }
+        Ok(escrow)
+    }
+}
+ 
 /// Escrow status
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
 pub enum EscrowStatus {
@@ -175,6 +319,8 @@ pub enum EscrowStatus {
     Disputed,
     /// Escrow cancelled
     Cancelled,
+    /// Winner's funds partially locked, awaiting top-up before settlement
+    Funding,
 }
  
 /// Collateral pool for bid collateral management
@@ -198,15 +344,33 @@ pub struct CollateralPool {
  
     /// Bump seed for PDA
     pub bump: u8,
+ 
+    // Fields below were added after the original release. New fields are only ever
+    // appended, so accounts created under an older layout can be migrated in place.
+    /// Total winner collateral applied toward settlement payments
+    pub total_credited: u64,
+ 
+    /// Penalties withheld from bidders' refunds, plus any deposits made to fund rebates,
+    /// set aside to pay prompt revealers' rebates
+    pub penalty_reserve: u64,
 }
  
 impl CollateralPool {
-    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 4 + 1;
+    /// Size of accounts created under the original layout
+    pub const LEGACY_LEN: usize = 8 + 32 + 32 + 32 + 8 + 4 + 1;
+ 
+    pub const LEN: usize = Self::LEGACY_LEN +
+        8 + // total_credited
+        8; // penalty_reserve
  
     /// Deposit collateral for a bid
-    pub fn deposit(&mut self, amount: u64) {
-        self.total_collateral += amount;
+    pub fn deposit(&mut self, amount: u64) -> Result<()> {
+        self.total_collateral = self
+            .total_collateral
+            .checked_add(amount)
+            .ok_or(crate::errors::ConfigError::ArithmeticOverflow)?;
         self.active_bids += 1;
+        Ok(())
     }
  
     /// Withdraw collateral (refund or forfeiture)
@@ -220,4 +384,208 @@ impl CollateralPool {
         }
         Ok(())
     }
+ 
+    /// Release a winner's collateral, crediting part of it toward their payment
+    pub fn credit_winner(&mut self, collateral: u64, credited: u64) -> Result<()> {
+        self.withdraw(collateral)?;
+        self.total_credited = self.total_credited.saturating_add(credited);
+        Ok(())
+    }
+ 
+    /// Set aside the part of a bid's collateral withheld as a penalty
+    pub fn withhold_penalty(&mut self, amount: u64) {
+        self.penalty_reserve = self.penalty_reserve.saturating_add(amount);
+    }
+ 
+    /// Add a deposit made to fund reveal rebates
+    pub fn fund_rebates(&mut self, amount: u64) -> Result<()> {
+        self.penalty_reserve = self
+            .penalty_reserve
+            .checked_add(amount)
+            .ok_or(crate::errors::ConfigError::ArithmeticOverflow)?;
+        Ok(())
+    }
+ 
+    /// Pay a reveal rebate out of the penalty reserve; returns the part the reserve covers
+    pub fn pay_rebate(&mut self, rebate: u64) -> u64 {
+        let paid = rebate.min(self.penalty_reserve);
+        self.penalty_reserve -= paid;
+        paid
+    }
+ 
+    /// Balance the vault must hold: live collateral plus the penalty reserve
+    pub fn tracked_balance(&self) -> u64 {
+        self.total_collateral.saturating_add(self.penalty_reserve)
+    }
+ 
+    /// Tracked balance the vault can't cover
+    pub fn shortfall(&self, vault_balance: u64) -> u64 {
+        self.tracked_balance().saturating_sub(vault_balance)
+    }
+ 
+    /// Vault balance beyond everything the pool tracks
+    pub fn surplus(&self, vault_balance: u64) -> u64 {
+        vault_balance.saturating_sub(self.tracked_balance())
+    }
+}
+ 
+impl LegacyLayout for CollateralPool {
+    const SPACE: usize = Self::LEN;
+    const LEGACY_SPACE: usize = Self::LEGACY_LEN;
+ 
+    fn decode_legacy(data: &mut &[u8], _auction: Option<&mut AuctionState>) -> Result<Self> {
+        let mut pool: Self = zeroed(Self::LEN)?;
+        pool.authority = read_field(data)?;
+        pool.token_mint = read_field(data)?;
+        pool.token_account = read_field(data)?;
+        pool.total_collateral = read_field(data)?;
+        pool.active_bids = read_field(data)?;
+        pool.bump = read_field(data)?;
+        Ok(pool)
+    }
+}
+ 
+#[cfg(test)]
+mod tests {
+    use super::*;
+ 
+    const DAY: i64 = 24 * 60 * 60;
+ 
+    /// Escrow locked at time 0: two grace days, then five penalty days at 1%/day capped at 4%
+    fn locked_escrow() -> EscrowAccount {
+        let mut escrow: EscrowAccount = zeroed(EscrowAccount::LEN).unwrap();
+        escrow.open_funding_window(0, 5 * DAY, 100, 400);
+        escrow
+    }
+ 
+    #[test]
+    fn late_penalty_is_waived_through_the_grace_period() {
+        let escrow = locked_escrow();
+ 
+        assert_eq!(escrow.late_penalty_bps(DAY), 0);
+        assert_eq!(escrow.late_penalty_bps(escrow.grace_deadline), 0);
+        assert_eq!(escrow.late_penalty_on(1_000_000, DAY), 0);
+    }
+ 
+    #[test]
+    fn late_penalty_escalates_per_started_day() {
+        let escrow = locked_escrow();
+ 
+        // Day 3 is the first day past the grace period
+        assert_eq!(escrow.late_penalty_bps(escrow.grace_deadline + 1), 100);
+        assert_eq!(escrow.late_penalty_bps(3 * DAY), 100);
+        assert_eq!(escrow.late_penalty_bps(3 * DAY + 1), 200);
+        assert_eq!(escrow.late_penalty_on(1_000_000, 3 * DAY + 1), 20_000);
+    }
+ 
+    #[test]
+    fn late_penalty_caps_until_the_winner_can_be_forfeited() {
+        let escrow = locked_escrow();
+ 
+        assert_eq!(escrow.late_penalty_bps(6 * DAY), 400);
+        assert_eq!(escrow.late_penalty_bps(escrow.funding_deadline), 400);
+        assert_eq!(escrow.funding_deadline, 7 * DAY);
+ 
+        // Past the funding deadline the rate stays capped and the winner can be forfeited
+        assert_eq!(escrow.late_penalty_bps(escrow.funding_deadline + DAY), 400);
+    }
+ 
+    #[test]
+    fn shortfall_counts_penalties_and_never_goes_negative() {
+        let mut escrow = locked_escrow();
+        escrow.required_amount = 1_000;
+        escrow.amount = 600;
+        assert_eq!(escrow.shortfall(), 400);
+ 
+        escrow.late_penalty = 40;
+        assert_eq!(escrow.shortfall(), 440);
+ 
+        escrow.amount = 1_100;
+        assert_eq!(escrow.shortfall(), 0);
+    }
+ 
+    fn collateral_pool(total_collateral: u64) -> CollateralPool {
+        let mut pool: CollateralPool = zeroed(CollateralPool::LEN).unwrap();
+        pool.total_collateral = total_collateral;
+        pool
+    }
+ 
+    #[test]
+    fn early_revealer_is_rebated_from_withheld_penalties() {
+        let mut config = crate::state::ProgramConfig::default_production();
+        config.reveal_rebate_bps = 1_000;
+        let mut auction: AuctionState = zeroed(AuctionState::LEN).unwrap();
+        auction.end_time = 1_000;
+        auction.reveal_duration = 400;
+        let mut pool = collateral_pool(3_000);
+ 
+        // A no-show forfeits half of their 1,000 collateral into the reserve
+        pool.withdraw(1_000).unwrap();
+        pool.withhold_penalty(500);
+ 
+        // Revealing in the first quarter of the window earns 10%; later reveals earn nothing
+        assert!(auction.is_early_reveal(1_099, config.early_reveal_window_bps));
+        assert!(!auction.is_early_reveal(1_100, config.early_reveal_window_bps));
+        assert_eq!(pool.pay_rebate(config.reveal_rebate(1_000)), 100);
+        assert_eq!(pool.penalty_reserve, 400);
+ 
+        // Rebates never dip into live collateral
+        assert_eq!(pool.pay_rebate(1_000), 400);
+        assert_eq!(pool.pay_rebate(1_000), 0);
+        assert_eq!(pool.total_collateral, 2_000);
+    }
+ 
+    #[test]
+    fn funded_rebates_are_paid_before_any_penalty_is_withheld() {
+        let mut pool = collateral_pool(1_000);
+        pool.fund_rebates(250).unwrap();
+ 
+        assert_eq!(pool.shortfall(1_000), 250);
+        assert_eq!(pool.pay_rebate(100), 100);
+        assert_eq!(pool.tracked_balance(), 1_150);
+    }
+ 
+    #[test]
+    fn only_the_untracked_escrow_balance_is_rescuable() {
+        let mut escrow = locked_escrow();
+        escrow.amount = 1_000;
+        escrow.late_penalty = 40;
+        escrow.amount += escrow.late_penalty;
+ 
+        assert_eq!(escrow.surplus(1_040), 0);
+        assert_eq!(escrow.surplus(1_540), 500);
+        // A vault that's already short has nothing to spare
+        assert_eq!(escrow.surplus(900), 0);
+    }
+ 
+    #[test]
+    fn pool_collateral_and_penalty_reserve_are_never_rescuable() {
+        let mut pool = collateral_pool(2_000);
+        pool.withhold_penalty(300);
+ 
+        assert_eq!(pool.surplus(2_300), 0);
+        assert_eq!(pool.surplus(2_450), 150);
+        assert_eq!(pool.surplus(2_100), 0);
+        assert_eq!(pool.shortfall(2_100), 200);
+    }
+ 
+    #[test]
+    fn confirmed_delivery_cannot_be_disputed() {
+        let mut escrow: EscrowAccount = zeroed(EscrowAccount::LEN).unwrap();
+        escrow.status = EscrowStatus::Funded;
+        assert!(escrow.ensure_disputable().is_ok());
+ 
+        // confirm_delivery releases the escrow
+        escrow.status = EscrowStatus::Released;
+        assert_eq!(
+            escrow.ensure_disputable().unwrap_err(),
+            crate::errors::DisputeError::DeliveryAlreadyConfirmed.into()
+        );
+ 
+        escrow.status = EscrowStatus::Refunded;
+        assert_eq!(
+            escrow.ensure_disputable().unwrap_err(),
+            crate::errors::EscrowError::InvalidEscrowState.into()
+        );
+    }
 }
\ No newline at end of file
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+ 
+/// Fulfillment record for a settled auction, created at settlement
+/// Tracks off-escrow delivery state between buyer and seller
+#[account]
+#[derive(Default, Debug)]
+pub struct FulfillmentRecord {
+    /// Associated auction
+    pub auction_id: Pubkey,
+ 
+    /// Buyer (auction winner)
+    pub buyer: Pubkey,
+ 
+    /// Seller
+    pub seller: Pubkey,
+ 
+    /// Shipping address encrypted to the seller's key (physical goods)
+    pub encrypted_shipping_address: Vec<u8>,
+ 
+    /// When the shipping address was submitted
+    pub shipping_submitted_at: Option<i64>,
+ 
+    /// Shipping carrier used by the seller
+    pub carrier: String,
+ 
+    /// Carrier tracking number
+    pub tracking_number: String,
+ 
+    /// When the seller dispatched the shipment
+    pub shipped_at: Option<i64>,
+ 
+    /// Remaining downloads of the digital product
+    pub downloads_remaining: u8,
+ 
+    /// When the buyer redeemed the service
+    pub service_redeemed_at: Option<i64>,
+ 
+    /// When delivery was confirmed (set at settlement for NFTs)
+    pub delivery_confirmed_at: Option<i64>,
+ 
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+ 
+impl FulfillmentRecord {
+    /// Maximum size of the encrypted shipping address
+    pub const MAX_ENCRYPTED_ADDRESS_LEN: usize = 512;
+ 
+    /// Maximum length of the carrier name
+    pub const MAX_CARRIER_LEN: usize = 32;
+ 
+    /// Maximum length of a tracking number
+    pub const MAX_TRACKING_NUMBER_LEN: usize = 64;
+ 
+    /// Escrow auto-release window once a shipment is dispatched: 14 days
+    pub const SHIPPED_RELEASE_WINDOW: i64 = 14 * 24 * 60 * 60;
+ 
+    pub const LEN: usize = 8 + // discriminator
+        32 + // auction_id
+        32 + // buyer
+        32 + // seller
+        (4 + Self::MAX_ENCRYPTED_ADDRESS_LEN) + // encrypted_shipping_address
+        9 + // shipping_submitted_at (Option<i64>)
+        (4 + Self::MAX_CARRIER_LEN) + // carrier
+        (4 + Self::MAX_TRACKING_NUMBER_LEN) + // tracking_number
+        9 + // shipped_at (Option<i64>)
+        1 + // downloads_remaining
+        9 + // service_redeemed_at (Option<i64>)
+        9 + // delivery_confirmed_at (Option<i64>)
+        1; // bump
+ 
+    /// Check if the record was just created and still needs its parties set
+    pub fn is_new(&self) -> bool {
+        self.auction_id == Pubkey::default()
+    }
+ 
+    /// Check if the buyer has handed off a shipping address
+    pub fn has_shipping_info(&self) -> bool {
+        self.shipping_submitted_at.is_some()
+    }
+ 
+    /// Check if the seller has dispatched the shipment
+    pub fn is_shipped(&self) -> bool {
+        self.shipped_at.is_some()
+    }
+ 
+    /// Check if the buyer has redeemed the service
+    pub fn is_service_redeemed(&self) -> bool {
+        self.service_redeemed_at.is_some()
+    }
+ 
+    /// Check if delivery has been confirmed
+    pub fn is_delivery_confirmed(&self) -> bool {
+        self.delivery_confirmed_at.is_some()
+    }
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+ 
+use super::{AuctionState, ProgramStats};
+use crate::events::KeeperRewarded;
+ 
+/// Lamport pool funding rewards for permissionless crank callers
+#[account]
+#[derive(Default, Debug)]
+pub struct KeeperPool {
+    /// Total lamports deposited into the pool
+    pub total_funded: u64,
+ 
+    /// Total lamports paid out to keepers
+    pub total_paid: u64,
+ 
+    /// Number of rewards paid
+    pub rewards_paid: u64,
+ 
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+ 
+impl KeeperPool {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // total_funded
+        8 + // total_paid
+        8 + // rewards_paid
+        1; // bump
+ 
+    /// Reward owed for cranking an auction: up to `amount` of the pool's `available`
+    /// lamports, or nothing once the auction has been rewarded
+    pub fn reward_due(auction: &AuctionState, amount: u64, available: u64) -> u64 {
+        if auction.keeper_rewarded {
+            return 0;
+        }
+        amount.min(available)
+    }
+ 
+    /// Record a reward paid for cranking an auction
+    pub fn record_reward(&mut self, auction: &mut AuctionState, reward: u64) {
+        self.total_paid += reward;
+        self.rewards_paid += 1;
+        auction.keeper_rewarded = true;
+    }
+ 
+    /// Reward the keeper that cranked an auction, at most once per auction
+    pub fn reward_keeper<'info>(
+        pool: &mut Account<'info, KeeperPool>,
+        stats: &mut ProgramStats,
+        auction: &mut Account<'info, AuctionState>,
+        keeper: &AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        // The pool stays rent-exempt; a drained pool pays nothing
+        let pool_info = pool.to_account_info();
+        let rent_exempt = Rent::get()?.minimum_balance(Self::LEN);
+        let available = pool_info.lamports().saturating_sub(rent_exempt);
+        let reward = Self::reward_due(auction, amount, available);
+        if reward == 0 {
+            return Ok(());
+        }
+ 
+        **pool_info.try_borrow_mut_lamports()? -= reward;
+        **keeper.try_borrow_mut_lamports()? += reward;
+        pool.record_reward(auction, reward);
+        emit!(KeeperRewarded {
+            auction_id: auction.key(),
+            keeper: keeper.key(),
+            amount: reward,
+            timestamp: Clock::get()?.unix_timestamp,
+            event_seq: stats.next_event_seq(),
+        });
+        Ok(())
+    }
+}
+ 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::zeroed;
+ 
+    #[test]
+    fn keeper_is_rewarded_once_per_auction() {
+        let mut pool: KeeperPool = zeroed(KeeperPool::LEN).unwrap();
+        let mut auction: AuctionState = zeroed(AuctionState::LEN).unwrap();
+ 
+        let reward = KeeperPool::reward_due(&auction, 5_000, 1_000_000);
+        assert_eq!(reward, 5_000);
+        pool.record_reward(&mut auction, reward);
+ 
+        // A second crank of the same auction, e.g. promote_runner_up after forfeit_winner
+        assert_eq!(KeeperPool::reward_due(&auction, 5_000, 1_000_000), 0);
+        assert_eq!((pool.total_paid, pool.rewards_paid), (5_000, 1));
+    }
+ 
+    #[test]
+    fn keeper_reward_is_capped_by_the_pool() {
+        let auction: AuctionState = zeroed(AuctionState::LEN).unwrap();
+ 
+        assert_eq!(KeeperPool::reward_due(&auction, 5_000, 3_000), 3_000);
+        assert_eq!(KeeperPool::reward_due(&auction, 5_000, 0), 0);
+        assert_eq!(KeeperPool::reward_due(&auction, 0, 3_000), 0);
+    }
+}
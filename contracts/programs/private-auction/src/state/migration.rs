@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+ 
+use crate::state::AuctionState;
+ 
+/// An account whose layout has grown since the original release. Fields are only ever
+/// appended, so an account written under the original layout is decoded field by field
+/// and everything added since is filled in from what the original fields imply.
+pub trait LegacyLayout: AccountSerialize + AnchorDeserialize + Discriminator {
+    /// Allocated size under the current layout
+    const SPACE: usize;
+ 
+    /// Allocated size of accounts created under the original layout
+    const LEGACY_SPACE: usize;
+ 
+    /// Decode account data (after the discriminator) written under the original layout.
+    /// Bids need the auction they belong to, already migrated.
+    fn decode_legacy(data: &mut &[u8], auction: Option<&mut AuctionState>) -> Result<Self>;
+}
+ 
+/// Read the next field of serialized account data
+pub fn read_field<T: AnchorDeserialize>(data: &mut &[u8]) -> Result<T> {
+    T::deserialize(data).map_err(|_| error!(ErrorCode::AccountDidNotDeserialize))
+}
+ 
+/// An account value with every field zero-initialized
+pub fn zeroed<T: AnchorDeserialize>(len: usize) -> Result<T> {
+    read_field(&mut &vec![0u8; len][..])
+}
+ 
+/// Resize a program account to `space`, topping up rent from `payer`, and write `value`
+/// over it. The old contents are cleared first so no stale bytes survive past the new data.
+pub fn rewrite_account<'info, T: AccountSerialize>(
+    account: &AccountInfo<'info>,
+    value: &T,
+    space: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let required_lamports = Rent::get()?.minimum_balance(space);
+    let shortfall = required_lamports.saturating_sub(account.lamports());
+    if shortfall > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+ 
+    if account.data_len() != space {
+        account.resize(space)?;
+    }
+    let mut data = account.try_borrow_mut_data()?;
+    data.fill(0);
+    let mut writer: &mut [u8] = &mut data[..];
+    value.try_serialize(&mut writer)
+}
\ No newline at end of file
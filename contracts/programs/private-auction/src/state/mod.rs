@@ -5,6 +5,11 @@ pub mod product;
 pub mod profile;
 pub mod program_config;
 pub mod dispute;
+pub mod oracle;
+pub mod fulfillment;
+pub mod storefront;
+pub mod keeper;
+pub mod migration;
  
 pub use auction::*;
 pub use bid::*;
@@ -12,4 +17,9 @@ pub use escrow::*;
 pub use product::*;
 pub use profile::*;
 pub use program_config::*;
-pub use dispute::*;
\ No newline at end of file
+pub use dispute::*;
+pub use oracle::*;
+pub use fulfillment::*;
+pub use storefront::*;
+pub use keeper::*;
+pub use migration::*;
\ No newline at end of file
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+ 
+use crate::errors::OracleError;
+ 
+/// USD price feed for a payment mint (written by the program authority)
+#[account]
+#[derive(Default, Debug)]
+pub struct PriceFeed {
+    /// Mint this price applies to
+    pub mint: Pubkey,
+ 
+    /// Price of one whole token in micro-USD (1_000_000 = $1.00)
+    pub price: u64,
+ 
+    /// Decimals of the mint (cached from the mint account)
+    pub decimals: u8,
+ 
+    /// Last update timestamp
+    pub updated_at: i64,
+ 
+    /// Price before the last update (0 until the feed has been updated twice)
+    pub previous_price: u64,
+ 
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+ 
+impl PriceFeed {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // mint
+        8 + // price
+        1 + // decimals
+        8 + // updated_at
+        8 + // previous_price
+        1; // bump
+ 
+    /// Decimals of the micro-USD price unit
+    pub const PRICE_DECIMALS: u32 = 6;
+ 
+    /// Micro-USD per US cent
+    pub const MICRO_USD_PER_CENT: u64 = 10_000;
+ 
+    /// Load the feed at its seed-derived address, if one has been created for the mint
+    pub fn load(info: &AccountInfo) -> Result<Option<Self>> {
+        if info.owner != &crate::ID || info.data_is_empty() {
+            return Ok(None);
+        }
+        Self::try_deserialize(&mut &info.try_borrow_data()?[..]).map(Some)
+    }
+ 
+    /// Convert a token amount (base units) into micro-USD
+    pub fn normalize(&self, amount: u64) -> Result<u64> {
+        let value = (amount as u128)
+            .checked_mul(self.price as u128)
+            .ok_or(OracleError::PriceConversionFailed)?
+            / 10u128.pow(self.decimals as u32);
+ 
+        u64::try_from(value).map_err(|_| OracleError::PriceConversionFailed.into())
+    }
+ 
+    /// Convert a token amount (base units) into USD cents
+    pub fn to_usd_cents(&self, amount: u64) -> Result<u64> {
+        Ok(self.normalize(amount)? / Self::MICRO_USD_PER_CENT)
+    }
+ 
+    /// Convert USD cents into a token amount (base units), rounding down
+    pub fn from_usd_cents(&self, cents: u64) -> Result<u64> {
+        require!(self.price > 0, OracleError::PriceConversionFailed);
+        let amount = (cents as u128)
+            .checked_mul(Self::MICRO_USD_PER_CENT as u128)
+            .and_then(|micro_usd| micro_usd.checked_mul(10u128.pow(self.decimals as u32)))
+            .ok_or(OracleError::PriceConversionFailed)?
+            / self.price as u128;
+ 
+        u64::try_from(amount).map_err(|_| OracleError::PriceConversionFailed.into())
+    }
+ 
+    /// Reject a stale price, or one that moved too far from the previous price
+    pub fn check_price(
+        &self,
+        current_time: i64,
+        max_age: i64,
+        max_deviation_bps: u16,
+    ) -> Result<()> {
+        require!(
+            current_time.saturating_sub(self.updated_at) <= max_age,
+            OracleError::StalePrice
+        );
+        if self.previous_price > 0 {
+            let deviation = self.price.abs_diff(self.previous_price) as u128 * 10_000
+                / self.previous_price as u128;
+            require!(
+                deviation <= max_deviation_bps as u128,
+                OracleError::PriceDeviationTooLarge
+            );
+        }
+        Ok(())
+    }
+}
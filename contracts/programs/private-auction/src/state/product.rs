@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
  
-use super::ProductType;
+use super::{read_field, zeroed, AuctionState, LegacyLayout, ProductType};
  
 /// Product category for marketplace organization
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
@@ -45,6 +45,38 @@ impl Default for Category {
     }
 }
  
+impl Category {
+    /// Product type this category belongs to (None for generic categories)
+    pub fn product_type(&self) -> Option<ProductType> {
+        match self {
+            Self::Art
+            | Self::Collectibles
+            | Self::Gaming
+            | Self::Music
+            | Self::Photography
+            | Self::Sports => Some(ProductType::Nft),
+            Self::Electronics
+            | Self::Fashion
+            | Self::HomeGarden
+            | Self::Jewelry
+            | Self::Vehicles
+            | Self::Antiques => Some(ProductType::Physical),
+            Self::Software | Self::Ebooks | Self::Courses | Self::Templates | Self::Domains => {
+                Some(ProductType::Digital)
+            }
+            Self::Consulting | Self::Design | Self::Development | Self::Marketing | Self::Writing => {
+                Some(ProductType::Service)
+            }
+            Self::Other => None,
+        }
+    }
+ 
+    /// Check if a listing of the given product type may use this category
+    pub fn is_valid_for(&self, product_type: ProductType) -> bool {
+        self.product_type().is_none_or(|category_type| category_type == product_type)
+    }
+}
+ 
 /// Product condition (for physical goods)
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Condition {
@@ -101,6 +133,13 @@ impl Default for ShippingOptions {
     }
 }
  
+impl ShippingOptions {
+    /// Check if a carrier may be used (any carrier if none are listed)
+    pub fn supports_carrier(&self, carrier: &str) -> bool {
+        self.carriers.is_empty() || self.carriers.iter().any(|c| c == carrier)
+    }
+}
+ 
 /// Digital product delivery options
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct DigitalDelivery {
@@ -240,6 +279,11 @@ pub struct ProductMetadata {
  
     /// Bump seed for PDA
     pub bump: u8,
+ 
+    // Fields below were added after the original release. New fields are only ever
+    // appended, so accounts created under an older layout can be migrated in place.
+    /// Last edit timestamp (equals created_at until edited)
+    pub updated_at: i64,
 }
  
 impl ProductMetadata {
@@ -247,8 +291,11 @@ impl ProductMetadata {
     pub const MAX_DESCRIPTION_LEN: usize = 500;
     pub const MAX_IMAGES: usize = 10;
     pub const MAX_IPFS_HASH_LEN: usize = 64;
+    /// Per-image budget in LEN is 100 bytes including the 4-byte length prefix
+    pub const MAX_IMAGE_URL_LEN: usize = 96;
  
-    pub const LEN: usize = 8 + // discriminator
+    /// Size of accounts created under the original layout
+    pub const LEGACY_LEN: usize = 8 + // discriminator
         32 + // product_id
         32 + // auction_id
         1 + // product_type
@@ -256,7 +303,7 @@ impl ProductMetadata {
         (4 + Self::MAX_IPFS_HASH_LEN) + // ipfs_hash
         (4 + Self::MAX_TITLE_LEN) + // title
         (4 + Self::MAX_DESCRIPTION_LEN) + // description
-        (4 + Self::MAX_IMAGES * 100) + // images
+        (4 + Self::MAX_IMAGES * (4 + Self::MAX_IMAGE_URL_LEN)) + // images
         32 + // seller
         2 + // condition (Option<Condition>)
         256 + // shipping (Option<ShippingOptions>) approx
@@ -267,24 +314,65 @@ impl ProductMetadata {
         1 + // verified
         1; // bump
  
+    pub const LEN: usize = Self::LEGACY_LEN +
+        8; // updated_at
+ 
     /// Validate product metadata
     pub fn validate(&self) -> Result<()> {
+        use crate::errors::AuctionError;
+ 
+        require!(
+            self.ipfs_hash.len() <= Self::MAX_IPFS_HASH_LEN,
+            AuctionError::IpfsHashTooLong
+        );
         require!(
             self.title.len() <= Self::MAX_TITLE_LEN,
-            anchor_lang::error::ErrorCode::AccountDidNotSerialize
+            AuctionError::TitleTooLong
         );
         require!(
             self.description.len() <= Self::MAX_DESCRIPTION_LEN,
-            anchor_lang::error::ErrorCode::AccountDidNotSerialize
+            AuctionError::DescriptionTooLong
         );
         require!(
             self.images.len() <= Self::MAX_IMAGES,
-            anchor_lang::error::ErrorCode::AccountDidNotSerialize
+            AuctionError::TooManyImages
+        );
+        require!(
+            self.images.iter().all(|url| url.len() <= Self::MAX_IMAGE_URL_LEN),
+            AuctionError::ImageUrlTooLong
         );
         Ok(())
     }
 }
  
+impl LegacyLayout for ProductMetadata {
+    const SPACE: usize = Self::LEN;
+    const LEGACY_SPACE: usize = Self::LEGACY_LEN;
+ 
+    fn decode_legacy(data: &mut &[u8], _auction: Option<&mut AuctionState>) -> Result<Self> {
+        let mut product: Self = zeroed(Self::LEN)?;
+        product.product_id = read_field(data)?;
+        product.auction_id = read_field(data)?;
+        product.product_type = read_field(data)?;
+        product.category = read_field(data)?;
+        product.ipfs_hash = read_field(data)?;
+        product.title = read_field(data)?;
+        product.description = read_field(data)?;
+        product.images = read_field(data)?;
+        product.seller = read_field(data)?;
+        product.condition = read_field(data)?;
+        product.shipping = read_field(data)?;
+        product.digital_delivery = read_field(data)?;
+        product.service_details = read_field(data)?;
+        product.nft_mint = read_field(data)?;
+        product.created_at = read_field(data)?;
+        product.verified = read_field(data)?;
+        product.bump = read_field(data)?;
+        product.updated_at = product.created_at;
+        Ok(product)
+    }
+}
+ 
 /// Compressed product metadata for Light Protocol
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct CompressedProductMetadata {
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
  
+use super::{read_field, zeroed, AuctionState, LegacyLayout};
+ 
 /// User profile account (COMPRESSED via Light Protocol)
 #[account]
 #[derive(Default, Debug)]
@@ -61,10 +63,28 @@ pub struct UserProfile {
  
     /// Reserved for future use
     pub _reserved: [u8; 32],
+ 
+    // Fields below were added after the original release. New fields are only ever
+    // appended, so accounts created under an older layout can be migrated in place.
+    /// Number of non-delivery claims by this user that were resolved for the seller
+    pub frivolous_disputes: u8,
+ 
+    /// Number of auctions this user cancelled as seller after bids were placed
+    pub cancellations_with_bids: u8,
+ 
+    /// When this user last raised a dispute (0 = never)
+    pub last_dispute_at: i64,
+ 
+    /// Bids with waived collateral not yet refunded or settled
+    pub open_waived_bids: u8,
+ 
+    /// Secondary wallets linked to this profile's identity
+    pub linked_wallets: u8,
 }
  
 impl UserProfile {
-    pub const LEN: usize = 8 + // discriminator
+    /// Size of accounts created under the original layout
+    pub const LEGACY_LEN: usize = 8 + // discriminator
         32 + // user_pubkey
         2 + // reputation_score
         4 + // auctions_as_seller
@@ -85,37 +105,94 @@ impl UserProfile {
         1 + // bump
         32; // _reserved
  
+    pub const LEN: usize = Self::LEGACY_LEN +
+        1 + // frivolous_disputes
+        1 + // cancellations_with_bids
+        8 + // last_dispute_at
+        1 + // open_waived_bids
+        1; // linked_wallets
+ 
+    /// Reputation penalty per frivolous dispute
+    pub const FRIVOLOUS_DISPUTE_PENALTY: i32 = 50;
+ 
+    /// Reputation penalty per auction cancelled with bids
+    pub const CANCELLATION_PENALTY: i32 = 50;
+ 
+    /// Waived-collateral bids a user may have open at once; unclaimed ones block new waivers
+    pub const MAX_OPEN_WAIVED_BIDS: u8 = 3;
+ 
     /// Calculate reputation score based on activity
     pub fn calculate_reputation(&self) -> u16 {
-        let total_auctions = self.auctions_as_seller + self.auctions_as_buyer;
-        if total_auctions == 0 {
-            return 500; // Neutral for new users
+        // Frivolous disputes and seller cancellations are penalized per incident,
+        // even before any completed auction
+        if self.total_auctions() == 0 {
+            return (500 - self.incident_penalty()).max(0) as u16; // Neutral for new users
         }
  
-        let mut score: i32 = 500;
+        let score: i32 = 500 - self.incident_penalty()
+            + self.delivery_bonus()
+            - self.dispute_penalty()
+            + self.volume_bonus()
+            + self.rating_bonus();
+ 
+        // Clamp to valid range
+        score.max(0).min(1000) as u16
+    }
+ 
+    /// Auctions completed as seller or buyer
+    pub fn total_auctions(&self) -> u32 {
+        self.auctions_as_seller + self.auctions_as_buyer
+    }
  
-        // Successful deliveries boost
-        let delivery_rate = (self.successful_deliveries as f64) / (self.auctions_as_seller as f64).max(1.0);
-        score += (delivery_rate * 200.0) as i32;
+    /// Score lost to frivolous disputes and cancellations with bids
+    pub fn incident_penalty(&self) -> i32 {
+        self.frivolous_disputes as i32 * Self::FRIVOLOUS_DISPUTE_PENALTY
+            + self.cancellations_with_bids as i32 * Self::CANCELLATION_PENALTY
+    }
  
-        // Dispute penalty
-        let dispute_rate = (self.disputes_against as f64) / (total_auctions as f64).max(1.0);
-        score -= (dispute_rate * 300.0) as i32;
+    /// Score gained from successful deliveries (0 to +200)
+    pub fn delivery_bonus(&self) -> i32 {
+        let delivery_rate =
+            (self.successful_deliveries as f64) / (self.auctions_as_seller as f64).max(1.0);
+        (delivery_rate * 200.0) as i32
+    }
  
-        // Volume bonus (logarithmic)
-        if self.total_volume > 0 {
-            let volume_bonus = ((self.total_volume as f64).log10() * 10.0) as i32;
-            score += volume_bonus.min(100);
+    /// Score lost to disputes raised against the user
+    pub fn dispute_penalty(&self) -> i32 {
+        let dispute_rate =
+            (self.disputes_against as f64) / (self.total_auctions() as f64).max(1.0);
+        (dispute_rate * 300.0) as i32
+    }
+ 
+    /// Score gained from transaction volume (logarithmic, at most +100)
+    pub fn volume_bonus(&self) -> i32 {
+        if self.total_volume == 0 {
+            return 0;
         }
+        (((self.total_volume as f64).log10() * 10.0) as i32).min(100)
+    }
  
-        // Rating bonus
-        if self.rating_count > 0 {
-            let rating_bonus = ((self.average_rating as i32 - 25) * 4); // -100 to +100
-            score += rating_bonus;
+    /// Score gained or lost from ratings received (-100 to +100)
+    pub fn rating_bonus(&self) -> i32 {
+        if self.rating_count == 0 {
+            return 0;
         }
+        (self.average_rating as i32 - 25) * 4
+    }
  
-        // Clamp to valid range
-        score.max(0).min(1000) as u16
+    /// Successful deliveries per auction sold (bps)
+    pub fn delivery_rate_bps(&self) -> u32 {
+        (self.successful_deliveries as u64 * 10_000 / self.auctions_as_seller.max(1) as u64) as u32
+    }
+ 
+    /// Disputes raised against the user per completed auction (bps)
+    pub fn dispute_rate_bps(&self) -> u32 {
+        (self.disputes_against as u64 * 10_000 / self.total_auctions().max(1) as u64) as u32
+    }
+ 
+    /// Order of magnitude of total volume in USD cents (0 below $0.10, 5 from $1,000)
+    pub fn volume_tier(&self) -> u8 {
+        self.total_volume.checked_ilog10().unwrap_or(0) as u8
     }
  
     /// Check if user meets minimum reputation for an action
@@ -132,7 +209,15 @@ impl UserProfile {
     }
  
     /// Update reputation after auction completion
-    pub fn update_after_auction(&mut self, as_seller: bool, successful: bool, rating: Option<u8>) {
+    pub fn update_after_auction(
+        &mut self,
+        as_seller: bool,
+        successful: bool,
+        rating: Option<u8>,
+        volume_usd_cents: u64,
+    ) {
+        self.total_volume = self.total_volume.saturating_add(volume_usd_cents);
+ 
         if as_seller {
             self.auctions_as_seller += 1;
             if successful {
@@ -143,9 +228,9 @@ impl UserProfile {
         }
  
         if let Some(r) = rating {
-            let total_rating = (self.average_rating as u32) * self.rating_count + (r as u32);
-            self.rating_count += 1;
-            self.average_rating = (total_rating / self.rating_count) as u8;
+            let total_rating = self.average_rating as u64 * self.rating_count as u64 + r as u64;
+            self.rating_count = self.rating_count.saturating_add(1);
+            self.average_rating = (total_rating / self.rating_count as u64) as u8;
         }
  
         self.reputation_score = self.calculate_reputation();
@@ -158,6 +243,11 @@ impl UserProfile {
         self.reputation_score = self.calculate_reputation();
     }
  
+    /// Check if the user is still within the cooldown after their last dispute
+    pub fn in_dispute_cooldown(&self, cooldown: i64, current_time: i64) -> bool {
+        self.last_dispute_at > 0 && current_time < self.last_dispute_at + cooldown
+    }
+ 
     /// Record a dispute this user raised
     pub fn record_dispute_raised(&mut self, won: bool) {
         self.disputes_raised += 1;
@@ -165,6 +255,57 @@ impl UserProfile {
             self.disputes_won += 1;
         }
     }
+ 
+    /// Record a non-delivery claim by this user that was resolved for the seller
+    pub fn record_frivolous_dispute(&mut self) {
+        self.frivolous_disputes = self.frivolous_disputes.saturating_add(1);
+        self.reputation_score = self.calculate_reputation();
+    }
+ 
+    /// Record an auction this user cancelled as seller after bids were placed
+    pub fn record_cancellation_with_bids(&mut self) {
+        self.cancellations_with_bids = self.cancellations_with_bids.saturating_add(1);
+        self.reputation_score = self.calculate_reputation();
+    }
+ 
+    /// Check if the user may place another bid with waived collateral
+    pub fn can_open_waived_bid(&self) -> bool {
+        self.open_waived_bids < Self::MAX_OPEN_WAIVED_BIDS
+    }
+ 
+    /// Close out a waived-collateral bid once it is refunded or settled
+    pub fn close_waived_bid(&mut self) {
+        self.open_waived_bids = self.open_waived_bids.saturating_sub(1);
+    }
+}
+ 
+impl LegacyLayout for UserProfile {
+    const SPACE: usize = Self::LEN;
+    const LEGACY_SPACE: usize = Self::LEGACY_LEN;
+ 
+    fn decode_legacy(data: &mut &[u8], _auction: Option<&mut AuctionState>) -> Result<Self> {
+        let mut profile: Self = zeroed(Self::LEN)?;
+        profile.user_pubkey = read_field(data)?;
+        profile.reputation_score = read_field(data)?;
+        profile.auctions_as_seller = read_field(data)?;
+        profile.auctions_as_buyer = read_field(data)?;
+        profile.successful_deliveries = read_field(data)?;
+        profile.disputes_against = read_field(data)?;
+        profile.disputes_raised = read_field(data)?;
+        profile.disputes_won = read_field(data)?;
+        profile.total_volume = read_field(data)?;
+        profile.average_rating = read_field(data)?;
+        profile.rating_count = read_field(data)?;
+        profile.encrypted_preferences = read_field(data)?;
+        profile.kyc_level = read_field(data)?;
+        profile.created_at = read_field(data)?;
+        profile.last_active = read_field(data)?;
+        profile.platform_verified = read_field(data)?;
+        profile.staked_amount = read_field(data)?;
+        profile.bump = read_field(data)?;
+        profile._reserved = read_field(data)?;
+        Ok(profile)
+    }
 }
  
 /// KYC verification level
@@ -266,9 +407,139 @@ impl ReputationStake {
  
     /// Slash a portion of stake
     pub fn slash(&mut self, percentage: u8) -> u64 {
-        let slash_amount = (self.amount * percentage as u64) / 100;
+        let slash_amount = (self.amount as u128 * percentage.min(100) as u128 / 100) as u64;
         self.amount -= slash_amount;
         slash_amount
     }
 }
+ 
+/// Link from a secondary wallet to the primary wallet whose identity it shares
+#[account]
+#[derive(Default, Debug)]
+pub struct WalletLink {
+    /// Profile owner the secondary wallet belongs to
+    pub primary: Pubkey,
+ 
+    /// Linked secondary wallet
+    pub secondary: Pubkey,
+ 
+    /// When the link was created
+    pub linked_at: i64,
+ 
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+ 
+impl WalletLink {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // primary
+        32 + // secondary
+        8 + // linked_at
+        1; // bump
+ 
+    /// Identity a wallet acts under: its primary if a link PDA exists, else itself
+    pub fn identity_of(wallet: Pubkey, link_info: &AccountInfo) -> Result<Pubkey> {
+        if link_info.data_is_empty() {
+            return Ok(wallet);
+        }
+        require_keys_eq!(*link_info.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+        let link = WalletLink::try_deserialize(&mut &link_info.try_borrow_data()?[..])?;
+        require_keys_eq!(link.secondary, wallet, crate::errors::ProfileError::InvalidWalletLink);
+        Ok(link.primary)
+    }
+}
+ 
+#[cfg(test)]
+mod tests {
+    use super::*;
+ 
+    fn link_data(primary: Pubkey, secondary: Pubkey) -> Vec<u8> {
+        let link = WalletLink {
+            primary,
+            secondary,
+            linked_at: 0,
+            bump: 255,
+        };
+        let mut data = Vec::with_capacity(WalletLink::LEN);
+        link.try_serialize(&mut data).unwrap();
+        data
+    }
+ 
+    fn identity_with(wallet: Pubkey, owner: Pubkey, mut data: Vec<u8>) -> Result<Pubkey> {
+        let link_key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let link_info = AccountInfo::new(
+            &link_key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+        WalletLink::identity_of(wallet, &link_info)
+    }
+ 
+    #[test]
+    fn unlinked_wallet_is_its_own_identity() {
+        let wallet = Pubkey::new_unique();
+        assert_eq!(identity_with(wallet, Pubkey::default(), vec![]).unwrap(), wallet);
+    }
+ 
+    #[test]
+    fn linked_wallet_acts_as_its_primary() {
+        let (primary, secondary) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let data = link_data(primary, secondary);
+        assert_eq!(identity_with(secondary, crate::ID, data).unwrap(), primary);
+    }
+ 
+    #[test]
+    fn link_must_be_program_owned_and_match_the_wallet() {
+        let (primary, secondary) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let forged = link_data(primary, secondary);
+        assert!(identity_with(secondary, Pubkey::new_unique(), forged).is_err());
+ 
+        let someone_elses = link_data(primary, secondary);
+        assert!(identity_with(Pubkey::new_unique(), crate::ID, someone_elses).is_err());
+    }
+ 
+    #[test]
+    fn each_side_loses_reputation_when_a_dispute_goes_against_them() {
+        // Buyer whose non-delivery claim was released to the seller
+        let mut buyer: UserProfile = zeroed(UserProfile::LEN).unwrap();
+        buyer.auctions_as_buyer = 1;
+        let before = buyer.calculate_reputation();
+        buyer.record_frivolous_dispute();
+        assert_eq!(buyer.frivolous_disputes, 1);
+        assert_eq!(
+            before - buyer.reputation_score,
+            UserProfile::FRIVOLOUS_DISPUTE_PENALTY as u16
+        );
+ 
+        // Seller who refunded the buyer in full, one dispute in four sales
+        let mut seller: UserProfile = zeroed(UserProfile::LEN).unwrap();
+        seller.auctions_as_seller = 4;
+        seller.successful_deliveries = 4;
+        let before = seller.calculate_reputation();
+        seller.record_dispute_against();
+        assert_eq!(seller.frivolous_disputes, 0);
+        assert_eq!(before - seller.reputation_score, 75);
+    }
+ 
+    #[test]
+    fn second_dispute_waits_out_the_cooldown() {
+        let cooldown = crate::state::ProgramConfig::default_production().dispute_cooldown;
+        let mut profile: UserProfile = zeroed(UserProfile::LEN).unwrap();
+ 
+        // A first-time disputer is never throttled
+        assert!(!profile.in_dispute_cooldown(cooldown, 0));
+ 
+        // raise_dispute records the filing time
+        profile.last_dispute_at = 1_000;
+        assert!(profile.in_dispute_cooldown(cooldown, 1_001));
+        assert!(profile.in_dispute_cooldown(cooldown, 1_000 + cooldown - 1));
+        assert!(!profile.in_dispute_cooldown(cooldown, 1_000 + cooldown));
+    }
+}
  
\ No newline at end of file
@@ -1,5 +1,77 @@
 use anchor_lang::prelude::*;
  
+use super::{
+    read_field, zeroed, AuctionState, Category, LegacyLayout, PriceFeed, ReputationStake,
+    SellerStorefront,
+};
+ 
+/// Platform fee override for a product category
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CategoryFeeOverride {
+    /// Category the override applies to
+    pub category: Category,
+    /// Platform fee in basis points for this category
+    pub fee_bps: u16,
+}
+ 
+/// Platform fee floor and ceiling for a payment mint, in its base units
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MintFeeBounds {
+    /// Mint the bounds apply to
+    pub mint: Pubkey,
+    /// Smallest platform fee charged on a sale
+    pub min_fee: u64,
+    /// Largest platform fee charged on a sale (0 = no ceiling)
+    pub max_fee: u64,
+}
+ 
+/// Bond a party posts to raise a dispute over a sale in a mint, in its base units
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MintDisputeBond {
+    /// Mint the bond is posted in
+    pub mint: Pubkey,
+    /// Bond amount
+    pub amount: u64,
+}
+ 
+/// Groth16 verifying key over BN254, encoded as the alt_bn128 syscalls expect
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct Groth16VerifyingKey {
+    pub alpha_g1: [u8; 64],
+    pub beta_g2: [u8; 128],
+    pub gamma_g2: [u8; 128],
+    pub delta_g2: [u8; 128],
+    /// Input commitments (one more than the number of public inputs)
+    pub ic: Vec<[u8; 64]>,
+}
+ 
+impl Groth16VerifyingKey {
+    pub const MAX_PUBLIC_INPUTS: usize = 3;
+ 
+    /// Public inputs of the bid validity circuit: commitment, reserve hash, declared ceiling
+    pub const BID_PROOF_INPUTS: usize = 3;
+ 
+    /// Public inputs of the reveal circuit: commitment, revealed amount
+    pub const REVEAL_PROOF_INPUTS: usize = 2;
+ 
+    /// Public inputs of the range circuit: commitment, cap
+    pub const RANGE_PROOF_INPUTS: usize = 2;
+ 
+    /// Public inputs of the delivery circuit: proof hash
+    pub const DELIVERY_PROOF_INPUTS: usize = 1;
+ 
+    pub const LEN: usize = 64 + 128 + 128 + 128 + (4 + (Self::MAX_PUBLIC_INPUTS + 1) * 64);
+ 
+    /// Validate that the key is for a circuit with `public_inputs` public inputs
+    pub fn validate(&self, public_inputs: usize) -> Result<()> {
+        require!(
+            self.ic.len() == public_inputs + 1,
+            crate::errors::ConfigError::InvalidParameter
+        );
+        Ok(())
+    }
+}
+ 
 /// Global program configuration
 #[account]
 #[derive(Default, Debug)]
@@ -66,13 +138,153 @@ pub struct ProgramConfig {
  
     /// Reserved for future use
     pub _reserved: [u8; 64],
+ 
+    // Fields below were added after the original release. New fields are only ever
+    // appended, so accounts created under an older layout can be migrated in place.
+    /// Escrow time-lock for physical products (seconds)
+    pub physical_timelock: i64,
+ 
+    /// Escrow time-lock for digital products (seconds)
+    pub digital_timelock: i64,
+ 
+    /// Escrow time-lock for services (seconds)
+    pub service_timelock: i64,
+ 
+    /// Share of slashed stake paid to the wronged party (basis points, rest to platform)
+    pub slash_split_bps: u16,
+ 
+    /// Evidence deadline extension when an arbitrator requests more info (seconds)
+    pub info_request_extension: i64,
+ 
+    /// Evidence submission window for new disputes (seconds)
+    pub evidence_period: i64,
+ 
+    /// Resolution window for new disputes (seconds)
+    pub resolution_period: i64,
+ 
+    /// Window for an auction winner to fund the escrow before the runner-up is promoted (seconds)
+    pub claim_window: i64,
+ 
+    /// Per-category platform fee overrides
+    pub category_fee_overrides: Vec<CategoryFeeOverride>,
+ 
+    /// Grace after the reveal deadline for penalized late reveals (seconds)
+    pub late_reveal_grace: i64,
+ 
+    /// Proposed new authority awaiting acceptance
+    pub pending_authority: Option<Pubkey>,
+ 
+    /// Whether the winner's bid collateral is credited toward their settlement payment
+    pub credit_winner_collateral: bool,
+ 
+    /// Verifying key for bid validity proofs (used with the `zk` feature)
+    pub bid_verifying_key: Option<Groth16VerifyingKey>,
+ 
+    /// Verifying key for delivery proofs (used with the `zk` feature)
+    pub delivery_verifying_key: Option<Groth16VerifyingKey>,
+ 
+    /// Minimum time between a user's consecutive dispute filings (seconds, 0 disables)
+    pub dispute_cooldown: i64,
+ 
+    /// Minimum reputation stake an arbitrator must hold to vote
+    pub min_arbitrator_stake: u64,
+ 
+    /// Lamports paid from the keeper pool to callers of permissionless cranks
+    pub keeper_reward: u64,
+ 
+    /// Seller reputation above which the platform fee is discounted
+    pub fee_discount_reputation: u16,
+ 
+    /// Fee discount at a perfect reputation score (bps, scaled linearly from the threshold)
+    pub max_fee_discount_bps: u16,
+ 
+    /// Distinct revealed bids required before an auction can settle
+    pub min_reveals_for_settlement: u32,
+ 
+    /// Seconds every phase boundary is deferred by, absorbing validator clock skew
+    pub clock_skew_tolerance: i64,
+ 
+    /// Bidder reputation at or above which bid collateral is partly waived (0 = no waiver)
+    pub collateral_waiver_reputation: u16,
+ 
+    /// Share of bid collateral waived for trusted bidders (bps; 10_000 = none posted)
+    pub collateral_waiver_bps: u16,
+ 
+    /// Oldest price feed update settlement will accept (seconds)
+    pub max_oracle_age: i64,
+ 
+    /// Largest price move since the previous update settlement will accept (bps)
+    pub max_price_deviation_bps: u16,
+ 
+    /// Per-mint platform fee floors and ceilings (mints without an entry are unbounded)
+    pub mint_fee_bounds: Vec<MintFeeBounds>,
+ 
+    /// When the program was last paused (0 while running)
+    pub paused_at: i64,
+ 
+    /// Per-mint bonds a party posts to raise a dispute (mints without an entry need no bond)
+    pub dispute_bonds: Vec<MintDisputeBond>,
+ 
+    /// Wait before a seller can relist an NFT after cancelling it (seconds, 0 disables)
+    pub relist_cooldown: i64,
+ 
+    /// Paid to whoever settles an auction whose seller covers settlement costs, converted
+    /// into the payment mint through its price feed (USD cents, 0 = no reimbursement)
+    pub settlement_reimbursement_usd_cents: u64,
+ 
+    /// Leading share of the reveal window that counts as a prompt reveal (bps)
+    pub early_reveal_window_bps: u16,
+ 
+    /// Rebate on collateral paid to prompt revealers from withheld penalties (bps, 0 disables)
+    pub reveal_rebate_bps: u16,
+ 
+    /// Auctions a seller may have open at once (0 = unlimited)
+    pub max_active_auctions_per_seller: u32,
+ 
+    /// Longest total reveal window a seller can extend an auction to (seconds)
+    pub max_reveal_duration: i64,
+ 
+    /// Window for a buyer to return an item before a return-for-refund pays out anyway (seconds)
+    pub return_window: i64,
+ 
+    /// Penalty added for each started day a winner funds the escrow past the grace period
+    /// (bps of the late amount, 0 = forfeit as soon as the grace period ends)
+    pub late_funding_penalty_bps_per_day: u16,
+ 
+    /// Highest late-funding penalty; a winner still short once it is reached forfeits (bps)
+    pub max_late_funding_penalty_bps: u16,
+ 
+    /// Verifying key for reveal proofs opening a commitment to its amount
+    /// (used with the `zk` feature)
+    pub reveal_verifying_key: Option<Groth16VerifyingKey>,
+ 
+    /// Verifying key for range proofs bounding a committed bid by the auction cap
+    /// (used with the `zk` feature)
+    pub range_verifying_key: Option<Groth16VerifyingKey>,
 }
  
 impl ProgramConfig {
     pub const MAX_SUPPORTED_MINTS: usize = 10;
     pub const MAX_ARBITRATORS: usize = 10;
+    pub const MAX_CATEGORY_FEE_OVERRIDES: usize = 8;
+ 
+    /// Upper bound for a category fee override
+    pub const MAX_CATEGORY_FEE_BPS: u16 = 1000; // 10%
+ 
+    /// Highest possible reputation score
+    pub const MAX_REPUTATION: u16 = 1000;
+ 
+    /// Upper bound for any escrow time-lock
+    pub const MAX_TIMELOCK: i64 = 90 * 24 * 60 * 60; // 90 days
  
-    pub const LEN: usize = 8 + // discriminator
+    /// Upper bound for the phase-boundary clock skew tolerance
+    pub const MAX_CLOCK_SKEW_TOLERANCE: i64 = 60;
+ 
+    /// How long the program must stay paused before emergency withdrawals open
+    pub const EMERGENCY_WITHDRAW_DELAY: i64 = 7 * 24 * 60 * 60; // 7 days
+ 
+    /// Size of accounts created under the original layout
+    pub const LEGACY_LEN: usize = 8 + // discriminator
         32 + // authority
         32 + // upgrade_authority
         32 + // fee_collector
@@ -95,11 +307,53 @@ impl ProgramConfig {
         1 + // bump
         64; // _reserved
  
+    pub const LEN: usize = Self::LEGACY_LEN +
+        8 + // physical_timelock
+        8 + // digital_timelock
+        8 + // service_timelock
+        2 + // slash_split_bps
+        8 + // info_request_extension
+        8 + // evidence_period
+        8 + // resolution_period
+        8 + // claim_window
+        (4 + Self::MAX_CATEGORY_FEE_OVERRIDES * 3) + // category_fee_overrides
+        8 + // late_reveal_grace
+        (1 + 32) + // pending_authority
+        1 + // credit_winner_collateral
+        (1 + Groth16VerifyingKey::LEN) + // bid_verifying_key
+        (1 + Groth16VerifyingKey::LEN) + // delivery_verifying_key
+        8 + // dispute_cooldown
+        8 + // min_arbitrator_stake
+        8 + // keeper_reward
+        2 + // fee_discount_reputation
+        2 + // max_fee_discount_bps
+        4 + // min_reveals_for_settlement
+        8 + // clock_skew_tolerance
+        2 + // collateral_waiver_reputation
+        2 + // collateral_waiver_bps
+        8 + // max_oracle_age
+        2 + // max_price_deviation_bps
+        (4 + Self::MAX_SUPPORTED_MINTS * (32 + 8 + 8)) + // mint_fee_bounds
+        8 + // paused_at
+        (4 + Self::MAX_SUPPORTED_MINTS * (32 + 8)) + // dispute_bonds
+        8 + // relist_cooldown
+        8 + // settlement_reimbursement_usd_cents
+        2 + // early_reveal_window_bps
+        2 + // reveal_rebate_bps
+        4 + // max_active_auctions_per_seller
+        8 + // max_reveal_duration
+        8 + // return_window
+        2 + // late_funding_penalty_bps_per_day
+        2 + // max_late_funding_penalty_bps
+        (1 + Groth16VerifyingKey::LEN) + // reveal_verifying_key
+        (1 + Groth16VerifyingKey::LEN); // range_verifying_key
+ 
     /// Default values for production
     pub fn default_production() -> Self {
         Self {
             authority: Pubkey::default(),
             upgrade_authority: Pubkey::default(),
+            pending_authority: None,
             fee_collector: Pubkey::default(),
             platform_fee_bps: 250, // 2.5%
             min_auction_duration: 3600, // 1 hour
@@ -110,12 +364,50 @@ impl ProgramConfig {
             min_seller_reputation: 300,
             min_high_value_reputation: 700,
             high_value_threshold: 1_000_000, // $10,000 in cents
+            physical_timelock: 30 * 24 * 60 * 60, // 30 days
+            digital_timelock: 24 * 60 * 60, // 24 hours
+            service_timelock: 14 * 24 * 60 * 60, // 14 days
+            slash_split_bps: 5000, // 50%
+            info_request_extension: 3 * 24 * 60 * 60, // 3 days
+            evidence_period: crate::state::Dispute::DEFAULT_EVIDENCE_PERIOD,
+            resolution_period: crate::state::Dispute::DEFAULT_RESOLUTION_PERIOD,
+            claim_window: 48 * 60 * 60, // 48 hours
+            late_reveal_grace: 60 * 60, // 1 hour
+            credit_winner_collateral: true,
+            dispute_cooldown: 24 * 60 * 60, // 24 hours
+            min_arbitrator_stake: crate::state::ReputationStake::MIN_HIGH_VALUE_STAKE,
+            keeper_reward: 1_000_000, // 0.001 SOL
+            min_reveals_for_settlement: 1,
+            fee_discount_reputation: 700,
+            max_fee_discount_bps: 50, // 0.5%
+            clock_skew_tolerance: 2,
+            collateral_waiver_reputation: 0,
+            collateral_waiver_bps: 5_000, // 50%
+            max_oracle_age: 60 * 60, // 1 hour
+            max_price_deviation_bps: 1_000, // 10%
+            mint_fee_bounds: vec![],
+            dispute_bonds: vec![],
+            relist_cooldown: 24 * 60 * 60, // 24 hours
+            settlement_reimbursement_usd_cents: 0,
+            max_active_auctions_per_seller: 50,
+            max_reveal_duration: 7 * 24 * 60 * 60, // 7 days
+            return_window: 14 * 24 * 60 * 60, // 14 days
+            early_reveal_window_bps: 2_500, // first quarter of the window
+            reveal_rebate_bps: 0,
+            late_funding_penalty_bps_per_day: 500, // 5% per day
+            max_late_funding_penalty_bps: 1_500, // three days late
             paused: false,
+            paused_at: 0,
             state_tree: Pubkey::default(),
             nullifier_queue: Pubkey::default(),
             per_config: Pubkey::default(),
             supported_mints: vec![],
             arbitrators: vec![],
+            category_fee_overrides: vec![],
+            bid_verifying_key: None,
+            delivery_verifying_key: None,
+            reveal_verifying_key: None,
+            range_verifying_key: None,
             version: 1,
             bump: 0,
             _reserved: [0u8; 64],
@@ -127,14 +419,294 @@ impl ProgramConfig {
         self.supported_mints.contains(mint)
     }
  
+    /// Check if a token account belongs to the fee collector
+    /// (either the configured account itself or one owned by it)
+    pub fn is_fee_collector(&self, key: &Pubkey, owner: &Pubkey) -> bool {
+        self.fee_collector == *key || self.fee_collector == *owner
+    }
+ 
+    /// Validate an escrow time-lock duration
+    pub fn validate_timelock(timelock: i64) -> Result<()> {
+        require!(
+            (0..=Self::MAX_TIMELOCK).contains(&timelock),
+            crate::errors::ConfigError::InvalidParameter
+        );
+        Ok(())
+    }
+ 
+    /// Validate a clock skew tolerance
+    pub fn validate_clock_skew_tolerance(tolerance: i64) -> Result<()> {
+        require!(
+            (0..=Self::MAX_CLOCK_SKEW_TOLERANCE).contains(&tolerance),
+            crate::errors::ConfigError::InvalidParameter
+        );
+        Ok(())
+    }
+ 
+    /// Time to compare against auction phase boundaries. Shifting every check by the
+    /// same tolerance makes each boundary take effect at one agreed instant, so an
+    /// action near a deadline gets the same answer from every instruction.
+    pub fn phase_time(&self, now: i64) -> i64 {
+        now.saturating_sub(self.clock_skew_tolerance)
+    }
+ 
+    /// Pause or resume the program; the pause clock only starts on the first pause
+    pub fn set_paused(&mut self, paused: bool, now: i64) {
+        if paused && !self.paused {
+            self.paused_at = now;
+        } else if !paused {
+            self.paused_at = 0;
+        }
+        self.paused = paused;
+    }
+ 
+    /// Check if the program has been paused long enough for emergency withdrawals
+    pub fn emergency_withdraw_open(&self, now: i64) -> bool {
+        self.paused && now >= self.paused_at.saturating_add(Self::EMERGENCY_WITHDRAW_DELAY)
+    }
+ 
+    /// Validate a period duration (must be positive)
+    pub fn validate_period(period: i64) -> Result<()> {
+        require!(
+            (1..=Self::MAX_TIMELOCK).contains(&period),
+            crate::errors::ConfigError::InvalidParameter
+        );
+        Ok(())
+    }
+ 
+    /// Validate dispute evidence and resolution windows
+    pub fn validate_dispute_periods(evidence_period: i64, resolution_period: i64) -> Result<()> {
+        Self::validate_period(evidence_period)?;
+        Self::validate_period(resolution_period)?;
+        require!(
+            resolution_period > evidence_period,
+            crate::errors::ConfigError::InvalidParameter
+        );
+        Ok(())
+    }
+ 
+    /// Split a slashed amount into (to wronged party, to platform)
+    pub fn split_slash(&self, amount: u64) -> (u64, u64) {
+        let to_counterparty = (amount as u128 * self.slash_split_bps as u128 / 10_000) as u64;
+        (to_counterparty, amount - to_counterparty)
+    }
+ 
     /// Check if address is an arbitrator
     pub fn is_arbitrator(&self, address: &Pubkey) -> bool {
         self.arbitrators.contains(address)
     }
  
-    /// Calculate platform fee for a given amount
-    pub fn calculate_fee(&self, amount: u64) -> u64 {
-        (amount * self.platform_fee_bps as u64) / 10_000
+    /// Whether an arbitrator's stake is large enough to vote on disputes
+    pub fn arbitrator_stake_sufficient(&self, stake: &ReputationStake) -> bool {
+        stake.amount >= self.min_arbitrator_stake
+    }
+ 
+    /// Platform fee rate for a category (override if configured, else the base fee)
+    pub fn fee_bps_for(&self, category: Category) -> u16 {
+        self.category_fee_overrides
+            .iter()
+            .find(|fee_override| fee_override.category == category)
+            .map_or(self.platform_fee_bps, |fee_override| fee_override.fee_bps)
+    }
+ 
+    /// Fee discount for a seller's reputation, scaled linearly from the threshold
+    /// up to max_fee_discount_bps at the maximum score
+    pub fn reputation_discount_bps(&self, seller_reputation: u16) -> u16 {
+        if seller_reputation <= self.fee_discount_reputation {
+            return 0;
+        }
+        let score = seller_reputation.min(Self::MAX_REPUTATION);
+        let above = (score - self.fee_discount_reputation) as u32;
+        let range = (Self::MAX_REPUTATION - self.fee_discount_reputation) as u32;
+        (self.max_fee_discount_bps as u32 * above / range) as u16
+    }
+ 
+    /// Fee floor and ceiling configured for a mint
+    pub fn fee_bounds_for(&self, mint: &Pubkey) -> Option<&MintFeeBounds> {
+        self.mint_fee_bounds.iter().find(|bounds| bounds.mint == *mint)
+    }
+ 
+    /// Calculate platform fee for an amount of `mint` in a category, discounted by the
+    /// seller's reputation and clamped to the mint's [min_fee, max_fee]. A sale smaller
+    /// than min_fee pays its whole amount as the fee, so the fee never exceeds the amount.
+    pub fn calculate_fee(
+        &self,
+        amount: u64,
+        mint: &Pubkey,
+        category: Category,
+        seller_reputation: u16,
+    ) -> u64 {
+        let fee_bps = self
+            .fee_bps_for(category)
+            .saturating_sub(self.reputation_discount_bps(seller_reputation));
+        let mut fee = (amount as u128 * fee_bps as u128 / 10_000) as u64;
+        if let Some(bounds) = self.fee_bounds_for(mint) {
+            if bounds.max_fee > 0 {
+                fee = fee.min(bounds.max_fee);
+            }
+            fee = fee.max(bounds.min_fee);
+        }
+        fee.min(amount)
+    }
+ 
+    /// Validate the per-mint fee floors and ceilings (one entry per mint)
+    pub fn validate_fee_bounds(mint_fee_bounds: &[MintFeeBounds]) -> Result<()> {
+        require!(
+            mint_fee_bounds.len() <= Self::MAX_SUPPORTED_MINTS,
+            crate::errors::ConfigError::InvalidParameter
+        );
+        for (i, bounds) in mint_fee_bounds.iter().enumerate() {
+            require!(
+                (bounds.max_fee == 0 || bounds.min_fee <= bounds.max_fee)
+                    && mint_fee_bounds[..i].iter().all(|other| other.mint != bounds.mint),
+                crate::errors::ConfigError::InvalidParameter
+            );
+        }
+        Ok(())
+    }
+ 
+    /// Bond required to raise a dispute over a sale in `mint`
+    pub fn dispute_bond_for(&self, mint: &Pubkey) -> u64 {
+        self.dispute_bonds
+            .iter()
+            .find(|bond| bond.mint == *mint)
+            .map_or(0, |bond| bond.amount)
+    }
+ 
+    /// Validate the per-mint dispute bonds (one entry per mint)
+    pub fn validate_dispute_bonds(dispute_bonds: &[MintDisputeBond]) -> Result<()> {
+        require!(
+            dispute_bonds.len() <= Self::MAX_SUPPORTED_MINTS,
+            crate::errors::ConfigError::InvalidParameter
+        );
+        for (i, bond) in dispute_bonds.iter().enumerate() {
+            require!(
+                dispute_bonds[..i].iter().all(|other| other.mint != bond.mint),
+                crate::errors::ConfigError::InvalidParameter
+            );
+        }
+        Ok(())
+    }
+ 
+    /// Validate the reputation fee discount settings
+    pub fn validate_fee_discount(threshold: u16, max_discount_bps: u16) -> Result<()> {
+        require!(
+            threshold < Self::MAX_REPUTATION && max_discount_bps <= Self::MAX_CATEGORY_FEE_BPS,
+            crate::errors::ConfigError::InvalidParameter
+        );
+        Ok(())
+    }
+ 
+    /// Collateral waived for a bidder: trusted bidders put their reputation at stake
+    /// for the waived share instead of tokens
+    pub fn collateral_waiver(&self, collateral: u64, bidder_reputation: u16) -> u64 {
+        if self.collateral_waiver_reputation == 0
+            || bidder_reputation < self.collateral_waiver_reputation
+        {
+            return 0;
+        }
+        (collateral as u128 * self.collateral_waiver_bps as u128 / 10_000) as u64
+    }
+ 
+    /// Validate the oracle staleness and deviation bounds
+    pub fn validate_oracle_bounds(max_age: i64, max_deviation_bps: u16) -> Result<()> {
+        Self::validate_period(max_age)?;
+        require!(
+            max_deviation_bps <= 10_000,
+            crate::errors::ConfigError::InvalidParameter
+        );
+        Ok(())
+    }
+ 
+    /// Stake a seller must hold to sell an item worth `value_usd_cents`
+    pub fn required_seller_stake(&self, value_usd_cents: u64) -> u64 {
+        if value_usd_cents >= self.high_value_threshold {
+            ReputationStake::MIN_HIGH_VALUE_STAKE
+        } else {
+            ReputationStake::MIN_SELLER_STAKE
+        }
+    }
+ 
+    /// Settlement reimbursement in the payment mint, capped at the seller's proceeds
+    /// (nothing is owed for a mint without a price feed to convert it)
+    pub fn settlement_reimbursement(
+        &self,
+        price_feed: Option<&PriceFeed>,
+        seller_receives: u64,
+    ) -> Result<u64> {
+        match price_feed {
+            Some(price_feed) if self.settlement_reimbursement_usd_cents > 0 => Ok(price_feed
+                .from_usd_cents(self.settlement_reimbursement_usd_cents)?
+                .min(seller_receives)),
+            _ => Ok(0),
+        }
+    }
+ 
+    /// Check a price feed is fresh and stable enough to settle against
+    pub fn check_price_feed(&self, price_feed: &PriceFeed, current_time: i64) -> Result<()> {
+        price_feed.check_price(current_time, self.max_oracle_age, self.max_price_deviation_bps)
+    }
+ 
+    /// Check a seller has room for `new_listings` more open auctions; a storefront is
+    /// required to track the count whenever a limit is configured
+    pub fn check_listing_capacity(
+        &self,
+        storefront: Option<&SellerStorefront>,
+        new_listings: u32,
+    ) -> Result<()> {
+        if self.max_active_auctions_per_seller == 0 {
+            return Ok(());
+        }
+        let storefront = storefront.ok_or(crate::errors::AuctionError::StorefrontRequired)?;
+        require!(
+            storefront.active_listings.saturating_add(new_listings)
+                <= self.max_active_auctions_per_seller,
+            crate::errors::AuctionError::TooManyActiveAuctions
+        );
+        Ok(())
+    }
+ 
+    /// Rebate owed on a prompt revealer's collateral, before capping to the penalty reserve
+    pub fn reveal_rebate(&self, collateral: u64) -> u64 {
+        (collateral as u128 * self.reveal_rebate_bps as u128 / 10_000) as u64
+    }
+ 
+    /// Validate the prompt-reveal window and rebate shares
+    pub fn validate_reveal_rebate(window_bps: u16, rebate_bps: u16) -> Result<()> {
+        require!(
+            window_bps <= 10_000 && rebate_bps <= 10_000,
+            crate::errors::ConfigError::InvalidParameter
+        );
+        Ok(())
+    }
+ 
+    /// How long after the funding grace period a winner may still fund with a penalty:
+    /// as many days as it takes the daily penalty to reach the cap
+    pub fn late_funding_window(&self) -> i64 {
+        if self.late_funding_penalty_bps_per_day == 0 {
+            return 0;
+        }
+        let days = (self.max_late_funding_penalty_bps as u64)
+            .div_ceil(self.late_funding_penalty_bps_per_day as u64);
+        days as i64 * 24 * 60 * 60
+    }
+ 
+    /// Validate the late-funding penalty schedule
+    pub fn validate_late_funding_penalty(bps_per_day: u16, max_bps: u16) -> Result<()> {
+        require!(
+            max_bps <= 10_000 && bps_per_day <= max_bps,
+            crate::errors::ConfigError::InvalidParameter
+        );
+        Ok(())
+    }
+ 
+    /// Validate the collateral waiver threshold and share
+    pub fn validate_collateral_waiver(threshold: u16, waiver_bps: u16) -> Result<()> {
+        require!(
+            threshold <= Self::MAX_REPUTATION && waiver_bps <= 10_000,
+            crate::errors::ConfigError::InvalidParameter
+        );
+        Ok(())
     }
  
     /// Validate auction parameters
@@ -159,6 +731,37 @@ impl ProgramConfig {
     }
 }
  
+impl LegacyLayout for ProgramConfig {
+    const SPACE: usize = Self::LEN;
+    const LEGACY_SPACE: usize = Self::LEGACY_LEN;
+ 
+    fn decode_legacy(data: &mut &[u8], _auction: Option<&mut AuctionState>) -> Result<Self> {
+        let mut config = Self::default_production();
+        config.authority = read_field(data)?;
+        config.upgrade_authority = read_field(data)?;
+        config.fee_collector = read_field(data)?;
+        config.platform_fee_bps = read_field(data)?;
+        config.min_auction_duration = read_field(data)?;
+        config.max_auction_duration = read_field(data)?;
+        config.default_reveal_duration = read_field(data)?;
+        config.min_bid_collateral = read_field(data)?;
+        config.max_bid_collateral = read_field(data)?;
+        config.min_seller_reputation = read_field(data)?;
+        config.min_high_value_reputation = read_field(data)?;
+        config.high_value_threshold = read_field(data)?;
+        config.paused = read_field(data)?;
+        config.state_tree = read_field(data)?;
+        config.nullifier_queue = read_field(data)?;
+        config.per_config = read_field(data)?;
+        config.supported_mints = read_field(data)?;
+        config.arbitrators = read_field(data)?;
+        config.version = read_field(data)?;
+        config.bump = read_field(data)?;
+        config._reserved = read_field(data)?;
+        Ok(config)
+    }
+}
+ 
 /// Program statistics (for analytics)
 #[account]
 #[derive(Default, Debug)]
@@ -166,13 +769,14 @@ pub struct ProgramStats {
     /// Total auctions created
     pub total_auctions: u64,
  
-    /// Active auctions
+    /// Active auctions (open until settled or closed; disputes come after
+    /// settlement and don't touch this counter)
     pub active_auctions: u64,
  
     /// Total bids placed
     pub total_bids: u64,
  
-    /// Total transaction volume (in lamports)
+    /// Total transaction volume (in USD cents)
     pub total_volume: u64,
  
     /// Total fees collected (in lamports)
@@ -192,47 +796,282 @@ pub struct ProgramStats {
  
     /// Bump seed for PDA
     pub bump: u8,
+ 
+    // Fields below were added after the original release. New fields are only ever
+    // appended, so accounts created under an older layout can be migrated in place.
+    /// Disputes currently open (not yet resolved or withdrawn)
+    pub open_disputes: u64,
+ 
+    /// Sequence number of the last emitted event (total ordering for indexers)
+    pub event_seq: u64,
 }
  
 impl ProgramStats {
-    pub const LEN: usize = 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 1;
+    /// Size of accounts created under the original layout
+    pub const LEGACY_LEN: usize = 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 1;
+ 
+    pub const LEN: usize = Self::LEGACY_LEN + 8 + 8;
+ 
+    /// Advance and return the event sequence number; every emitted event carries one
+    pub fn next_event_seq(&mut self) -> u64 {
+        self.event_seq += 1;
+        self.event_seq
+    }
  
     /// Update stats when auction is created
-    pub fn auction_created(&mut self) {
+    pub fn auction_created(&mut self, now: i64) {
         self.total_auctions += 1;
         self.active_auctions += 1;
-        self.last_updated = Clock::get().unwrap().unix_timestamp;
+        self.last_updated = now;
     }
  
-    /// Update stats when auction is completed
-    pub fn auction_completed(&mut self, volume: u64, fee: u64) {
+    /// Update stats when auction is completed (volume in USD cents)
+    pub fn auction_completed(&mut self, volume_usd_cents: u64, fee: u64, now: i64) {
         self.active_auctions = self.active_auctions.saturating_sub(1);
-        self.total_volume += volume;
-        self.total_fees += fee;
-        self.last_updated = Clock::get().unwrap().unix_timestamp;
+        self.total_volume = self.total_volume.saturating_add(volume_usd_cents);
+        self.total_fees = self.total_fees.saturating_add(fee);
+        self.last_updated = now;
     }
  
     /// Update stats when bid is placed
-    pub fn bid_placed(&mut self) {
+    pub fn bid_placed(&mut self, now: i64) {
         self.total_bids += 1;
-        self.last_updated = Clock::get().unwrap().unix_timestamp;
+        self.last_updated = now;
     }
  
     /// Update stats when user registers
-    pub fn user_registered(&mut self) {
+    pub fn user_registered(&mut self, now: i64) {
         self.total_users += 1;
-        self.last_updated = Clock::get().unwrap().unix_timestamp;
+        self.last_updated = now;
     }
  
     /// Update stats when dispute is raised
-    pub fn dispute_raised(&mut self) {
+    pub fn dispute_raised(&mut self, now: i64) {
         self.total_disputes += 1;
-        self.last_updated = Clock::get().unwrap().unix_timestamp;
+        self.open_disputes += 1;
+        self.last_updated = now;
     }
  
     /// Update stats when dispute is resolved
-    pub fn dispute_resolved(&mut self) {
+    pub fn dispute_resolved(&mut self, now: i64) {
         self.disputes_resolved += 1;
-        self.last_updated = Clock::get().unwrap().unix_timestamp;
+        self.open_disputes = self.open_disputes.saturating_sub(1);
+        self.last_updated = now;
+    }
+ 
+    /// Update stats when a dispute is withdrawn before arbitration
+    pub fn dispute_cancelled(&mut self, now: i64) {
+        self.open_disputes = self.open_disputes.saturating_sub(1);
+        self.last_updated = now;
+    }
+}
+ 
+impl LegacyLayout for ProgramStats {
+    const SPACE: usize = Self::LEN;
+    const LEGACY_SPACE: usize = Self::LEGACY_LEN;
+ 
+    fn decode_legacy(data: &mut &[u8], _auction: Option<&mut AuctionState>) -> Result<Self> {
+        let mut stats: Self = zeroed(Self::LEN)?;
+        stats.total_auctions = read_field(data)?;
+        stats.active_auctions = read_field(data)?;
+        stats.total_bids = read_field(data)?;
+        stats.total_volume = read_field(data)?;
+        stats.total_fees = read_field(data)?;
+        stats.total_users = read_field(data)?;
+        stats.total_disputes = read_field(data)?;
+        stats.disputes_resolved = read_field(data)?;
+        stats.last_updated = read_field(data)?;
+        stats.bump = read_field(data)?;
+        stats.open_disputes = stats.total_disputes.saturating_sub(stats.disputes_resolved);
+        Ok(stats)
+    }
+}
+ 
+#[cfg(test)]
+mod tests {
+    use super::*;
+ 
+    const USDC: Pubkey = Pubkey::new_from_array([1; 32]);
+ 
+    #[test]
+    fn oracle_value_above_threshold_needs_high_value_stake() {
+        let config = ProgramConfig::default_production();
+        let threshold = config.high_value_threshold;
+ 
+        assert_eq!(
+            config.required_seller_stake(threshold - 1),
+            ReputationStake::MIN_SELLER_STAKE
+        );
+        assert_eq!(
+            config.required_seller_stake(threshold),
+            ReputationStake::MIN_HIGH_VALUE_STAKE
+        );
+    }
+ 
+    #[test]
+    fn category_override_replaces_the_base_rate() {
+        let mut config = ProgramConfig::default_production();
+        config.category_fee_overrides = vec![CategoryFeeOverride {
+            category: Category::Art,
+            fee_bps: 100,
+        }];
+ 
+        assert_eq!(config.calculate_fee(1_000_000, &USDC, Category::Art, 0), 10_000);
+        assert_eq!(config.calculate_fee(1_000_000, &USDC, Category::Electronics, 0), 25_000);
+    }
+ 
+    #[test]
+    fn reputation_discount_scales_from_the_threshold() {
+        let config = ProgramConfig::default_production();
+ 
+        assert_eq!(config.reputation_discount_bps(config.fee_discount_reputation), 0);
+        assert_eq!(config.reputation_discount_bps(850), config.max_fee_discount_bps / 2);
+        assert_eq!(
+            config.reputation_discount_bps(ProgramConfig::MAX_REPUTATION),
+            config.max_fee_discount_bps
+        );
+        assert_eq!(config.reputation_discount_bps(u16::MAX), config.max_fee_discount_bps);
+    }
+ 
+    #[test]
+    fn trusted_seller_pays_a_lower_fee_on_the_same_sale() {
+        let config = ProgramConfig::default_production();
+ 
+        // 500 is below the threshold and pays the full 2.5%; 900 is two thirds of
+        // the way to the maximum score and earns two thirds of the 0.5% discount
+        assert_eq!(config.calculate_fee(1_000_000, &USDC, Category::Electronics, 500), 25_000);
+        assert_eq!(config.calculate_fee(1_000_000, &USDC, Category::Electronics, 900), 21_700);
+    }
+ 
+    fn price_feed(price: u64, decimals: u8) -> PriceFeed {
+        let mut price_feed: PriceFeed = zeroed(PriceFeed::LEN).unwrap();
+        price_feed.price = price;
+        price_feed.decimals = decimals;
+        price_feed
+    }
+ 
+    #[test]
+    fn settlement_caller_is_reimbursed_out_of_the_seller_payout() {
+        let mut config = ProgramConfig::default_production();
+        config.settlement_reimbursement_usd_cents = 50;
+        let usdc = price_feed(1_000_000, 6);
+        let sol = price_feed(150_000_000, 9);
+ 
+        // The same 50 cents is paid in whichever mint the auction settles in
+        let platform_fee = config.calculate_fee(1_000_000, &USDC, Category::Electronics, 0);
+        let seller_receives = 1_000_000 - platform_fee;
+        let reimbursement = config.settlement_reimbursement(Some(&usdc), seller_receives).unwrap();
+        assert_eq!(reimbursement, 500_000);
+        assert_eq!(seller_receives - reimbursement, 475_000);
+        assert_eq!(config.settlement_reimbursement(Some(&sol), u64::MAX).unwrap(), 3_333_333);
+ 
+        // Never more than the seller is owed, and nothing without a feed to convert through
+        assert_eq!(config.settlement_reimbursement(Some(&usdc), 200_000).unwrap(), 200_000);
+        assert_eq!(config.settlement_reimbursement(None, seller_receives).unwrap(), 0);
+    }
+ 
+    #[test]
+    fn fee_is_clamped_to_the_mint_floor_and_ceiling() {
+        let mut config = ProgramConfig::default_production();
+        config.mint_fee_bounds = vec![MintFeeBounds {
+            mint: USDC,
+            min_fee: 1_000_000,
+            max_fee: 50_000_000,
+        }];
+        let fee = |amount| config.calculate_fee(amount, &USDC, Category::Electronics, 0);
+ 
+        // 2.5% of $40 is exactly the $1 floor; 2.5% of $2,000 is exactly the $50 ceiling
+        assert_eq!(fee(40_000_000), 1_000_000);
+        assert_eq!(fee(10_000_000), 1_000_000);
+        assert_eq!(fee(2_000_000_000), 50_000_000);
+        assert_eq!(fee(10_000_000_000), 50_000_000);
+ 
+        // A sale below the floor pays its whole amount as the fee, never more
+        assert_eq!(fee(600_000), 600_000);
+ 
+        // Bounds are in the mint's own units and don't carry over to other mints
+        let other = config.calculate_fee(600_000, &Pubkey::new_unique(), Category::Electronics, 0);
+        assert_eq!(other, 15_000);
+    }
+ 
+    #[test]
+    fn fee_bounds_are_one_consistent_entry_per_mint() {
+        let bounds = |mint, min_fee, max_fee| MintFeeBounds { mint, min_fee, max_fee };
+ 
+        assert!(ProgramConfig::validate_fee_bounds(&[bounds(USDC, 100, 0)]).is_ok());
+        assert!(ProgramConfig::validate_fee_bounds(&[bounds(USDC, 100, 50)]).is_err());
+        assert!(
+            ProgramConfig::validate_fee_bounds(&[bounds(USDC, 0, 10), bounds(USDC, 0, 20)]).is_err()
+        );
+    }
+ 
+    #[test]
+    fn dispute_bond_is_set_per_mint() {
+        let mut config = ProgramConfig::default_production();
+        config.dispute_bonds = vec![MintDisputeBond { mint: USDC, amount: 5_000_000 }];
+ 
+        assert_eq!(config.dispute_bond_for(&USDC), 5_000_000);
+        assert_eq!(config.dispute_bond_for(&Pubkey::new_unique()), 0);
+        assert!(ProgramConfig::validate_dispute_bonds(&[
+            MintDisputeBond { mint: USDC, amount: 1 },
+            MintDisputeBond { mint: USDC, amount: 2 },
+        ])
+        .is_err());
+    }
+ 
+    #[test]
+    fn under_staked_arbitrator_cannot_vote() {
+        let config = ProgramConfig::default_production();
+        let mut stake: ReputationStake = zeroed(ReputationStake::LEN).unwrap();
+ 
+        stake.amount = config.min_arbitrator_stake - 1;
+        assert!(!config.arbitrator_stake_sufficient(&stake));
+ 
+        stake.amount = config.min_arbitrator_stake;
+        assert!(config.arbitrator_stake_sufficient(&stake));
+ 
+        // A slash on an overturned decision can drop the arbitrator below the floor
+        stake.slash(10);
+        assert!(!config.arbitrator_stake_sufficient(&stake));
+    }
+ 
+    #[test]
+    fn dispute_lifecycle_leaves_active_auctions_alone() {
+        // A settled auction has already left active_auctions
+        let mut stats: ProgramStats = zeroed(ProgramStats::LEN).unwrap();
+        stats.auction_created(1);
+        stats.auction_completed(10_000, 250, 2);
+        assert_eq!(stats.active_auctions, 0);
+ 
+        stats.dispute_raised(3);
+        assert_eq!((stats.active_auctions, stats.open_disputes), (0, 1));
+ 
+        stats.dispute_resolved(4);
+        assert_eq!((stats.active_auctions, stats.open_disputes), (0, 0));
+        assert_eq!((stats.total_disputes, stats.disputes_resolved), (1, 1));
+        assert_eq!(stats.last_updated, 4);
+ 
+        // A dispute withdrawn before arbitration doesn't count as resolved
+        stats.dispute_raised(5);
+        stats.dispute_cancelled(6);
+        assert_eq!((stats.active_auctions, stats.open_disputes), (0, 0));
+        assert_eq!((stats.total_disputes, stats.disputes_resolved), (2, 1));
+    }
+ 
+    #[test]
+    fn event_sequence_is_strictly_increasing_without_gaps() {
+        let mut stats: ProgramStats = zeroed(ProgramStats::LEN).unwrap();
+ 
+        // create_auction, submit_bid, then settle_auction emitting two events
+        let mut seqs = vec![stats.next_event_seq()];
+        stats.auction_created(1);
+        seqs.push(stats.next_event_seq());
+        stats.bid_placed(2);
+        seqs.extend([stats.next_event_seq(), stats.next_event_seq()]);
+        stats.auction_completed(10_000, 250, 3);
+ 
+        // Indexers rely on consecutive numbers to detect missed events
+        assert_eq!(seqs, [1, 2, 3, 4]);
+        assert_eq!(stats.event_seq, 4);
     }
 }
\ No newline at end of file
@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+ 
+/// Per-seller aggregate of listings and sales for storefront display
+#[account]
+#[derive(Default, Debug)]
+pub struct SellerStorefront {
+    /// Seller this storefront belongs to
+    pub seller: Pubkey,
+ 
+    /// Auctions currently open (not yet settled or closed)
+    pub active_listings: u32,
+ 
+    /// Total auctions created
+    pub total_listings: u32,
+ 
+    /// Auctions settled with a winner
+    pub total_sales: u32,
+ 
+    /// Lifetime settled volume (in USD cents)
+    pub lifetime_volume: u64,
+ 
+    /// Creation timestamp
+    pub created_at: i64,
+ 
+    /// Last updated timestamp
+    pub last_updated: i64,
+ 
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+ 
+impl SellerStorefront {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // seller
+        4 + // active_listings
+        4 + // total_listings
+        4 + // total_sales
+        8 + // lifetime_volume
+        8 + // created_at
+        8 + // last_updated
+        1; // bump
+ 
+    /// Load the storefront at its seed-derived address, or None if the seller hasn't
+    /// created one
+    pub fn load(info: &AccountInfo) -> Result<Option<Self>> {
+        if info.owner != &crate::ID || info.data_is_empty() {
+            return Ok(None);
+        }
+        Self::try_deserialize(&mut &info.try_borrow_data()?[..]).map(Some)
+    }
+ 
+    /// Apply `update` to the seller's storefront, if they have one, and persist it
+    pub fn update(info: &AccountInfo, update: impl FnOnce(&mut Self)) -> Result<()> {
+        if let Some(mut storefront) = Self::load(info)? {
+            update(&mut storefront);
+            storefront.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+        }
+        Ok(())
+    }
+ 
+    /// Record a new listing
+    pub fn listing_created(&mut self, timestamp: i64) {
+        self.active_listings += 1;
+        self.total_listings += 1;
+        self.last_updated = timestamp;
+    }
+ 
+    /// Record a listing closing without a sale (cancelled, expired, failed)
+    pub fn listing_closed(&mut self, timestamp: i64) {
+        self.active_listings = self.active_listings.saturating_sub(1);
+        self.last_updated = timestamp;
+    }
+ 
+    /// Record a settled sale (volume in USD cents)
+    pub fn sale_completed(&mut self, volume_usd_cents: u64, timestamp: i64) {
+        self.active_listings = self.active_listings.saturating_sub(1);
+        self.total_sales += 1;
+        self.lifetime_volume += volume_usd_cents;
+        self.last_updated = timestamp;
+    }
+}
+ 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{zeroed, ProgramConfig};
+ 
+    #[test]
+    fn listing_limit_frees_up_when_a_listing_ends() {
+        let mut config = ProgramConfig::default_production();
+        config.max_active_auctions_per_seller = 2;
+        let mut storefront: SellerStorefront = zeroed(SellerStorefront::LEN).unwrap();
+ 
+        storefront.listing_created(1);
+        storefront.listing_created(2);
+        assert!(config.check_listing_capacity(Some(&storefront), 1).is_err());
+ 
+        storefront.sale_completed(5_000, 3);
+        assert!(config.check_listing_capacity(Some(&storefront), 1).is_ok());
+        assert_eq!(storefront.total_sales, 1);
+        assert_eq!(storefront.lifetime_volume, 5_000);
+ 
+        storefront.listing_created(4);
+        storefront.listing_closed(5);
+        assert!(config.check_listing_capacity(Some(&storefront), 1).is_ok());
+        assert_eq!(storefront.active_listings, 1);
+        assert_eq!(storefront.total_listings, 3);
+    }
+ 
+    #[test]
+    fn listing_limit_needs_a_storefront() {
+        let mut config = ProgramConfig::default_production();
+        config.max_active_auctions_per_seller = 0;
+        assert!(config.check_listing_capacity(None, 1).is_ok());
+ 
+        config.max_active_auctions_per_seller = 2;
+        assert!(config.check_listing_capacity(None, 1).is_err());
+    }
+}
@@ -0,0 +1,403 @@
+use anchor_lang::prelude::*;
+ 
+use crate::state::{Groth16VerifyingKey, ProgramConfig};
+use crate::errors::{BidError, FulfillmentError};
+ 
+/// Native program that verifies Ed25519 signatures
+const ED25519_PROGRAM_ID: Pubkey = pubkey!("Ed25519SigVerify111111111111111111111111111");
+ 
+/// Offset of the first signature entry in Ed25519 program instruction data
+const ED25519_OFFSETS_START: usize = 2;
+ 
+/// Size of one signature entry's offsets in Ed25519 program instruction data
+const ED25519_OFFSETS_LEN: usize = 14;
+ 
+/// Verifies a proof against a set of public inputs
+pub trait ProofVerifier {
+    fn verify(&self, proof: &[u8], public_inputs: &[[u8; 32]]) -> bool;
+}
+ 
+/// Fallback used when the `zk` feature is disabled (accepts any non-empty proof)
+pub struct MockVerifier;
+ 
+impl ProofVerifier for MockVerifier {
+    fn verify(&self, proof: &[u8], _public_inputs: &[[u8; 32]]) -> bool {
+        !proof.is_empty()
+    }
+}
+ 
+/// Groth16 verifier over BN254 using the alt_bn128 syscalls
+#[cfg(feature = "zk")]
+pub struct Groth16Verifier<'a> {
+    pub vk: &'a Groth16VerifyingKey,
+}
+ 
+#[cfg(feature = "zk")]
+impl Groth16Verifier<'_> {
+    /// Proof layout: A (G1) || B (G2) || C (G1)
+    pub const PROOF_LEN: usize = 64 + 128 + 64;
+ 
+    /// BN254 base field modulus (big-endian)
+    const FIELD_MODULUS: [u8; 32] = [
+        0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58,
+        0x5d, 0x97, 0x81, 0x6a, 0x91, 0x68, 0x71, 0xca, 0x8d, 0x3c, 0x20, 0x8c, 0x16, 0xd8, 0x7c,
+        0xfd, 0x47,
+    ];
+ 
+    /// Negate a G1 point (p - y), as the pairing check needs -A
+    fn negate_g1(point: &[u8]) -> [u8; 64] {
+        let mut negated = [0u8; 64];
+        negated[..32].copy_from_slice(&point[..32]);
+        if point[32..].iter().all(|byte| *byte == 0) {
+            return negated;
+        }
+        let mut borrow = 0i16;
+        for i in (0..32).rev() {
+            let diff = Self::FIELD_MODULUS[i] as i16 - point[32 + i] as i16 - borrow;
+            borrow = (diff < 0) as i16;
+            negated[32 + i] = diff.rem_euclid(256) as u8;
+        }
+        negated
+    }
+ 
+    /// Accumulate IC[0] + sum(input_i * IC[i + 1])
+    fn prepare_inputs(&self, public_inputs: &[[u8; 32]]) -> Option<Vec<u8>> {
+        use solana_bn254::prelude::{alt_bn128_addition, alt_bn128_multiplication};
+ 
+        let mut acc = self.vk.ic[0].to_vec();
+        for (input, ic) in public_inputs.iter().zip(&self.vk.ic[1..]) {
+            let term = alt_bn128_multiplication(&[ic.as_ref(), input.as_ref()].concat()).ok()?;
+            acc = alt_bn128_addition(&[acc.as_slice(), term.as_slice()].concat()).ok()?;
+        }
+        Some(acc)
+    }
+}
+ 
+#[cfg(feature = "zk")]
+impl ProofVerifier for Groth16Verifier<'_> {
+    fn verify(&self, proof: &[u8], public_inputs: &[[u8; 32]]) -> bool {
+        use solana_bn254::prelude::alt_bn128_pairing;
+ 
+        if proof.len() != Self::PROOF_LEN || public_inputs.len() + 1 != self.vk.ic.len() {
+            return false;
+        }
+        let Some(vk_x) = self.prepare_inputs(public_inputs) else {
+            return false;
+        };
+ 
+        // e(-A, B) * e(alpha, beta) * e(vk_x, gamma) * e(C, delta) == 1
+        let pairing_input = [
+            Self::negate_g1(&proof[..64]).as_ref(),
+            &proof[64..192],
+            self.vk.alpha_g1.as_ref(),
+            self.vk.beta_g2.as_ref(),
+            vk_x.as_slice(),
+            self.vk.gamma_g2.as_ref(),
+            &proof[192..],
+            self.vk.delta_g2.as_ref(),
+        ]
+        .concat();
+ 
+        match alt_bn128_pairing(&pairing_input) {
+            Ok(result) => {
+                result.len() == 32 && result[..31].iter().all(|b| *b == 0) && result[31] == 1
+            }
+            Err(_) => false,
+        }
+    }
+}
+ 
+/// Map 32 bytes into the BN254 scalar field by clearing the top bits
+pub fn to_field(bytes: &[u8; 32]) -> [u8; 32] {
+    let mut field = *bytes;
+    field[0] &= 0x1f;
+    field
+}
+ 
+/// Encode a u64 as a big-endian field element
+pub fn u64_to_field(value: u64) -> [u8; 32] {
+    let mut field = [0u8; 32];
+    field[24..].copy_from_slice(&value.to_be_bytes());
+    field
+}
+ 
+#[cfg(feature = "zk")]
+fn verify_with(vk: Option<&Groth16VerifyingKey>, proof: &[u8], public_inputs: &[[u8; 32]]) -> bool {
+    // Fail closed if no key is configured
+    vk.is_some_and(|vk| Groth16Verifier { vk }.verify(proof, public_inputs))
+}
+ 
+#[cfg(not(feature = "zk"))]
+fn verify_with(_vk: Option<&Groth16VerifyingKey>, proof: &[u8], public_inputs: &[[u8; 32]]) -> bool {
+    MockVerifier.verify(proof, public_inputs)
+}
+ 
+/// Verify a bid validity proof against the configured bid verifying key
+pub fn verify_bid_proof(
+    config: &ProgramConfig,
+    proof: &[u8],
+    public_inputs: &[[u8; 32]],
+) -> Result<()> {
+    require!(
+        verify_with(config.bid_verifying_key.as_ref(), proof, public_inputs),
+        BidError::InvalidProof
+    );
+    Ok(())
+}
+ 
+/// Verify a reveal proof (the commitment opens to the revealed amount) against the
+/// configured reveal verifying key
+pub fn verify_reveal_proof(
+    config: &ProgramConfig,
+    proof: &[u8],
+    public_inputs: &[[u8; 32]],
+) -> Result<()> {
+    require!(
+        verify_with(config.reveal_verifying_key.as_ref(), proof, public_inputs),
+        BidError::InvalidProof
+    );
+    Ok(())
+}
+ 
+/// Verify a range proof (the committed bid is at most the cap) against the configured
+/// range verifying key
+pub fn verify_range_proof(
+    config: &ProgramConfig,
+    proof: &[u8],
+    public_inputs: &[[u8; 32]],
+) -> Result<()> {
+    require!(
+        verify_with(config.range_verifying_key.as_ref(), proof, public_inputs),
+        BidError::InvalidProof
+    );
+    Ok(())
+}
+ 
+/// Verify a delivery proof against the configured delivery verifying key
+pub fn verify_delivery_proof(
+    config: &ProgramConfig,
+    proof: &[u8],
+    public_inputs: &[[u8; 32]],
+) -> Result<()> {
+    require!(
+        verify_with(config.delivery_verifying_key.as_ref(), proof, public_inputs),
+        FulfillmentError::InvalidDeliveryProof
+    );
+    Ok(())
+}
+ 
+/// Check that an Ed25519 program instruction in this transaction verified
+/// `signer`'s signature over `message`
+pub fn verify_ed25519_authorization(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    #[allow(deprecated)]
+    use anchor_lang::solana_program::sysvar::instructions::load_instruction_at_checked;
+ 
+    let mut index = 0;
+    while let Ok(instruction) = load_instruction_at_checked(index, instructions_sysvar) {
+        if instruction.program_id == ED25519_PROGRAM_ID
+            && ed25519_covers(&instruction.data, signer, message)
+        {
+            return Ok(());
+        }
+        index += 1;
+    }
+    err!(BidError::InvalidRevealAuthorization)
+}
+ 
+/// Check whether Ed25519 instruction data includes a signature by `signer` over `message`
+fn ed25519_covers(data: &[u8], signer: &Pubkey, message: &[u8]) -> bool {
+    let Some(&count) = data.first() else {
+        return false;
+    };
+ 
+    (0..count as usize).any(|i| {
+        let start = ED25519_OFFSETS_START + i * ED25519_OFFSETS_LEN;
+        let Some(offsets) = data.get(start..start + ED25519_OFFSETS_LEN) else {
+            return false;
+        };
+        let field = |at: usize| u16::from_le_bytes([offsets[at], offsets[at + 1]]);
+ 
+        // Signature, key and message must all live in the Ed25519 instruction itself
+        if [field(2), field(6), field(12)].iter().any(|ix| *ix != u16::MAX) {
+            return false;
+        }
+        let key_start = field(4) as usize;
+        let message_start = field(8) as usize;
+        let message_end = message_start + field(10) as usize;
+ 
+        data.get(key_start..key_start + 32) == Some(signer.as_ref())
+            && data.get(message_start..message_end) == Some(message)
+    })
+}
+ 
+#[cfg(test)]
+mod tests {
+    use super::*;
+ 
+    #[cfg(not(feature = "zk"))]
+    #[test]
+    fn mock_verifier_requires_a_proof() {
+        assert!(MockVerifier.verify(&[1], &[]));
+        assert!(!MockVerifier.verify(&[], &[]));
+    }
+ 
+    /// Ed25519 program data for one instruction signing each (signer, message) pair
+    fn ed25519_data(entries: &[(Pubkey, [u8; 32])]) -> Vec<u8> {
+        let mut data = vec![entries.len() as u8, 0];
+        let body_start = ED25519_OFFSETS_START + entries.len() * ED25519_OFFSETS_LEN;
+        let mut body = Vec::new();
+        for (signer, message) in entries {
+            let key_offset = body_start + body.len();
+            body.extend_from_slice(signer.as_ref());
+            let signature_offset = body_start + body.len();
+            body.extend_from_slice(&[7u8; 64]);
+            let message_offset = body_start + body.len();
+            body.extend_from_slice(message);
+ 
+            let offsets = [
+                signature_offset as u16,
+                u16::MAX,
+                key_offset as u16,
+                u16::MAX,
+                message_offset as u16,
+                message.len() as u16,
+                u16::MAX,
+            ];
+            data.extend(offsets.iter().flat_map(|field| field.to_le_bytes()));
+        }
+        data.extend(body);
+        data
+    }
+ 
+    #[test]
+    fn batched_reveal_authorizations_must_match_each_bidder() {
+        let auction = Pubkey::new_unique();
+        let reveal = |amount: u64| crate::state::BidReveal {
+            amount,
+            salt: [3u8; 32],
+            proof: vec![],
+        };
+        let alice = Pubkey::new_unique();
+        let bob = Pubkey::new_unique();
+        let alice_message = reveal(1_000).authorization_message(&alice, &auction);
+        let bob_message = reveal(2_000).authorization_message(&bob, &auction);
+ 
+        // One Ed25519 instruction covering both reveals in the batch
+        let data = ed25519_data(&[(alice, alice_message), (bob, bob_message)]);
+        assert!(ed25519_covers(&data, &alice, &alice_message));
+        assert!(ed25519_covers(&data, &bob, &bob_message));
+ 
+        // Forged: a keeper changing the amount, or passing one bidder's signature off as another's
+        let forged_amount = reveal(1).authorization_message(&alice, &auction);
+        assert!(!ed25519_covers(&data, &alice, &forged_amount));
+        assert!(!ed25519_covers(&data, &bob, &alice_message));
+        let other_auction = reveal(1_000).authorization_message(&alice, &Pubkey::new_unique());
+        assert!(!ed25519_covers(&data, &alice, &other_auction));
+ 
+        // Signatures pointing into another instruction aren't trusted
+        let mut elsewhere = ed25519_data(&[(alice, alice_message)]);
+        elsewhere[ED25519_OFFSETS_START + 6..ED25519_OFFSETS_START + 8]
+            .copy_from_slice(&0u16.to_le_bytes());
+        assert!(!ed25519_covers(&elsewhere, &alice, &alice_message));
+    }
+ 
+    #[cfg(feature = "zk")]
+    mod groth16 {
+        use super::*;
+        use solana_bn254::prelude::alt_bn128_multiplication;
+ 
+        /// BN254 G2 generator (x.c1, x.c0, y.c1, y.c0)
+        const G2_GENERATOR: [&str; 4] = [
+            "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+            "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+            "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+            "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+        ];
+ 
+        fn g1() -> [u8; 64] {
+            let mut point = [0u8; 64];
+            point[31] = 1;
+            point[63] = 2;
+            point
+        }
+ 
+        fn g2() -> [u8; 128] {
+            let mut point = [0u8; 128];
+            for (i, hex) in G2_GENERATOR.iter().enumerate() {
+                for j in 0..32 {
+                    point[i * 32 + j] = u8::from_str_radix(&hex[j * 2..j * 2 + 2], 16).unwrap();
+                }
+            }
+            point
+        }
+ 
+        /// Fixed key whose generators make the pairing check reduce to C == -vk_x
+        fn fixed_key(public_inputs: usize) -> Groth16VerifyingKey {
+            Groth16VerifyingKey {
+                alpha_g1: g1(),
+                beta_g2: g2(),
+                gamma_g2: g2(),
+                delta_g2: g2(),
+                ic: vec![g1(); public_inputs + 1],
+            }
+        }
+ 
+        /// A = G1 and B = G2 cancel alpha/beta; C cancels vk_x = (1 + sum(inputs)) * G1
+        fn proof_for(inputs: &[u64]) -> Vec<u8> {
+            let scalar = u64_to_field(1 + inputs.iter().sum::<u64>());
+            let vk_x =
+                alt_bn128_multiplication(&[g1().as_ref(), scalar.as_ref()].concat()).unwrap();
+            [g1().as_ref(), g2().as_ref(), Groth16Verifier::negate_g1(&vk_x).as_ref()].concat()
+        }
+ 
+        fn fields(inputs: &[u64]) -> Vec<[u8; 32]> {
+            inputs.iter().map(|input| u64_to_field(*input)).collect()
+        }
+ 
+        #[test]
+        fn accepts_a_valid_proof() {
+            let vk = fixed_key(2);
+            assert!(Groth16Verifier { vk: &vk }.verify(&proof_for(&[7, 9]), &fields(&[7, 9])));
+        }
+ 
+        #[test]
+        fn rejects_a_proof_for_other_inputs() {
+            let vk = fixed_key(2);
+            assert!(!Groth16Verifier { vk: &vk }.verify(&proof_for(&[7, 9]), &fields(&[7, 10])));
+        }
+ 
+        #[test]
+        fn rejects_a_malformed_proof() {
+            let vk = fixed_key(2);
+            let mut proof = proof_for(&[7, 9]);
+            proof.pop();
+            assert!(!Groth16Verifier { vk: &vk }.verify(&proof, &fields(&[7, 9])));
+        }
+ 
+        #[test]
+        fn each_circuit_is_checked_against_its_own_key() {
+            let mut config = ProgramConfig::default_production();
+            config.bid_verifying_key = Some(fixed_key(Groth16VerifyingKey::BID_PROOF_INPUTS));
+            config.reveal_verifying_key = Some(fixed_key(Groth16VerifyingKey::REVEAL_PROOF_INPUTS));
+ 
+            let bid_proof = proof_for(&[1, 2, 3]);
+            assert!(verify_bid_proof(&config, &bid_proof, &fields(&[1, 2, 3])).is_ok());
+            assert!(verify_reveal_proof(&config, &bid_proof, &fields(&[1, 2, 3])).is_err());
+ 
+            let reveal_proof = proof_for(&[1, 5]);
+            assert!(verify_reveal_proof(&config, &reveal_proof, &fields(&[1, 5])).is_ok());
+            assert!(verify_bid_proof(&config, &reveal_proof, &fields(&[1, 5])).is_err());
+        }
+ 
+        #[test]
+        fn fails_closed_without_a_key() {
+            let config = ProgramConfig::default_production();
+            let proof = proof_for(&[1, 5]);
+            assert!(verify_range_proof(&config, &proof, &fields(&[1, 5])).is_err());
+        }
+    }
+}
\ No newline at end of file